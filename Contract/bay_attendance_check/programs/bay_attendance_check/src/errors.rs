@@ -22,4 +22,124 @@ pub enum AttendanceError {
     
     #[msg("Session not found")]
     SessionNotFound,
+    
+    #[msg("Integer overflow")]
+    Overflow,
+    
+    #[msg("The session has been finalized")]
+    SessionFinalized,
+    
+    #[msg("The session has not ended yet")]
+    SessionNotEnded,
+    
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+    
+    #[msg("Only the session admin or its co-hosts can manage this session")]
+    NotSessionHost,
+    
+    #[msg("Too many co-hosts")]
+    TooManyCoHosts,
+    
+    #[msg("Session title is too long")]
+    TitleTooLong,
+    
+    #[msg("Invalid point multiplier")]
+    InvalidPointMultiplier,
+    
+    #[msg("This session requires a signed QR presence code")]
+    PresenceProofRequired,
+    
+    #[msg("Invalid presence proof")]
+    InvalidPresenceProof,
+    
+    #[msg("The presence code has expired")]
+    PresenceProofExpired,
+    
+    #[msg("The excuse request has already been reviewed")]
+    ExcuseAlreadyReviewed,
+    
+    #[msg("Attendance has already been recorded for this member")]
+    AttendanceAlreadyRecorded,
+    
+    #[msg("Deactivate the member before closing the account")]
+    MemberStillActive,
+    
+    #[msg("Invalid permission bits")]
+    InvalidPermissions,
+    
+    #[msg("Display name is too long")]
+    DisplayNameTooLong,
+    
+    #[msg("Profile URI is too long")]
+    ProfileUriTooLong,
+    
+    #[msg("Account cannot be migrated")]
+    InvalidMigrationAccount,
+    
+    #[msg("Term name is too long")]
+    TermNameTooLong,
+    
+    #[msg("The term is not active")]
+    TermNotActive,
+    
+    #[msg("The session date is outside the term")]
+    SessionOutsideTerm,
+    
+    #[msg("Term statistics account for this session's term is required")]
+    TermStatsRequired,
+    
+    #[msg("Invalid streak rules")]
+    InvalidStreakRules,
+    
+    #[msg("Check-in is not open yet")]
+    CheckInNotOpen,
+    
+    #[msg("Session times must fall within the session date")]
+    SessionTimeOutsideDate,
+    
+    #[msg("Session late time has already passed")]
+    SessionAlreadyEnded,
+    
+    #[msg("Late window exceeds the configured maximum")]
+    LateWindowTooLong,
+    
+    #[msg("Invalid UTC offset")]
+    InvalidUtcOffset,
+    
+    #[msg("Team name is too long")]
+    TeamNameTooLong,
+    
+    #[msg("Team account is required for a member in a team")]
+    TeamRequired,
+    
+    #[msg("Member must be removed from their team first")]
+    MemberStillInTeam,
+    
+    #[msg("Leaderboard does not match the session or stats account")]
+    InvalidLeaderboard,
+    
+    #[msg("Invalid session series schedule")]
+    InvalidSeriesSchedule,
+    
+    #[msg("All sessions of the series have been created")]
+    SeriesComplete,
+    
+    #[msg("The session has been cancelled")]
+    SessionCancelled,
+    
+    #[msg("The point mint does not match the configured BAY mint")]
+    InvalidPointMint,
+    
+    #[msg("Invalid point schedule")]
+    InvalidPointSchedule,
+    
+    #[msg("Session rewards cannot change after check-in has opened")]
+    RewardsLocked,
+    
+    #[msg("Only cancelled sessions can be closed")]
+    SessionNotCancelled,
+    
+    #[msg("All attendance records of the session must be closed first")]
+    SessionHasOpenRecords,
 }
\ No newline at end of file
@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+// 오프체인 인덱서/알림 봇을 위한 상태 변경 이벤트
+
+#[event]
+pub struct ConfigInitialized {
+    pub super_admin: Pubkey,
+}
+
+#[event]
+pub struct RolePermissionsUpdated {
+    pub role: MemberRole,
+    pub permissions: u16,
+}
+
+#[event]
+pub struct MemberRegistered {
+    pub member: Pubkey,
+    pub wallet: Pubkey,
+    pub role: MemberRole,
+    pub registered_by: Pubkey,
+}
+
+#[event]
+pub struct MemberProfileUpdated {
+    pub member: Pubkey,
+    pub wallet: Pubkey,
+    pub display_name: String,
+    pub cohort: Option<u16>,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub previous_version: u8,
+    pub version: u8,
+    pub previous_len: u32,
+    pub new_len: u32,
+}
+
+#[event]
+pub struct MemberStatusChanged {
+    pub member: Pubkey,
+    pub wallet: Pubkey,
+    pub is_active: bool,
+    pub changed_by: Pubkey,
+}
+
+#[event]
+pub struct MemberRoleChanged {
+    pub member: Pubkey,
+    pub wallet: Pubkey,
+    pub previous_role: MemberRole,
+    pub role: MemberRole,
+    pub changed_by: Pubkey,
+}
+
+#[event]
+pub struct MemberClosed {
+    pub member: Pubkey,
+    pub wallet: Pubkey,
+    pub closed_by: Pubkey,
+    pub refunded_to: Pubkey,
+}
+
+#[event]
+pub struct TermCreated {
+    pub term: Pubkey,
+    pub term_id: u64,
+    pub name: String,
+    pub start_date: i64,
+    pub end_date: i64,
+}
+
+#[event]
+pub struct TeamCreated {
+    pub team: Pubkey,
+    pub team_id: u16,
+    pub name: String,
+    pub lead: Pubkey,
+}
+
+#[event]
+pub struct TeamUpdated {
+    pub team: Pubkey,
+    pub name: String,
+    pub lead: Pubkey,
+}
+
+#[event]
+pub struct MemberTeamChanged {
+    pub member: Pubkey,
+    pub previous_team_id: Option<u16>,
+    pub team_id: Option<u16>,
+    pub changed_by: Pubkey,
+}
+
+#[event]
+pub struct LeaderboardCreated {
+    pub leaderboard: Pubkey,
+    pub term: Option<Pubkey>,
+}
+
+#[event]
+pub struct SessionSeriesCreated {
+    pub series: Pubkey,
+    pub series_id: u64,
+    pub title: String,
+    pub schedule: SeriesSchedule,
+    pub first_date: i64,
+    pub term: Option<Pubkey>,
+}
+
+#[event]
+pub struct SessionSeriesRescheduled {
+    pub series: Pubkey,
+    pub start_time_of_day: u32,
+    pub late_window: u32,
+    pub sessions_updated: u32,
+}
+
+#[event]
+pub struct TermStatusChanged {
+    pub term: Pubkey,
+    pub is_active: bool,
+    pub changed_by: Pubkey,
+}
+
+#[event]
+pub struct SessionCreated {
+    pub session: Pubkey,
+    pub session_id: u64,
+    pub title: String,
+    pub kind: SessionKind,
+    pub admin: Pubkey,
+    pub session_date: i64,
+    pub open_time: i64,
+    pub start_time: i64,
+    pub late_time: i64,
+    pub term: Option<Pubkey>,
+}
+
+#[event]
+pub struct CheckedIn {
+    pub session: Pubkey,
+    pub member: Pubkey,
+    pub status: AttendanceStatus,
+    pub points: u64,
+    pub check_in_time: i64,
+}
+
+#[event]
+pub struct AttendanceCorrected {
+    pub session: Pubkey,
+    pub member: Pubkey,
+    pub previous_status: Option<AttendanceStatus>, // None이면 새로 생성된 기록
+    pub status: AttendanceStatus,
+    pub points: u64,
+    pub reason: CorrectionReason,
+    pub corrected_by: Pubkey,
+}
+
+#[event]
+pub struct ExcuseSubmitted {
+    pub excuse_request: Pubkey,
+    pub session: Pubkey,
+    pub member: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub is_pre_announced: bool,
+}
+
+#[event]
+pub struct ExcuseReviewed {
+    pub excuse_request: Pubkey,
+    pub session: Pubkey,
+    pub member: Pubkey,
+    pub status: ExcuseStatus,
+    pub reviewed_by: Pubkey,
+}
+
+#[event]
+pub struct StreakMilestoneReached {
+    pub session: Pubkey,
+    pub member: Pubkey,
+    pub streak: u16,
+    pub bonus_points: u64,
+}
+
+#[event]
+pub struct SessionTimeRulesUpdated {
+    pub session_time_rules: SessionTimeRules,
+}
+
+#[event]
+pub struct StreakRulesUpdated {
+    pub streak_rules: StreakRules,
+}
+
+#[event]
+pub struct SessionStatusChanged {
+    pub session: Pubkey,
+    pub state_override: SessionOverride,
+    pub state: SessionState,
+    pub changed_by: Pubkey,
+}
+
+#[event]
+pub struct PointMintUpdated {
+    pub point_mint: Pubkey,
+}
+
+#[event]
+pub struct SessionCancelled {
+    pub session: Pubkey,
+    pub session_id: u64,
+    pub title: String,
+    pub session_date: i64,
+    pub start_time: i64,
+    pub affected_records: u32,   // 되돌릴 출석 기록 수 (체크인/결석/공결)
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct CancelledRecordsClosed {
+    pub session: Pubkey,
+    pub records_closed: u32,
+    pub records_remaining: u32,
+}
+
+#[event]
+pub struct SessionClosed {
+    pub session: Pubkey,
+    pub session_id: u64,
+    pub refunded_to: Pubkey,     // rent 환불 대상 (세션을 만든 운영진)
+    pub closed_by: Pubkey,
+}
+
+#[event]
+pub struct SessionReactivated {
+    pub session: Pubkey,
+    pub mode: ReactivateMode,
+    pub open_time: i64,
+    pub start_time: i64,
+    pub late_time: i64,
+    pub records_reset: u32,
+}
+
+#[event]
+pub struct SessionCoHostsUpdated {
+    pub session: Pubkey,
+    pub co_hosts: Vec<Pubkey>,
+}
+
+#[event]
+pub struct PointScheduleUpdated {
+    pub session: Option<Pubkey>, // None이면 Config 기본 지급 기준
+    pub point_schedule: PointSchedule,
+    pub point_multiplier: u16,
+}
+
+#[event]
+pub struct SessionPresenceModeChanged {
+    pub session: Pubkey,
+    pub presence_window: u32,
+}
+
+#[event]
+pub struct MemberMarkedAbsent {
+    pub session: Pubkey,
+    pub member: Pubkey,
+}
+
+#[event]
+pub struct SessionFinalized {
+    pub session: Pubkey,
+    pub total_attendees: u32,
+    pub total_late: u32,
+    pub total_absent: u32,
+    pub total_excused: u32,
+}
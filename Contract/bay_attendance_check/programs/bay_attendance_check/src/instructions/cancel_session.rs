@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*, utils::close_attendance_record};
+
+// 세션 취소 (취소된 세션은 체크인 및 변경 불가, 학기에 속한 세션은 학기 세션 수에서 제외)
+// remaining_accounts로 전달된 [attendance_record, member, payer] 묶음의 출석 기록을 종료하고
+// 세션/학회원 통계와 포인트를 되돌린 뒤 rent를 payer에게 환불
+//   학기에 속한 세션은 [attendance_record, member, payer, term_stats] 묶음
+//   출석 기록에 팀이 있으면 묶음 끝에 해당 팀 계정 추가
+// 출석 기록이 많으면 취소된 세션에 다시 호출하여 나머지 기록을 나누어 종료
+// 이미 발행된 BAY 토큰은 학회원 지갑에 남고 이후 발행분에서 차감되며, 전달된 리더보드에 되돌린 통계 반영
+// 마감된 세션은 결과(결석 처리, 학기 보상)가 확정되었으므로 취소 불가
+pub fn cancel_session<'info>(ctx: Context<'_, '_, 'info, 'info, CancelSession<'info>>) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    let admin = &ctx.accounts.admin;
+    
+    // 세션 생성/운영 권한 확인
+    require!(
+        ctx.accounts.config.member_has_permission(admin, PERMISSION_CREATE_SESSION),
+        AttendanceError::Unauthorized
+    );
+    
+    // 세션 진행자(생성자 또는 공동 진행자) 확인
+    require!(
+        session.is_host(&ctx.accounts.authority.key()),
+        AttendanceError::NotSessionHost
+    );
+    
+    // 처음 취소하는 경우에만 상태 변경 및 알림 이벤트 발생
+    if !session.is_cancelled {
+        require!(
+            !session.is_finalized,
+            AttendanceError::SessionFinalized
+        );
+        require!(
+            ctx.accounts.term.as_ref().map(|term| term.key()) == session.term,
+            AttendanceError::SessionOutsideTerm
+        );
+        if let Some(term) = ctx.accounts.term.as_mut() {
+            term.total_sessions = term.total_sessions
+                .checked_sub(1)
+                .ok_or(AttendanceError::Overflow)?;
+        }
+        
+        session.is_cancelled = true;
+        
+        emit!(SessionCancelled {
+            session: session.key(),
+            session_id: session.id,
+            title: session.title.clone(),
+            session_date: session.session_date,
+            start_time: session.start_time,
+            affected_records: session.open_record_count(),
+            cancelled_by: ctx.accounts.authority.key(),
+        });
+        
+        msg!("Session cancelled: {}", session.id);
+    }
+    
+    let mut records_closed: u32 = 0;
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    
+    while let Some(record_info) = remaining_accounts.next() {
+        let wallet = close_attendance_record(
+            &mut remaining_accounts,
+            record_info,
+            session,
+            ctx.accounts.leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+            ctx.accounts.term_leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+            ctx.program_id,
+        )?;
+        
+        records_closed += 1;
+        msg!("Attendance record closed: {}", wallet);
+    }
+    
+    if records_closed > 0 {
+        emit!(CancelledRecordsClosed {
+            session: session.key(),
+            records_closed,
+            records_remaining: session.open_record_count(),
+        });
+    }
+    
+    Ok(())
+}
+
+// 취소된 세션의 출석 기록이 모두 종료된 후 세션 계정 종료 및 rent 환불 (세션을 만든 운영진에게 반환)
+pub fn close_session(ctx: Context<CloseSession>) -> Result<()> {
+    let session = &ctx.accounts.session;
+    
+    require!(
+        ctx.accounts.config.member_has_permission(&ctx.accounts.admin, PERMISSION_CREATE_SESSION),
+        AttendanceError::Unauthorized
+    );
+    
+    require!(
+        session.is_host(&ctx.accounts.authority.key()),
+        AttendanceError::NotSessionHost
+    );
+    
+    require!(
+        session.is_cancelled,
+        AttendanceError::SessionNotCancelled
+    );
+    
+    // 남은 출석 기록은 cancel_session으로 먼저 종료 (세션 통계 기준)
+    require!(
+        session.open_record_count() == 0,
+        AttendanceError::SessionHasOpenRecords
+    );
+    
+    emit!(SessionClosed {
+        session: session.key(),
+        session_id: session.id,
+        refunded_to: session.admin,
+        closed_by: ctx.accounts.authority.key(),
+    });
+    
+    msg!("Session account closed: {}", session.id);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelSession<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Account<'info, Session>,
+    
+    // 세션이 속한 학기 (처음 취소할 때 세션 수 차감)
+    #[account(
+        mut,
+        seeds = [b"term", term.id.to_le_bytes().as_ref()],
+        bump = term.bump
+    )]
+    pub term: Option<Account<'info, Term>>,
+    
+    // 전체 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", leaderboard.term.as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    // 세션이 속한 학기의 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", term_leaderboard.term.as_ref()],
+        bump = term_leaderboard.bump
+    )]
+    pub term_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSession<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump,
+        close = session_admin
+    )]
+    pub session: Account<'info, Session>,
+    
+    /// CHECK: 세션을 만든 운영진 (세션 계정 rent 환불 대상)
+    #[account(mut, address = session.admin @ AttendanceError::Unauthorized)]
+    pub session_admin: UncheckedAccount<'info>,
+}
\ No newline at end of file
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::AttendanceError};
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::{state::*, errors::AttendanceError, events::*, utils::{mint_points, parse_ed25519_instruction, team_for, term_stats_for, update_leaderboards}};
 
 pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
     let session = &mut ctx.accounts.session;
@@ -8,11 +11,8 @@ pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
     
-    // 세션 활성화 상태 확인
-    require!(
-        session.is_active,
-        AttendanceError::SessionNotActive
-    );
+    // 세션 상태 확인 (체크인 가능 시간과 운영진 수동 개폐 기준)
+    session.require_open(current_time)?;
     
     // 학회원 활성화 상태 확인
     require!(
@@ -20,44 +20,112 @@ pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
         AttendanceError::MemberNotActive
     );
     
-    // 체크인 시간이 세션 날짜 내에 있는지 확인
-    // (실제로는 더 정교한 날짜 검증이 필요할 수 있음)
-    
-    // 디버깅을 위한 시간 정보 출력
-    msg!("=== Check-in Time Debug Info ===");
-    msg!("Current time: {}", current_time);
-    msg!("Session start time: {}", session.start_time);
-    msg!("Session late time: {}", session.late_time);
-    msg!("Time until start: {} seconds", session.start_time - current_time);
-    msg!("Time until late: {} seconds", session.late_time - current_time);
+    // 현장 인증 모드: 직전 instruction이 세션 진행자가 서명한 QR 코드의 Ed25519 검증이어야 함
+    if session.presence_window > 0 {
+        let instructions_sysvar = &ctx.accounts.instructions_sysvar;
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        require!(current_index > 0, AttendanceError::PresenceProofRequired);
+        
+        let ed25519_ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)
+            .map_err(|_| AttendanceError::PresenceProofRequired)?;
+        let (signer, message) = parse_ed25519_instruction(&ed25519_ix)?;
+        
+        require!(
+            session.is_host(&signer),
+            AttendanceError::InvalidPresenceProof
+        );
+        
+        // 현재 QR 코드 또는 직전 QR 코드만 허용 (스캔 중 교체 대비)
+        let window_index = current_time / session.presence_window as i64;
+        let session_key = session.key();
+        require!(
+            message == Session::presence_message(&session_key, window_index)
+                || message == Session::presence_message(&session_key, window_index - 1),
+            AttendanceError::PresenceProofExpired
+        );
+    }
     
     // 출석 상태 결정
-    let (status, points) = if current_time <= session.start_time {
+    let status = if current_time <= session.start_time {
         // 출석 (세션 시작 전에 체크인)
-        session.total_attendees += 1;
-        member.total_attendance += 1;
-        (AttendanceStatus::Present, 10u8)
+        AttendanceStatus::Present
     } else if current_time <= session.late_time {
         // 지각 (세션 시작 후 30분 이내)
-        session.total_attendees += 1;
-        session.total_late += 1;
-        member.total_late += 1;
-        (AttendanceStatus::Late, 5u8)
+        AttendanceStatus::Late
     } else {
         // 너무 늦은 체크인은 거부
         return Err(AttendanceError::CheckInTimePassed.into());
     };
     
+    // 세션 포인트 지급 기준에 따른 포인트 계산
+    let base_points = session.points_for(&status, current_time)?;
+    
+    // 연속 출석 갱신 및 마일스톤 보너스 (보너스도 출석 기록 포인트에 포함)
+    let streak_bonus = member.update_streak(&status, &ctx.accounts.config.streak_rules);
+    let points = base_points
+        .checked_add(streak_bonus)
+        .ok_or(AttendanceError::Overflow)?;
+    
     // 출석 기록 저장
     attendance_record.member = member.wallet;
     attendance_record.session = session.key();
     attendance_record.check_in_time = current_time;
     attendance_record.status = status.clone();
     attendance_record.points_earned = points;
+    attendance_record.payer = ctx.accounts.member_wallet.key();
+    attendance_record.corrected_by = None;
+    attendance_record.correction_reason = None;
     attendance_record.bump = ctx.bumps.attendance_record;
+    attendance_record.version = AttendanceRecord::VERSION;
+    attendance_record.team_id = member.team_id;
+    attendance_record.points_minted = points;
     
-    // 학회원 총 포인트 업데이트
-    member.total_points += points as u64;
+    // 세션 통계 및 학회원 통계/포인트 업데이트
+    session.record_status(&status)?;
+    member.record_status(&status, points)?;
+    if let Some(term_stats) = term_stats_for(&mut ctx.accounts.term_stats, session, &member.wallet)? {
+        term_stats.record_status(&status, points)?;
+    }
+    if let Some(team) = team_for(&mut ctx.accounts.team, attendance_record.team_id)? {
+        team.record_status(&status, points)?;
+    }
+    update_leaderboards(
+        ctx.accounts.leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+        ctx.accounts.term_leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+        session,
+        member,
+        ctx.accounts.term_stats.as_deref(),
+    )?;
+    
+    // 획득한 포인트만큼 BAY 토큰 발행 (종료된 기록으로 이미 발행된 적립분은 제외)
+    let minted_points = member.take_mint_amount(points);
+    if minted_points > 0 {
+        mint_points(
+            &ctx.accounts.token_program,
+            &ctx.accounts.point_mint,
+            &ctx.accounts.member_token_account,
+            &ctx.accounts.mint_authority,
+            ctx.bumps.mint_authority,
+            minted_points,
+        )?;
+    }
+    
+    if streak_bonus > 0 {
+        emit!(StreakMilestoneReached {
+            session: session.key(),
+            member: member.wallet,
+            streak: member.current_streak,
+            bonus_points: streak_bonus,
+        });
+    }
+    
+    emit!(CheckedIn {
+        session: session.key(),
+        member: member.wallet,
+        status: status.clone(),
+        points,
+        check_in_time: current_time,
+    });
     
     msg!("Check-in successful for member: {}", member.wallet);
     msg!("Status: {:?}, Points earned: {}", status, points);
@@ -70,6 +138,12 @@ pub struct CheckIn<'info> {
     #[account(mut)]
     pub member_wallet: Signer<'info>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
     #[account(
         mut,
         seeds = [b"member", member_wallet.key().as_ref()],
@@ -94,5 +168,65 @@ pub struct CheckIn<'info> {
     )]
     pub attendance_record: Account<'info, AttendanceRecord>,
     
+    // 세션이 학기에 속한 경우 학회원의 학기별 통계
+    #[account(
+        mut,
+        seeds = [b"term_stats", term_stats.term.as_ref(), member_wallet.key().as_ref()],
+        bump = term_stats.bump
+    )]
+    pub term_stats: Option<Account<'info, TermStats>>,
+    
+    // 학회원이 팀에 속한 경우 소속 팀 (팀별 출석 집계)
+    #[account(
+        mut,
+        seeds = [b"team", team.id.to_le_bytes().as_ref()],
+        bump = team.bump
+    )]
+    pub team: Option<Account<'info, Team>>,
+    
+    // 전체 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", leaderboard.term.as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    // 세션이 속한 학기의 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", term_leaderboard.term.as_ref()],
+        bump = term_leaderboard.bump
+    )]
+    pub term_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    /// CHECK: BAY 토큰 mint authority PDA (데이터 없음, 서명 전용)
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        address = config.point_mint @ AttendanceError::InvalidPointMint,
+        mint::authority = mint_authority
+    )]
+    pub point_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        init_if_needed,
+        payer = member_wallet,
+        associated_token::mint = point_mint,
+        associated_token::authority = member_wallet
+    )]
+    pub member_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: instructions sysvar (현장 인증 QR 서명 검증용)
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
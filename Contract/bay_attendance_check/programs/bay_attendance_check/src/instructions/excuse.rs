@@ -0,0 +1,291 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*, utils::{term_stats_for, update_leaderboards}};
+
+// 학회원이 세션 불참 사유(해시)를 제출하여 공결 신청 (세션 전후 모두 가능)
+pub fn submit_excuse(ctx: Context<SubmitExcuse>, reason_hash: [u8; 32]) -> Result<()> {
+    let session = &ctx.accounts.session;
+    let member = &ctx.accounts.member;
+    let excuse_request = &mut ctx.accounts.excuse_request;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    require!(
+        member.is_active,
+        AttendanceError::MemberNotActive
+    );
+    
+    require!(
+        !session.is_cancelled,
+        AttendanceError::SessionCancelled
+    );
+    
+    excuse_request.member = member.wallet;
+    excuse_request.session = session.key();
+    excuse_request.reason_hash = reason_hash;
+    excuse_request.submitted_at = current_time;
+    excuse_request.is_pre_announced = current_time < session.start_time;
+    excuse_request.status = ExcuseStatus::Pending;
+    excuse_request.reviewed_by = None;
+    excuse_request.bump = ctx.bumps.excuse_request;
+    excuse_request.version = ExcuseRequest::VERSION;
+    
+    emit!(ExcuseSubmitted {
+        excuse_request: excuse_request.key(),
+        session: session.key(),
+        member: member.wallet,
+        reason_hash,
+        is_pre_announced: excuse_request.is_pre_announced,
+    });
+    
+    msg!("Excuse submitted by member: {}", member.wallet);
+    msg!("Pre-announced: {}", excuse_request.is_pre_announced);
+    
+    Ok(())
+}
+
+// 공결 승인: 공결 출석 기록 생성 (이미 결석 처리된 경우 공결로 변경)
+pub fn approve_excuse(ctx: Context<ApproveExcuse>) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    let member = &mut ctx.accounts.member;
+    let excuse_request = &mut ctx.accounts.excuse_request;
+    let attendance_record = &mut ctx.accounts.attendance_record;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    require!(
+        excuse_request.status == ExcuseStatus::Pending,
+        AttendanceError::ExcuseAlreadyReviewed
+    );
+    
+    require!(
+        !session.is_cancelled,
+        AttendanceError::SessionCancelled
+    );
+    
+    if attendance_record.session == Pubkey::default() {
+        attendance_record.member = member.wallet;
+        attendance_record.session = session.key();
+        attendance_record.check_in_time = current_time;
+        attendance_record.payer = ctx.accounts.authority.key();
+        attendance_record.bump = ctx.bumps.attendance_record;
+        attendance_record.version = AttendanceRecord::VERSION;
+        attendance_record.team_id = member.team_id;
+        attendance_record.points_minted = 0;
+    } else {
+        // 체크인한 학회원은 공결 대상이 아님 (결석 기록만 공결로 변경)
+        require!(
+            attendance_record.status == AttendanceStatus::Absent,
+            AttendanceError::AttendanceAlreadyRecorded
+        );
+        session.revert_status(&attendance_record.status)?;
+        member.revert_status(&attendance_record.status, attendance_record.points_earned)?;
+        if let Some(term_stats) = term_stats_for(&mut ctx.accounts.term_stats, session, &member.wallet)? {
+            term_stats.revert_status(&attendance_record.status, attendance_record.points_earned)?;
+        }
+    }
+    
+    attendance_record.status = AttendanceStatus::Excused;
+    attendance_record.points_earned = 0;
+    attendance_record.corrected_by = Some(ctx.accounts.authority.key());
+    attendance_record.correction_reason = None;
+    
+    session.record_status(&AttendanceStatus::Excused)?;
+    member.record_status(&AttendanceStatus::Excused, 0)?;
+    if let Some(term_stats) = term_stats_for(&mut ctx.accounts.term_stats, session, &member.wallet)? {
+        term_stats.record_status(&AttendanceStatus::Excused, 0)?;
+    }
+    // 결석이 공결로 바뀌면 출석률이 달라지므로 순위 갱신
+    update_leaderboards(
+        ctx.accounts.leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+        ctx.accounts.term_leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+        session,
+        member,
+        ctx.accounts.term_stats.as_deref(),
+    )?;
+    
+    excuse_request.status = ExcuseStatus::Approved;
+    excuse_request.reviewed_by = Some(ctx.accounts.authority.key());
+    
+    emit!(ExcuseReviewed {
+        excuse_request: excuse_request.key(),
+        session: session.key(),
+        member: member.wallet,
+        status: ExcuseStatus::Approved,
+        reviewed_by: ctx.accounts.authority.key(),
+    });
+    
+    msg!("Excuse approved for member: {}", member.wallet);
+    
+    Ok(())
+}
+
+// 공결 반려 (출석 기록은 변경하지 않음)
+pub fn reject_excuse(ctx: Context<RejectExcuse>) -> Result<()> {
+    let excuse_request = &mut ctx.accounts.excuse_request;
+    
+    require!(
+        excuse_request.status == ExcuseStatus::Pending,
+        AttendanceError::ExcuseAlreadyReviewed
+    );
+    
+    excuse_request.status = ExcuseStatus::Rejected;
+    excuse_request.reviewed_by = Some(ctx.accounts.authority.key());
+    
+    emit!(ExcuseReviewed {
+        excuse_request: excuse_request.key(),
+        session: excuse_request.session,
+        member: excuse_request.member,
+        status: ExcuseStatus::Rejected,
+        reviewed_by: ctx.accounts.authority.key(),
+    });
+    
+    msg!("Excuse rejected for member: {}", excuse_request.member);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitExcuse<'info> {
+    #[account(mut)]
+    pub member_wallet: Signer<'info>,
+    
+    #[account(
+        seeds = [b"member", member_wallet.key().as_ref()],
+        bump = member.bump,
+        constraint = member.wallet == member_wallet.key() @ AttendanceError::Unauthorized
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Account<'info, Session>,
+    
+    #[account(
+        init,
+        payer = member_wallet,
+        space = ExcuseRequest::LEN,
+        seeds = [
+            b"excuse",
+            session.key().as_ref(),
+            member_wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub excuse_request: Account<'info, ExcuseRequest>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveExcuse<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive,
+        constraint = config.member_has_permission(&admin, PERMISSION_CORRECT_ATTENDANCE) @ AttendanceError::Unauthorized
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Account<'info, Session>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", excuse_request.member.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"excuse",
+            session.key().as_ref(),
+            excuse_request.member.as_ref()
+        ],
+        bump = excuse_request.bump
+    )]
+    pub excuse_request: Account<'info, ExcuseRequest>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AttendanceRecord::LEN,
+        seeds = [
+            b"attendance",
+            session.key().as_ref(),
+            excuse_request.member.as_ref()
+        ],
+        bump
+    )]
+    pub attendance_record: Account<'info, AttendanceRecord>,
+    
+    // 세션이 학기에 속한 경우 학회원의 학기별 통계
+    #[account(
+        mut,
+        seeds = [b"term_stats", term_stats.term.as_ref(), excuse_request.member.as_ref()],
+        bump = term_stats.bump
+    )]
+    pub term_stats: Option<Account<'info, TermStats>>,
+    
+    // 전체 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", leaderboard.term.as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    // 세션이 속한 학기의 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", term_leaderboard.term.as_ref()],
+        bump = term_leaderboard.bump
+    )]
+    pub term_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RejectExcuse<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive,
+        constraint = config.member_has_permission(&admin, PERMISSION_CORRECT_ATTENDANCE) @ AttendanceError::Unauthorized
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"excuse",
+            excuse_request.session.as_ref(),
+            excuse_request.member.as_ref()
+        ],
+        bump = excuse_request.bump
+    )]
+    pub excuse_request: Account<'info, ExcuseRequest>,
+}
\ No newline at end of file
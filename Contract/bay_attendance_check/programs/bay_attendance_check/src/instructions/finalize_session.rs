@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*, utils::{create_pda_account, load_term_stats, update_leaderboards}};
+
+// 지각 기준 시간이 지난 세션의 미출석자를 결석 처리하고 세션을 마감
+// remaining_accounts: [member, attendance_record] 쌍의 목록 (attendance_record는 아직 생성되지 않은 PDA)
+// 학기에 속한 세션은 [member, attendance_record, term_stats] 묶음
+pub fn finalize_session<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeSession<'info>>,
+    lock: bool,
+) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    let admin = &ctx.accounts.admin;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // 세션 생성/운영 권한 확인
+    require!(
+        ctx.accounts.config.member_has_permission(admin, PERMISSION_CREATE_SESSION),
+        AttendanceError::Unauthorized
+    );
+    
+    // 세션 진행자(생성자 또는 공동 진행자) 확인
+    require!(
+        session.is_host(&ctx.accounts.authority.key()),
+        AttendanceError::NotSessionHost
+    );
+    
+    require!(
+        !session.is_finalized,
+        AttendanceError::SessionFinalized
+    );
+    
+    require!(
+        !session.is_cancelled,
+        AttendanceError::SessionCancelled
+    );
+    
+    // 지각 기준 시간 이후에만 결석 처리 가능
+    require!(
+        current_time > session.late_time,
+        AttendanceError::SessionNotEnded
+    );
+    
+    let remaining_accounts = ctx.remaining_accounts;
+    let chunk_len = if session.term.is_some() { 3 } else { 2 };
+    // is_multiple_of는 Anchor 0.31의 SBF 툴체인에서 보장되지 않으므로 나머지 연산 사용
+    #[allow(clippy::manual_is_multiple_of)]
+    let complete_chunks = remaining_accounts.len() % chunk_len == 0;
+    require!(
+        complete_chunks,
+        AttendanceError::InvalidRemainingAccounts
+    );
+    
+    let session_key = session.key();
+    let mut absent_count: u32 = 0;
+    
+    for accounts in remaining_accounts.chunks(chunk_len) {
+        let member_info = &accounts[0];
+        let record_info = &accounts[1];
+        
+        require!(
+            member_info.is_writable && record_info.is_writable,
+            AttendanceError::InvalidRemainingAccounts
+        );
+        
+        let mut member: Account<'info, Member> = Account::try_from(member_info)?;
+        require!(
+            member.is_active,
+            AttendanceError::MemberNotActive
+        );
+        
+        // 출석 기록 PDA 검증 (이미 기록이 있으면 체크인한 학회원)
+        let (record_key, record_bump) = Pubkey::find_program_address(
+            &[b"attendance", session_key.as_ref(), member.wallet.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            record_info.key(),
+            record_key,
+            AttendanceError::InvalidRemainingAccounts
+        );
+        require!(
+            record_info.data_is_empty(),
+            AttendanceError::AlreadyCheckedIn
+        );
+        
+        create_pda_account(
+            &ctx.accounts.authority.to_account_info(),
+            record_info,
+            &ctx.accounts.system_program.to_account_info(),
+            AttendanceRecord::LEN,
+            &[b"attendance", session_key.as_ref(), member.wallet.as_ref(), &[record_bump]],
+            ctx.program_id,
+        )?;
+        
+        // 결석 기록 저장
+        let record = AttendanceRecord {
+            member: member.wallet,
+            session: session_key,
+            check_in_time: current_time,
+            status: AttendanceStatus::Absent,
+            points_earned: 0,
+            payer: ctx.accounts.authority.key(),
+            corrected_by: None,
+            correction_reason: None,
+            bump: record_bump,
+            version: AttendanceRecord::VERSION,
+            team_id: member.team_id,
+            points_minted: 0,
+            reserved: [0; AttendanceRecord::RESERVED_LEN],
+        };
+        record.try_serialize(&mut &mut record_info.try_borrow_mut_data()?[..])?;
+        
+        // 세션 및 학회원 결석 횟수 업데이트
+        session.record_status(&record.status)?;
+        member.record_status(&record.status, 0)?;
+        member.update_streak(&record.status, &ctx.accounts.config.streak_rules);
+        member.exit(ctx.program_id)?;
+        
+        let term_stats = match accounts.get(2) {
+            Some(term_stats_info) => {
+                let mut term_stats = load_term_stats(term_stats_info, session, &member.wallet)?;
+                term_stats.record_status(&record.status, 0)?;
+                term_stats.exit(ctx.program_id)?;
+                Some(term_stats)
+            }
+            None => None,
+        };
+        
+        // 결석으로 출석률이 바뀌었으므로 리더보드 갱신
+        update_leaderboards(
+            ctx.accounts.leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+            ctx.accounts.term_leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+            session,
+            &member,
+            term_stats.as_deref(),
+        )?;
+        
+        emit!(MemberMarkedAbsent {
+            session: session_key,
+            member: member.wallet,
+        });
+        
+        absent_count += 1;
+        msg!("Marked absent: {}", member.wallet);
+    }
+    
+    // 세션 영구 마감
+    if lock {
+        session.is_finalized = true;
+        
+        emit!(SessionFinalized {
+            session: session_key,
+            total_attendees: session.total_attendees,
+            total_late: session.total_late,
+            total_absent: session.total_absent,
+            total_excused: session.total_excused,
+        });
+        
+        msg!("Session finalized: {}", session_key);
+    }
+    
+    msg!("Absent members recorded: {}, Total absent: {}", absent_count, session.total_absent);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeSession<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Account<'info, Session>,
+    
+    // 전체 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", leaderboard.term.as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    // 세션이 속한 학기의 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", term_leaderboard.term.as_ref()],
+        bump = term_leaderboard.bump
+    )]
+    pub term_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -1,28 +1,143 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 
-// 통계 조회 기능들 - View functions
-pub fn get_member_stats(ctx: Context<GetMemberStats>) -> Result<()> {
+// 학회원 통계 (return data로 반환)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MemberStats {
+    pub wallet: Pubkey,
+    pub display_name: String,
+    pub cohort: Option<u16>,
+    pub team_id: Option<u16>,
+    pub total_attendance: u32,
+    pub total_late: u32,
+    pub total_absence: u32,
+    pub total_excused: u32,
+    pub total_points: u64,
+    pub attendance_rate_bps: u16, // 출석률 (지각 포함, 10000 = 100%)
+    pub current_streak: u16,
+    pub longest_streak: u16,
+    pub is_active: bool,
+}
+
+// 세션 통계 (return data로 반환)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SessionStats {
+    pub session_id: u64,
+    pub session_date: i64,
+    pub total_attendees: u32,
+    pub total_late: u32,
+    pub total_absent: u32,
+    pub total_excused: u32,
+    pub on_time_rate_bps: u16,    // 정시 출석률 (10000 = 100%)
+    pub state: SessionState,      // 조회 시점 기준 세션 상태
+}
+
+// 학회원 학기별 통계 (return data로 반환, 학기말 시상 기준)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TermMemberStats {
+    pub term: Pubkey,
+    pub wallet: Pubkey,
+    pub total_attendance: u32,
+    pub total_late: u32,
+    pub total_absence: u32,
+    pub total_excused: u32,
+    pub total_points: u64,
+    pub attendance_rate_bps: u16, // 학기 출석률 (지각 포함, 10000 = 100%)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TeamStats {
+    pub team: Pubkey,
+    pub team_id: u16,
+    pub name: String,
+    pub lead: Pubkey,
+    pub member_count: u32,
+    pub total_attendance: u32,
+    pub total_late: u32,
+    pub total_points: u64,
+    pub points_per_member: u64, // 학회원 1인당 평균 포인트 (팀 순위 비교용)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LeaderboardStats {
+    pub term: Option<Pubkey>, // None이면 전체 리더보드
+    pub entries: Vec<LeaderboardEntry>, // 1위부터 순서대로
+}
+
+// 통계 조회 기능들 - View functions (simulateTransaction 또는 CPI로 조회)
+pub fn get_member_stats(ctx: Context<GetMemberStats>) -> Result<MemberStats> {
     let member = &ctx.accounts.member;
     
-    msg!("Member Stats for: {}", member.wallet);
-    msg!("Total Attendance: {}", member.total_attendance);
-    msg!("Total Late: {}", member.total_late);
-    msg!("Total Absence: {}", member.total_absence);
-    msg!("Total Points: {}", member.total_points);
-    
-    Ok(())
+    Ok(MemberStats {
+        wallet: member.wallet,
+        display_name: member.display_name.clone(),
+        cohort: member.cohort,
+        team_id: member.team_id,
+        total_attendance: member.total_attendance,
+        total_late: member.total_late,
+        total_absence: member.total_absence,
+        total_excused: member.total_excused,
+        total_points: member.total_points,
+        attendance_rate_bps: member.attendance_rate_bps(),
+        current_streak: member.current_streak,
+        longest_streak: member.longest_streak,
+        is_active: member.is_active,
+    })
 }
 
-pub fn get_session_stats(ctx: Context<GetSessionStats>) -> Result<()> {
+pub fn get_session_stats(ctx: Context<GetSessionStats>) -> Result<SessionStats> {
     let session = &ctx.accounts.session;
     
-    msg!("Session Stats for date: {}", session.session_date);
-    msg!("Total Attendees: {}", session.total_attendees);
-    msg!("Total Late: {}", session.total_late);
-    msg!("Is Active: {}", session.is_active);
+    Ok(SessionStats {
+        session_id: session.id,
+        session_date: session.session_date,
+        total_attendees: session.total_attendees,
+        total_late: session.total_late,
+        total_absent: session.total_absent,
+        total_excused: session.total_excused,
+        on_time_rate_bps: session.on_time_rate_bps(),
+        state: session.state(Clock::get()?.unix_timestamp),
+    })
+}
+
+pub fn get_term_stats(ctx: Context<GetTermStats>) -> Result<TermMemberStats> {
+    let term_stats = &ctx.accounts.term_stats;
+    
+    Ok(TermMemberStats {
+        term: term_stats.term,
+        wallet: term_stats.member,
+        total_attendance: term_stats.total_attendance,
+        total_late: term_stats.total_late,
+        total_absence: term_stats.total_absence,
+        total_excused: term_stats.total_excused,
+        total_points: term_stats.total_points,
+        attendance_rate_bps: term_stats.attendance_rate_bps(),
+    })
+}
+
+pub fn get_team_stats(ctx: Context<GetTeamStats>) -> Result<TeamStats> {
+    let team = &ctx.accounts.team;
+    
+    Ok(TeamStats {
+        team: team.key(),
+        team_id: team.id,
+        name: team.name.clone(),
+        lead: team.lead,
+        member_count: team.member_count,
+        total_attendance: team.total_attendance,
+        total_late: team.total_late,
+        total_points: team.total_points,
+        points_per_member: team.points_per_member(),
+    })
+}
+
+pub fn get_leaderboard(ctx: Context<GetLeaderboard>) -> Result<LeaderboardStats> {
+    let leaderboard = &ctx.accounts.leaderboard;
     
-    Ok(())
+    Ok(LeaderboardStats {
+        term: (!leaderboard.is_global()).then_some(leaderboard.term),
+        entries: leaderboard.entries.clone(),
+    })
 }
 
 #[derive(Accounts)]
@@ -36,6 +151,36 @@ pub struct GetMemberStats<'info> {
 
 #[derive(Accounts)]
 pub struct GetSessionStats<'info> {
-    #[account()]
+    #[account(
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
     pub session: Account<'info, Session>,
+}
+
+#[derive(Accounts)]
+pub struct GetTermStats<'info> {
+    #[account(
+        seeds = [b"term_stats", term_stats.term.as_ref(), term_stats.member.as_ref()],
+        bump = term_stats.bump
+    )]
+    pub term_stats: Account<'info, TermStats>,
+}
+
+#[derive(Accounts)]
+pub struct GetTeamStats<'info> {
+    #[account(
+        seeds = [b"team", team.id.to_le_bytes().as_ref()],
+        bump = team.bump
+    )]
+    pub team: Account<'info, Team>,
+}
+
+#[derive(Accounts)]
+pub struct GetLeaderboard<'info> {
+    #[account(
+        seeds = [b"leaderboard", leaderboard.term.as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
 }
\ No newline at end of file
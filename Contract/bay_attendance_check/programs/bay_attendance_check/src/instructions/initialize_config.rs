@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{state::*, errors::AttendanceError, events::*, program::BayAttendanceCheck};
+
+// 프로그램 전역 설정 초기화 (업그레이드 권한자만 1회 호출 가능)
+pub fn initialize_config(
+    ctx: Context<InitializeConfig>,
+    super_admin: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    config.super_admin = super_admin;
+    config.session_count = 0;
+    config.point_schedule = PointSchedule::DEFAULT;
+    config.role_permissions = Config::DEFAULT_ROLE_PERMISSIONS;
+    config.session_time_rules = SessionTimeRules::DEFAULT;
+    config.point_mint = ctx.accounts.point_mint.key();
+    config.bump = ctx.bumps.config;
+    config.version = Config::VERSION;
+    
+    emit!(ConfigInitialized { super_admin });
+    
+    msg!("Config initialized, super admin: {}", super_admin);
+    
+    Ok(())
+}
+
+// BAY 포인트 토큰 mint 변경 (최고 관리자만 가능, 마이그레이션된 Config는 최초 1회 설정 필요)
+pub fn set_point_mint(ctx: Context<SetPointMint>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.point_mint = ctx.accounts.point_mint.key();
+    
+    emit!(PointMintUpdated {
+        point_mint: config.point_mint,
+    });
+    
+    msg!("Point mint updated: {}", config.point_mint);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Config::LEN,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ AttendanceError::Unauthorized
+    )]
+    pub program: Program<'info, BayAttendanceCheck>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ AttendanceError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    /// CHECK: BAY 토큰 mint authority PDA (데이터 없음, 서명 전용)
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    // mint authority가 프로그램 PDA인 BAY 토큰 mint
+    #[account(
+        mint::authority = mint_authority
+    )]
+    pub point_mint: Account<'info, Mint>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPointMint<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.super_admin == authority.key() @ AttendanceError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// CHECK: BAY 토큰 mint authority PDA (데이터 없음, 서명 전용)
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    // mint authority가 프로그램 PDA인 BAY 토큰 mint
+    #[account(
+        mint::authority = mint_authority
+    )]
+    pub point_mint: Account<'info, Mint>,
+}
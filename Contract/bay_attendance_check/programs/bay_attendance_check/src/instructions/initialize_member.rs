@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::AttendanceError};
+use crate::{state::*, errors::AttendanceError, events::*};
 
 pub fn initialize_member(
     ctx: Context<InitializeMember>, 
@@ -7,22 +7,48 @@ pub fn initialize_member(
 ) -> Result<()> {
     let member = &mut ctx.accounts.member;
     
-    // Admin 권한 체크
-    if role == MemberRole::Admin {
-        require!(
-            ctx.accounts.authority.key() == ctx.accounts.admin.key(),
-            AttendanceError::Unauthorized
-        );
-    }
+    // 최고 관리자 또는 학회원 관리 권한이 있는 학회원만 등록 가능
+    require!(
+        ctx.accounts.config.has_permission(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_MANAGE_MEMBERS
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    // 자신이 가진 권한보다 강한 역할은 부여할 수 없음
+    require!(
+        ctx.accounts.config.can_grant(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            &role
+        ),
+        AttendanceError::Unauthorized
+    );
     
     member.wallet = ctx.accounts.member_wallet.key();
     member.role = role;
     member.total_attendance = 0;
     member.total_late = 0;
     member.total_absence = 0;
+    member.total_excused = 0;
     member.total_points = 0;
     member.is_active = true;
     member.bump = ctx.bumps.member;
+    member.display_name = String::new();
+    member.cohort = None;
+    member.team_id = None;
+    member.profile_uri = String::new();
+    member.payer = ctx.accounts.authority.key();
+    member.version = Member::VERSION;
+    
+    emit!(MemberRegistered {
+        member: member.key(),
+        wallet: member.wallet,
+        role: member.role.clone(),
+        registered_by: ctx.accounts.authority.key(),
+    });
     
     msg!("Member initialized: {}", member.wallet);
     
@@ -35,8 +61,18 @@ pub struct InitializeMember<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
-    /// CHECK: Admin account to verify permissions for admin role
-    pub admin: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 Admin 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
     
     /// CHECK: The wallet of the member being initialized
     pub member_wallet: UncheckedAccount<'info>,
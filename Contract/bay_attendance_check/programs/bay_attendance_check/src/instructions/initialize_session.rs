@@ -1,45 +1,108 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::AttendanceError};
+use crate::{state::*, errors::AttendanceError, events::*};
 
 pub fn initialize_session(
     ctx: Context<InitializeSession>,
+    title: String,
+    kind: SessionKind,
     session_date: i64,
+    open_time: i64,
     start_time: i64,
     late_time: i64,
 ) -> Result<()> {
+    let config = &mut ctx.accounts.config;
     let session = &mut ctx.accounts.session;
     let admin = &ctx.accounts.admin;
     
-    // Admin 권한 확인
+    // 세션 생성/운영 권한 확인
     require!(
-        admin.role == MemberRole::Admin,
+        config.member_has_permission(admin, PERMISSION_CREATE_SESSION),
         AttendanceError::Unauthorized
     );
     
+    require!(
+        title.len() <= Session::MAX_TITLE_LEN,
+        AttendanceError::TitleTooLong
+    );
+    
     // 시간 파라미터 유효성 검사
     require!(
-        start_time < late_time,
+        Session::valid_times(open_time, start_time, late_time),
         AttendanceError::InvalidTimeParameters
     );
+    config.session_time_rules.validate(
+        session_date,
+        open_time,
+        start_time,
+        late_time,
+        Clock::get()?.unix_timestamp,
+    )?;
+    
+    // 학기에 속한 세션이면 학기 기간과 활성 상태 확인
+    if let Some(term) = ctx.accounts.term.as_mut() {
+        require!(
+            term.is_active,
+            AttendanceError::TermNotActive
+        );
+        require!(
+            term.contains(session_date),
+            AttendanceError::SessionOutsideTerm
+        );
+        term.total_sessions = term.total_sessions
+            .checked_add(1)
+            .ok_or(AttendanceError::Overflow)?;
+    }
+    
+    let session_id = config.session_count;
+    config.session_count = config.session_count
+        .checked_add(1)
+        .ok_or(AttendanceError::Overflow)?;
     
     // 세션 정보 설정
+    session.id = session_id;
+    session.title = title;
+    session.kind = kind;
     session.admin = ctx.accounts.authority.key();
+    session.co_hosts = Vec::new();
     session.session_date = session_date;
+    session.open_time = open_time;
     session.start_time = start_time;
     session.late_time = late_time;
     session.total_attendees = 0;
     session.total_late = 0;
-    session.is_active = true;
+    session.total_absent = 0;
+    session.total_excused = 0;
+    session.state_override = SessionOverride::Auto;
+    session.is_finalized = false;
+    session.point_schedule = config.point_schedule.clone();
+    session.point_multiplier = Session::DEFAULT_POINT_MULTIPLIER;
+    session.presence_window = 0;
     session.bump = ctx.bumps.session;
+    session.version = Session::VERSION;
+    session.term = ctx.accounts.term.as_ref().map(|term| term.key());
+    session.series = None;
+    session.is_cancelled = false;
+    
+    emit!(SessionCreated {
+        session: session.key(),
+        session_id,
+        title: session.title.clone(),
+        kind: session.kind.clone(),
+        admin: session.admin,
+        session_date,
+        open_time,
+        start_time,
+        late_time,
+        term: session.term,
+    });
     
-    msg!("Session initialized for date: {}", session_date);
-    msg!("Start time: {}, Late time: {}", start_time, late_time);
+    msg!("Session {} initialized for date: {}", session_id, session_date);
+    msg!("Open time: {}, Start time: {}, Late time: {}", open_time, start_time, late_time);
     
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(session_date: i64)]
 pub struct InitializeSession<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -51,11 +114,26 @@ pub struct InitializeSession<'info> {
     )]
     pub admin: Account<'info, Member>,
     
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 세션이 속할 학기 (없으면 학기별 통계 미집계)
+    #[account(
+        mut,
+        seeds = [b"term", term.id.to_le_bytes().as_ref()],
+        bump = term.bump
+    )]
+    pub term: Option<Account<'info, Term>>,
+    
     #[account(
         init,
         payer = authority,
         space = Session::LEN,
-        seeds = [b"session", session_date.to_le_bytes().as_ref()],
+        seeds = [b"session", config.session_count.to_le_bytes().as_ref()],
         bump
     )]
     pub session: Account<'info, Session>,
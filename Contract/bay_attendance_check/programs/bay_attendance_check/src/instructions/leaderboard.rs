@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*};
+
+// 리더보드 생성 (term이 Pubkey::default()이면 전체, 아니면 해당 학기 리더보드)
+pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>, term: Pubkey) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    
+    require!(
+        ctx.accounts.config.has_permission(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_CREATE_SESSION
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    // 학기 리더보드는 실제 학기 계정이 있어야 생성 가능
+    if term != Pubkey::default() {
+        let term_account = ctx.accounts.term_account
+            .as_ref()
+            .ok_or(AttendanceError::InvalidLeaderboard)?;
+        require_keys_eq!(term_account.key(), term, AttendanceError::InvalidLeaderboard);
+    }
+    
+    leaderboard.term = term;
+    leaderboard.entries = Vec::new();
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.version = Leaderboard::VERSION;
+    
+    let term = (!leaderboard.is_global()).then_some(term);
+    
+    emit!(LeaderboardCreated {
+        leaderboard: leaderboard.key(),
+        term,
+    });
+    
+    msg!("Leaderboard initialized for term: {:?}", term);
+    
+    Ok(())
+}
+
+// remaining_accounts로 전달된 학회원(전체) 또는 학기별 통계(학기) 계정으로 리더보드 갱신
+// 누구나 호출 가능 (정정/초기화 등으로 리더보드가 최신이 아닐 때 보정용)
+pub fn refresh_leaderboard<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RefreshLeaderboard<'info>>,
+) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    
+    for info in ctx.remaining_accounts {
+        if leaderboard.is_global() {
+            let member: Account<'info, Member> = Account::try_from(info)?;
+            leaderboard.record(member.wallet, member.total_points, member.attendance_rate_bps());
+        } else {
+            let term_stats: Account<'info, TermStats> = Account::try_from(info)?;
+            require_keys_eq!(term_stats.term, leaderboard.term, AttendanceError::InvalidLeaderboard);
+            leaderboard.record(term_stats.member, term_stats.total_points, term_stats.attendance_rate_bps());
+        }
+    }
+    
+    msg!("Leaderboard refreshed with {} accounts", ctx.remaining_accounts.len());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(term: Pubkey)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 운영진 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    // 학기 리더보드인 경우 대상 학기
+    #[account(
+        seeds = [b"term", term_account.id.to_le_bytes().as_ref()],
+        bump = term_account.bump
+    )]
+    pub term_account: Option<Account<'info, Term>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Leaderboard::LEN,
+        seeds = [b"leaderboard", term.as_ref()],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefreshLeaderboard<'info> {
+    #[account(
+        mut,
+        seeds = [b"leaderboard", leaderboard.term.as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+}
\ No newline at end of file
@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*};
+
+// 학회원 활성/비활성 전환 (졸업, 휴학 등)
+pub fn set_member_active(ctx: Context<SetMemberActive>, is_active: bool) -> Result<()> {
+    let member = &mut ctx.accounts.member;
+    
+    // 최고 관리자 또는 학회원 관리 권한이 있는 학회원만 변경 가능
+    require!(
+        ctx.accounts.config.has_permission(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_MANAGE_MEMBERS
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    // 자신보다 강한 역할의 학회원은 변경할 수 없음
+    require!(
+        ctx.accounts.config.can_grant(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            &member.role
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    member.is_active = is_active;
+    
+    emit!(MemberStatusChanged {
+        member: member.key(),
+        wallet: member.wallet,
+        is_active,
+        changed_by: ctx.accounts.authority.key(),
+    });
+    
+    msg!("Member {} active status updated to: {}", member.wallet, is_active);
+    
+    Ok(())
+}
+
+// 학회원 역할 변경 (신임 운영진 선출 등)
+pub fn change_member_role(ctx: Context<ChangeMemberRole>, role: MemberRole) -> Result<()> {
+    let member = &mut ctx.accounts.member;
+    
+    require!(
+        ctx.accounts.config.has_permission(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_MANAGE_MEMBERS
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    // 자신이 가진 권한보다 강한 역할은 부여/회수할 수 없음
+    let config = &ctx.accounts.config;
+    let authority = ctx.accounts.authority.key();
+    require!(
+        config.can_grant(&authority, ctx.accounts.admin.as_deref(), &role)
+            && config.can_grant(&authority, ctx.accounts.admin.as_deref(), &member.role),
+        AttendanceError::Unauthorized
+    );
+    
+    let previous_role = member.role.clone();
+    member.role = role;
+    
+    emit!(MemberRoleChanged {
+        member: member.key(),
+        wallet: member.wallet,
+        previous_role: previous_role.clone(),
+        role: member.role.clone(),
+        changed_by: ctx.accounts.authority.key(),
+    });
+    
+    msg!("Member {} role changed: {:?} -> {:?}", member.wallet, previous_role, member.role);
+    
+    Ok(())
+}
+
+// 비활성화된 학회원 계정 종료 및 rent 환불 (등록 시 rent를 지불한 지갑에게 반환)
+// remaining_accounts로 전달된 전체/학기 리더보드에서 학회원을 제외
+// (전달하지 않은 리더보드에는 종료된 학회원 항목이 남으므로 학회원이 순위에 오른 리더보드를 모두 전달)
+pub fn close_member<'info>(ctx: Context<'_, '_, 'info, 'info, CloseMember<'info>>) -> Result<()> {
+    let member = &ctx.accounts.member;
+    
+    require!(
+        ctx.accounts.config.has_permission(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_MANAGE_MEMBERS
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    require!(
+        ctx.accounts.config.can_grant(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            &member.role
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    // 실수로 활동 중인 학회원을 삭제하지 않도록 비활성화 후에만 종료 가능
+    require!(
+        !member.is_active,
+        AttendanceError::MemberStillActive
+    );
+    
+    // 팀 소속 학회원 수가 맞도록 팀에서 제외한 후에만 종료 가능
+    require!(
+        member.team_id.is_none(),
+        AttendanceError::MemberStillInTeam
+    );
+    
+    for info in ctx.remaining_accounts {
+        require!(info.is_writable, AttendanceError::InvalidRemainingAccounts);
+        
+        let mut leaderboard: Account<'info, Leaderboard> = Account::try_from(info)?;
+        if leaderboard.remove(&member.wallet) {
+            leaderboard.exit(ctx.program_id)?;
+        }
+    }
+    
+    // 남아 있는 출석 기록은 세션 초기화/취소 시 학회원 통계 없이 종료됨
+    // (close_attendance_record에 빈 학회원 PDA 전달)
+    emit!(MemberClosed {
+        member: member.key(),
+        wallet: member.wallet,
+        closed_by: ctx.accounts.authority.key(),
+        refunded_to: ctx.accounts.payer.key(),
+    });
+    
+    msg!("Member account closed: {}", member.wallet);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMemberActive<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 Admin 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", member.wallet.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+}
+
+#[derive(Accounts)]
+pub struct ChangeMemberRole<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 Admin 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", member.wallet.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+}
+
+#[derive(Accounts)]
+pub struct CloseMember<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 Admin 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"member", member.wallet.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    /// CHECK: 학회원 등록 시 rent를 지불한 지갑 (rent 환불 대상)
+    #[account(
+        mut,
+        address = member.rent_payer(&config) @ AttendanceError::Unauthorized
+    )]
+    pub payer: UncheckedAccount<'info>,
+}
\ No newline at end of file
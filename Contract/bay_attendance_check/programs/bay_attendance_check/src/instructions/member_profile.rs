@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*};
+
+// 학회원 프로필 변경 (본인 또는 학회원 관리 권한이 있는 운영진)
+pub fn update_member_profile(
+    ctx: Context<UpdateMemberProfile>,
+    profile: MemberProfile,
+) -> Result<()> {
+    let member = &mut ctx.accounts.member;
+    let authority = ctx.accounts.authority.key();
+    
+    require!(
+        member.wallet == authority
+            || ctx.accounts.config.has_permission(
+                &authority,
+                ctx.accounts.admin.as_deref(),
+                PERMISSION_MANAGE_MEMBERS
+            ),
+        AttendanceError::Unauthorized
+    );
+    
+    require!(
+        profile.display_name.len() <= Member::MAX_DISPLAY_NAME_LEN,
+        AttendanceError::DisplayNameTooLong
+    );
+    
+    require!(
+        profile.profile_uri.len() <= Member::MAX_PROFILE_URI_LEN,
+        AttendanceError::ProfileUriTooLong
+    );
+    
+    member.display_name = profile.display_name;
+    member.cohort = profile.cohort;
+    member.profile_uri = profile.profile_uri;
+    
+    emit!(MemberProfileUpdated {
+        member: member.key(),
+        wallet: member.wallet,
+        display_name: member.display_name.clone(),
+        cohort: member.cohort,
+        updated_by: authority,
+    });
+    
+    msg!("Member profile updated: {} ({})", member.wallet, member.display_name);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMemberProfile<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 본인이 아닌 경우 authority의 운영진 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", member.wallet.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+}
\ No newline at end of file
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*, utils::{grow_account, load_for_migration}};
+
+// 이전 레이아웃 계정을 현재 레이아웃으로 확장하고 새 필드 기본값 설정
+// (누구나 rent 차액을 지불하고 호출 가능, 이미 최신 계정이면 변경 없음)
+// - baseline 계정(버전 필드 없음): 크기로 구분하여 *V0 레이아웃으로 읽은 뒤 필드별 변환
+// - 버전 1 이상 계정: 새 필드가 reserved 자리에 추가되므로 0으로 확장해 그대로 읽음
+
+pub fn migrate_config(ctx: Context<MigrateAccount>) -> Result<()> {
+    let previous_len = ctx.accounts.account.data_len();
+    let mut config: Config = ctx.accounts.load(ctx.program_id, Config::LEN)?;
+    ctx.accounts.verify_address(&[b"config", &[config.bump]], ctx.program_id)?;
+    
+    let previous_version = config.version;
+    if previous_version == 0 {
+        // 역할별 권한이 없던 계정은 기본 권한 적용
+        if config.role_permissions == [0; MemberRole::COUNT] {
+            config.role_permissions = Config::DEFAULT_ROLE_PERMISSIONS;
+        }
+    }
+    if previous_version < 2 {
+        // 세션 시간 검증 기준이 없던 계정은 KST 기본값 적용
+        config.session_time_rules = SessionTimeRules::DEFAULT;
+    }
+    // 버전 3 미만 계정은 point_mint가 비어 있으므로 set_point_mint 호출 전까지 포인트 발행 불가
+    config.version = Config::VERSION;
+    
+    ctx.accounts.store(&config, previous_version, config.version, previous_len)
+}
+
+pub fn migrate_session(ctx: Context<MigrateAccount>) -> Result<()> {
+    let previous_len = ctx.accounts.account.data_len();
+    let (mut session, previous_version) = match ctx.accounts.load_legacy::<SessionV0>(
+        ctx.program_id,
+        Session::DISCRIMINATOR,
+        SessionV0::LEN,
+        Session::LEN,
+    )? {
+        // baseline 세션 PDA는 세션 날짜 기반 ([b"session", session_date])
+        Some(legacy) => (legacy.into_current(), 0),
+        None => {
+            let session: Session = ctx.accounts.load(ctx.program_id, Session::LEN)?;
+            let version = session.version;
+            (session, version)
+        }
+    };
+    ctx.accounts.verify_address(
+        &[b"session", session.id.to_le_bytes().as_ref(), &[session.bump]],
+        ctx.program_id,
+    )?;
+    session.version = Session::VERSION;
+    
+    ctx.accounts.store(&session, previous_version, session.version, previous_len)
+}
+
+pub fn migrate_member(ctx: Context<MigrateAccount>) -> Result<()> {
+    let previous_len = ctx.accounts.account.data_len();
+    let (mut member, previous_version) = match ctx.accounts.load_legacy::<MemberV0>(
+        ctx.program_id,
+        Member::DISCRIMINATOR,
+        MemberV0::LEN,
+        Member::LEN,
+    )? {
+        Some(legacy) => (legacy.into_current(), 0),
+        None => {
+            let member: Member = ctx.accounts.load(ctx.program_id, Member::LEN)?;
+            let version = member.version;
+            (member, version)
+        }
+    };
+    ctx.accounts.verify_address(
+        &[b"member", member.wallet.as_ref(), &[member.bump]],
+        ctx.program_id,
+    )?;
+    
+    member.version = Member::VERSION;
+    
+    ctx.accounts.store(&member, previous_version, member.version, previous_len)
+}
+
+pub fn migrate_attendance_record(ctx: Context<MigrateAccount>) -> Result<()> {
+    let previous_len = ctx.accounts.account.data_len();
+    let (mut record, previous_version) = match ctx.accounts.load_legacy::<AttendanceRecordV0>(
+        ctx.program_id,
+        AttendanceRecord::DISCRIMINATOR,
+        AttendanceRecordV0::LEN,
+        AttendanceRecord::LEN,
+    )? {
+        Some(legacy) => (legacy.into_current(), 0),
+        None => {
+            let record: AttendanceRecord = ctx.accounts.load(ctx.program_id, AttendanceRecord::LEN)?;
+            let version = record.version;
+            (record, version)
+        }
+    };
+    ctx.accounts.verify_address(
+        &[b"attendance", record.session.as_ref(), record.member.as_ref(), &[record.bump]],
+        ctx.program_id,
+    )?;
+    if previous_version > 0 && previous_version < 3 {
+        // 버전 1~2 기록은 체크인/정정 시 포인트만큼 발행했으므로 현재 포인트를 발행 최고치로 간주
+        record.points_minted = record.points_earned;
+    }
+    record.version = AttendanceRecord::VERSION;
+    
+    ctx.accounts.store(&record, previous_version, record.version, previous_len)
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: 이전 레이아웃 계정 (역직렬화 불가할 수 있어 핸들러에서 owner/discriminator/PDA 검증)
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateAccount<'info> {
+    fn load<T: AccountDeserialize + Discriminator>(&self, program_id: &Pubkey, len: usize) -> Result<T> {
+        load_for_migration(
+            &self.payer.to_account_info(),
+            &self.account.to_account_info(),
+            &self.system_program.to_account_info(),
+            program_id,
+            len,
+        )
+    }
+    
+    // baseline 크기의 계정이면 *V0 레이아웃으로 읽은 뒤 현재 크기로 확장 (아니면 None)
+    fn load_legacy<T: AnchorDeserialize>(
+        &self,
+        program_id: &Pubkey,
+        discriminator: &[u8],
+        legacy_len: usize,
+        len: usize,
+    ) -> Result<Option<T>> {
+        let account = self.account.to_account_info();
+        require_keys_eq!(
+            *account.owner,
+            *program_id,
+            AttendanceError::InvalidMigrationAccount
+        );
+        if account.data_len() != legacy_len {
+            return Ok(None);
+        }
+        
+        let legacy = {
+            let data = account.try_borrow_data()?;
+            require!(
+                data.starts_with(discriminator),
+                AttendanceError::InvalidMigrationAccount
+            );
+            T::deserialize(&mut &data[discriminator.len()..])
+                .map_err(|_| AttendanceError::InvalidMigrationAccount)?
+        };
+        
+        grow_account(
+            &self.payer.to_account_info(),
+            &account,
+            &self.system_program.to_account_info(),
+            len,
+        )?;
+        Ok(Some(legacy))
+    }
+    
+    // 계정 데이터에 저장된 bump로 PDA 주소 검증
+    fn verify_address(&self, seeds: &[&[u8]], program_id: &Pubkey) -> Result<()> {
+        let expected = Pubkey::create_program_address(seeds, program_id)
+            .map_err(|_| AttendanceError::InvalidMigrationAccount)?;
+        require_keys_eq!(
+            self.account.key(),
+            expected,
+            AttendanceError::InvalidMigrationAccount
+        );
+        Ok(())
+    }
+    
+    fn store<T: AccountSerialize>(
+        &self,
+        account: &T,
+        previous_version: u8,
+        version: u8,
+        previous_len: usize,
+    ) -> Result<()> {
+        let new_len = self.account.data_len();
+        if previous_version == version && previous_len == new_len {
+            msg!("Account already up to date: {}", self.account.key());
+            return Ok(());
+        }
+        
+        account.try_serialize(&mut &mut self.account.try_borrow_mut_data()?[..])?;
+        
+        emit!(AccountMigrated {
+            account: self.account.key(),
+            previous_version,
+            version,
+            previous_len: previous_len as u32,
+            new_len: new_len as u32,
+        });
+        
+        msg!(
+            "Account migrated: {} (v{} -> v{}, {} -> {} bytes)",
+            self.account.key(),
+            previous_version,
+            version,
+            previous_len,
+            new_len
+        );
+        
+        Ok(())
+    }
+}
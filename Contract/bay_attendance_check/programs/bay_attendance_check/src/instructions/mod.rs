@@ -1,13 +1,47 @@
+pub mod initialize_config;
 pub mod initialize_member;
 pub mod initialize_session;
 pub mod check_in;
 pub mod update_session;
 pub mod get_stats;
 pub mod reactivate_session;
+pub mod finalize_session;
+pub mod set_co_hosts;
+pub mod point_schedule;
+pub mod set_presence_mode;
+pub mod record_attendance;
+pub mod excuse;
+pub mod manage_member;
+pub mod role_permissions;
+pub mod member_profile;
+pub mod migrate;
+pub mod term;
+pub mod session_time_rules;
+pub mod team;
+pub mod leaderboard;
+pub mod series;
+pub mod cancel_session;
 
+pub use initialize_config::*;
 pub use initialize_member::*;
 pub use initialize_session::*;
 pub use check_in::*;
 pub use update_session::*;
 pub use get_stats::*;
-pub use reactivate_session::*;
\ No newline at end of file
+pub use reactivate_session::*;
+pub use finalize_session::*;
+pub use set_co_hosts::*;
+pub use point_schedule::*;
+pub use set_presence_mode::*;
+pub use record_attendance::*;
+pub use excuse::*;
+pub use manage_member::*;
+pub use role_permissions::*;
+pub use member_profile::*;
+pub use migrate::*;
+pub use term::*;
+pub use session_time_rules::*;
+pub use team::*;
+pub use leaderboard::*;
+pub use series::*;
+pub use cancel_session::*;
\ No newline at end of file
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*};
+
+// 새로 생성되는 세션에 적용할 기본 포인트 지급 기준 변경 (최고 관리자만 가능)
+pub fn set_point_schedule(
+    ctx: Context<SetPointSchedule>,
+    point_schedule: PointSchedule,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        point_schedule.is_valid(),
+        AttendanceError::InvalidPointSchedule
+    );
+    
+    config.point_schedule = point_schedule;
+    
+    emit!(PointScheduleUpdated {
+        session: None,
+        point_schedule: config.point_schedule.clone(),
+        point_multiplier: Session::DEFAULT_POINT_MULTIPLIER,
+    });
+    
+    msg!("Default point schedule updated: {:?}", config.point_schedule);
+    
+    Ok(())
+}
+
+// 연속 출석 규칙과 마일스톤 보너스 변경 (최고 관리자만 가능)
+pub fn set_streak_rules(ctx: Context<SetStreakRules>, streak_rules: StreakRules) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    // 사용하는 마일스톤은 연속 횟수가 서로 달라야 하고 보너스는 상한 이하
+    let milestones = &streak_rules.milestones;
+    for (i, milestone) in milestones.iter().enumerate() {
+        require!(
+            milestone.streak == 0
+                || milestones[i + 1..].iter().all(|other| other.streak != milestone.streak),
+            AttendanceError::InvalidStreakRules
+        );
+        require!(
+            milestone.bonus_points <= StreakRules::MAX_BONUS_POINTS,
+            AttendanceError::InvalidStreakRules
+        );
+    }
+    
+    config.streak_rules = streak_rules;
+    
+    emit!(StreakRulesUpdated {
+        streak_rules: config.streak_rules.clone(),
+    });
+    
+    msg!("Streak rules updated: {:?}", config.streak_rules);
+    
+    Ok(())
+}
+
+// 특정 세션의 포인트 지급 기준과 배율 변경 (세션 진행자만, 체크인 시작 전에만 가능)
+pub fn set_session_rewards(
+    ctx: Context<SetSessionRewards>,
+    point_schedule: Option<PointSchedule>,
+    point_multiplier: u16,
+) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    
+    require!(
+        session.is_host(&ctx.accounts.authority.key()),
+        AttendanceError::NotSessionHost
+    );
+    
+    require!(
+        !session.is_finalized,
+        AttendanceError::SessionFinalized
+    );
+    
+    require!(
+        !session.is_cancelled,
+        AttendanceError::SessionCancelled
+    );
+    
+    // 이미 체크인한 학회원과 이후 학회원의 포인트 기준이 달라지지 않도록 체크인 시작 전에만 변경
+    require!(
+        !session.check_in_started(Clock::get()?.unix_timestamp),
+        AttendanceError::RewardsLocked
+    );
+    
+    require!(
+        point_multiplier > 0 && point_multiplier <= Session::MAX_POINT_MULTIPLIER,
+        AttendanceError::InvalidPointMultiplier
+    );
+    
+    if let Some(point_schedule) = point_schedule {
+        require!(
+            point_schedule.is_valid(),
+            AttendanceError::InvalidPointSchedule
+        );
+        session.point_schedule = point_schedule;
+    }
+    session.point_multiplier = point_multiplier;
+    
+    emit!(PointScheduleUpdated {
+        session: Some(session.key()),
+        point_schedule: session.point_schedule.clone(),
+        point_multiplier,
+    });
+    
+    msg!("Session rewards updated: {:?}, multiplier: {}", session.point_schedule, point_multiplier);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPointSchedule<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.super_admin == authority.key() @ AttendanceError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct SetStreakRules<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.super_admin == authority.key() @ AttendanceError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct SetSessionRewards<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive,
+        constraint = config.member_has_permission(&admin, PERMISSION_CREATE_SESSION) @ AttendanceError::Unauthorized
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Account<'info, Session>,
+}
@@ -1,37 +1,97 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::AttendanceError};
+use crate::{state::*, errors::AttendanceError, events::*, utils::close_attendance_record};
 
-pub fn reactivate_session(
-    ctx: Context<ReactivateSession>,
+// 세션 재활성화
+// - Extend: 기존 통계와 출석 기록을 유지한 채 시간만 변경
+// - Reset: remaining_accounts로 전달된 [attendance_record, member, payer] 묶음의 출석 기록을 종료하고
+//   세션/학회원 통계와 포인트를 되돌린 뒤 rent를 payer에게 환불 (많을 경우 여러 번 나누어 호출)
+//   학기에 속한 세션은 [attendance_record, member, payer, term_stats] 묶음
+//   출석 기록에 팀이 있으면 묶음 끝에 해당 팀 계정 추가
+//   이미 발행된 BAY 토큰은 학회원 지갑에 남고 이후 발행분에서 차감되며, 전달된 리더보드에 되돌린 통계 반영
+pub fn reactivate_session<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReactivateSession<'info>>,
+    mode: ReactivateMode,
+    new_open_time: i64,
     new_start_time: i64,
     new_late_time: i64,
 ) -> Result<()> {
     let session = &mut ctx.accounts.session;
     let admin = &ctx.accounts.admin;
     
-    // Admin 권한 확인
+    // 세션 생성/운영 권한 확인
     require!(
-        admin.role == MemberRole::Admin,
+        ctx.accounts.config.member_has_permission(admin, PERMISSION_CREATE_SESSION),
         AttendanceError::Unauthorized
     );
     
+    // 세션 진행자(생성자 또는 공동 진행자) 확인
+    require!(
+        session.is_host(&ctx.accounts.authority.key()),
+        AttendanceError::NotSessionHost
+    );
+    
+    // 마감된 세션은 변경 불가
+    require!(
+        !session.is_finalized,
+        AttendanceError::SessionFinalized
+    );
+    
+    // 취소된 세션은 변경 불가
+    require!(
+        !session.is_cancelled,
+        AttendanceError::SessionCancelled
+    );
+    
     // 시간 파라미터 유효성 검사
     require!(
-        new_start_time < new_late_time,
+        Session::valid_times(new_open_time, new_start_time, new_late_time),
         AttendanceError::InvalidTimeParameters
     );
+    ctx.accounts.config.session_time_rules.validate(
+        session.session_date,
+        new_open_time,
+        new_start_time,
+        new_late_time,
+        Clock::get()?.unix_timestamp,
+    )?;
+    
+    let mut records_reset: u32 = 0;
+    
+    if mode == ReactivateMode::Reset {
+        let mut remaining_accounts = ctx.remaining_accounts.iter();
+        
+        while let Some(record_info) = remaining_accounts.next() {
+            let wallet = close_attendance_record(
+                &mut remaining_accounts,
+                record_info,
+                session,
+                ctx.accounts.leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+                ctx.accounts.term_leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+                ctx.program_id,
+            )?;
+            
+            records_reset += 1;
+            msg!("Attendance record reset: {}", wallet);
+        }
+    }
     
     // 세션 정보 업데이트
+    session.open_time = new_open_time;
     session.start_time = new_start_time;
     session.late_time = new_late_time;
-    session.is_active = true;
+    session.state_override = SessionOverride::Auto;
     
-    // 통계 초기화 (선택사항)
-    session.total_attendees = 0;
-    session.total_late = 0;
+    emit!(SessionReactivated {
+        session: session.key(),
+        mode: mode.clone(),
+        open_time: new_open_time,
+        start_time: new_start_time,
+        late_time: new_late_time,
+        records_reset,
+    });
     
-    msg!("Session reactivated with new times");
-    msg!("Start time: {}, Late time: {}", new_start_time, new_late_time);
+    msg!("Session reactivated with new times ({:?})", mode);
+    msg!("Open time: {}, Start time: {}, Late time: {}", new_open_time, new_start_time, new_late_time);
     
     Ok(())
 }
@@ -41,6 +101,12 @@ pub struct ReactivateSession<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
     #[account(
         seeds = [b"member", authority.key().as_ref()],
         bump = admin.bump,
@@ -48,6 +114,26 @@ pub struct ReactivateSession<'info> {
     )]
     pub admin: Account<'info, Member>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
     pub session: Account<'info, Session>,
+    
+    // 전체 리더보드 (선택, Reset 모드에서 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", leaderboard.term.as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    // 세션이 속한 학기의 리더보드 (선택, Reset 모드에서 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", term_leaderboard.term.as_ref()],
+        bump = term_leaderboard.bump
+    )]
+    pub term_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
 }
\ No newline at end of file
@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::{state::*, errors::AttendanceError, events::*, utils::{mint_points, team_for, term_stats_for, update_leaderboards}};
+
+// 운영진이 학회원 대신 출석 기록을 생성하거나 기존 기록의 상태를 정정
+// (휴대폰 방전, 지갑 앱 오류 등으로 직접 체크인하지 못한 경우)
+pub fn record_attendance(
+    ctx: Context<RecordAttendance>,
+    status: AttendanceStatus,
+    reason: CorrectionReason,
+) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    let member = &mut ctx.accounts.member;
+    let attendance_record = &mut ctx.accounts.attendance_record;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // 취소된 세션에는 출석 기록 불가
+    require!(
+        !session.is_cancelled,
+        AttendanceError::SessionCancelled
+    );
+    
+    // 기존 기록이 있으면 이전 상태와 포인트를 통계에서 되돌림
+    let is_new_record = attendance_record.session == Pubkey::default();
+    let previous_status = if is_new_record {
+        attendance_record.member = member.wallet;
+        attendance_record.session = session.key();
+        attendance_record.check_in_time = current_time;
+        attendance_record.payer = ctx.accounts.authority.key();
+        attendance_record.bump = ctx.bumps.attendance_record;
+        attendance_record.version = AttendanceRecord::VERSION;
+        attendance_record.team_id = member.team_id;
+        attendance_record.points_minted = 0;
+        None
+    } else {
+        session.revert_status(&attendance_record.status)?;
+        member.revert_status(&attendance_record.status, attendance_record.points_earned)?;
+        if let Some(term_stats) = term_stats_for(&mut ctx.accounts.term_stats, session, &member.wallet)? {
+            term_stats.revert_status(&attendance_record.status, attendance_record.points_earned)?;
+        }
+        if let Some(team) = team_for(&mut ctx.accounts.team, attendance_record.team_id)? {
+            team.revert_status(&attendance_record.status, attendance_record.points_earned)?;
+        }
+        Some(attendance_record.status.clone())
+    };
+    
+    // 최초 체크인 시간 기준으로 포인트 재계산
+    // (연속 출석 기록과 마일스톤 보너스는 정정 시 다시 계산하지 않음)
+    let points = session.points_for(&status, attendance_record.check_in_time)?;
+    
+    attendance_record.status = status.clone();
+    attendance_record.points_earned = points;
+    attendance_record.corrected_by = Some(ctx.accounts.authority.key());
+    attendance_record.correction_reason = Some(reason.clone());
+    
+    session.record_status(&status)?;
+    member.record_status(&status, points)?;
+    if let Some(term_stats) = term_stats_for(&mut ctx.accounts.term_stats, session, &member.wallet)? {
+        term_stats.record_status(&status, points)?;
+    }
+    if let Some(team) = team_for(&mut ctx.accounts.team, attendance_record.team_id)? {
+        team.record_status(&status, points)?;
+    }
+    update_leaderboards(
+        ctx.accounts.leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+        ctx.accounts.term_leaderboard.as_deref_mut().map(|leaderboard| &mut **leaderboard),
+        session,
+        member,
+        ctx.accounts.term_stats.as_deref(),
+    )?;
+    
+    // 이 기록으로 발행된 최고치를 넘는 포인트만 BAY 토큰 추가 발행
+    // (이미 발행된 토큰은 회수할 수 없으므로 포인트가 줄어든 경우 통계만 차감,
+    // 종료된 기록으로 이미 발행된 적립분도 제외)
+    let unminted_points = attendance_record.take_unminted_points(points);
+    let minted_points = member.take_mint_amount(unminted_points);
+    if minted_points > 0 {
+        mint_points(
+            &ctx.accounts.token_program,
+            &ctx.accounts.point_mint,
+            &ctx.accounts.member_token_account,
+            &ctx.accounts.mint_authority,
+            ctx.bumps.mint_authority,
+            minted_points,
+        )?;
+    }
+    
+    emit!(AttendanceCorrected {
+        session: session.key(),
+        member: member.wallet,
+        previous_status: previous_status.clone(),
+        status: status.clone(),
+        points,
+        reason: reason.clone(),
+        corrected_by: ctx.accounts.authority.key(),
+    });
+    
+    msg!("Attendance recorded by admin for member: {}", member.wallet);
+    msg!("Status: {:?} -> {:?}, Reason: {:?}, Points: {}", previous_status, status, reason, points);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecordAttendance<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive,
+        constraint = config.member_has_permission(&admin, PERMISSION_CORRECT_ATTENDANCE) @ AttendanceError::Unauthorized
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", member.wallet.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    /// CHECK: 출석 처리 대상 학회원 지갑 (토큰 계정 소유자)
+    #[account(address = member.wallet @ AttendanceError::Unauthorized)]
+    pub member_wallet: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Account<'info, Session>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AttendanceRecord::LEN,
+        seeds = [
+            b"attendance",
+            session.key().as_ref(),
+            member.wallet.as_ref()
+        ],
+        bump
+    )]
+    pub attendance_record: Account<'info, AttendanceRecord>,
+    
+    // 세션이 학기에 속한 경우 학회원의 학기별 통계
+    #[account(
+        mut,
+        seeds = [b"term_stats", term_stats.term.as_ref(), member.wallet.as_ref()],
+        bump = term_stats.bump
+    )]
+    pub term_stats: Option<Account<'info, TermStats>>,
+    
+    // 출석 기록 당시 학회원이 속한 팀 (팀별 출석 집계)
+    #[account(
+        mut,
+        seeds = [b"team", team.id.to_le_bytes().as_ref()],
+        bump = team.bump
+    )]
+    pub team: Option<Account<'info, Team>>,
+    
+    // 전체 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", leaderboard.term.as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    // 세션이 속한 학기의 리더보드 (선택, 전달 시 순위 갱신)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", term_leaderboard.term.as_ref()],
+        bump = term_leaderboard.bump
+    )]
+    pub term_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    
+    /// CHECK: BAY 토큰 mint authority PDA (데이터 없음, 서명 전용)
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        address = config.point_mint @ AttendanceError::InvalidPointMint,
+        mint::authority = mint_authority
+    )]
+    pub point_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = point_mint,
+        associated_token::authority = member_wallet
+    )]
+    pub member_token_account: Box<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*};
+
+// 역할별 권한 비트마스크 변경 (최고 관리자만 가능)
+pub fn set_role_permissions(
+    ctx: Context<SetRolePermissions>,
+    role: MemberRole,
+    permissions: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        permissions & !PERMISSION_ALL == 0,
+        AttendanceError::InvalidPermissions
+    );
+    
+    config.role_permissions[role.index()] = permissions;
+    
+    emit!(RolePermissionsUpdated {
+        role: role.clone(),
+        permissions,
+    });
+    
+    msg!("Permissions for {:?} updated: {:#07b}", role, permissions);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRolePermissions<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.super_admin == authority.key() @ AttendanceError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+}
\ No newline at end of file
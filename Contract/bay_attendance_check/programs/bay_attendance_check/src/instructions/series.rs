@@ -0,0 +1,402 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*, utils::create_pda_account};
+
+// 반복 세션 일정 생성 (세션은 create_series_sessions로 나누어 생성)
+pub fn create_session_series(
+    ctx: Context<CreateSessionSeries>,
+    title: String,
+    kind: SessionKind,
+    schedule: SeriesSchedule,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let series = &mut ctx.accounts.series;
+    let rules = &config.session_time_rules;
+    
+    // 세션 생성/운영 권한 확인
+    require!(
+        config.member_has_permission(&ctx.accounts.admin, PERMISSION_CREATE_SESSION),
+        AttendanceError::Unauthorized
+    );
+    
+    require!(
+        title.len() <= Session::MAX_TITLE_LEN,
+        AttendanceError::TitleTooLong
+    );
+    
+    require!(
+        schedule.is_valid(),
+        AttendanceError::InvalidSeriesSchedule
+    );
+    
+    require!(
+        rules.max_late_window == 0 || schedule.late_window <= rules.max_late_window,
+        AttendanceError::LateWindowTooLong
+    );
+    
+    // 시작일 이후 첫 해당 요일
+    let days_until_weekday = (schedule.weekday + 7 - rules.weekday(schedule.start_date)) % 7;
+    let first_date = rules.day_start(schedule.start_date)
+        + days_until_weekday as i64 * SessionTimeRules::SECONDS_PER_DAY;
+    
+    series.id = config.series_count;
+    series.title = title;
+    series.kind = kind;
+    series.admin = ctx.accounts.authority.key();
+    series.term = ctx.accounts.term.as_ref().map(|term| term.key());
+    series.schedule = schedule;
+    series.first_date = first_date;
+    series.next_occurrence = 0;
+    series.session_ids = Vec::new();
+    series.bump = ctx.bumps.series;
+    series.version = SessionSeries::VERSION;
+    
+    // 마지막 회차가 이미 지났으면 생성할 세션이 없음
+    let last_date = series.occurrence_date(series.schedule.occurrence_count - 1);
+    let (_, _, last_late_time) = series.times_on(last_date);
+    require!(
+        last_late_time > Clock::get()?.unix_timestamp,
+        AttendanceError::SessionAlreadyEnded
+    );
+    
+    // 학기에 속한 일정이면 모든 회차가 학기 기간 안에 있어야 함
+    if let Some(term) = ctx.accounts.term.as_ref() {
+        require!(
+            term.is_active,
+            AttendanceError::TermNotActive
+        );
+        require!(
+            term.contains(first_date) && term.contains(last_date),
+            AttendanceError::SessionOutsideTerm
+        );
+    }
+    
+    config.series_count = config.series_count
+        .checked_add(1)
+        .ok_or(AttendanceError::Overflow)?;
+    
+    emit!(SessionSeriesCreated {
+        series: series.key(),
+        series_id: series.id,
+        title: series.title.clone(),
+        schedule: series.schedule.clone(),
+        first_date,
+        term: series.term,
+    });
+    
+    msg!("Session series {} created: {} occurrences from {}", series.id, series.schedule.occurrence_count, first_date);
+    
+    Ok(())
+}
+
+// 반복 세션 일정의 다음 회차 세션들을 생성 (여러 번 나누어 호출, next_occurrence부터 재개)
+// remaining_accounts: 생성할 세션 PDA 목록 (Config.session_count부터 순서대로)
+// 이미 지난 회차는 세션 없이 건너뜀
+pub fn create_series_sessions<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateSeriesSessions<'info>>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let series = &mut ctx.accounts.series;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    require!(
+        config.member_has_permission(&ctx.accounts.admin, PERMISSION_CREATE_SESSION),
+        AttendanceError::Unauthorized
+    );
+    
+    // 일정을 만든 운영진(생성되는 세션의 admin) 또는 최고 관리자만 세션 생성 가능
+    require!(
+        series.is_manager(config, &ctx.accounts.authority.key()),
+        AttendanceError::NotSessionHost
+    );
+    
+    require!(
+        ctx.accounts.term.as_ref().map(|term| term.key()) == series.term,
+        AttendanceError::SessionOutsideTerm
+    );
+    if let Some(term) = ctx.accounts.term.as_ref() {
+        require!(
+            term.is_active,
+            AttendanceError::TermNotActive
+        );
+    }
+    
+    let series_key = series.key();
+    let mut created: u32 = 0;
+    
+    for session_info in ctx.remaining_accounts {
+        // 이미 지난 회차 건너뛰기
+        while !series.is_complete() {
+            let (_, _, late_time) = series.times_on(series.occurrence_date(series.next_occurrence));
+            if late_time > current_time {
+                break;
+            }
+            msg!("Skipping past occurrence {}", series.next_occurrence);
+            series.next_occurrence += 1;
+        }
+        require!(
+            !series.is_complete(),
+            AttendanceError::SeriesComplete
+        );
+        
+        let session_date = series.occurrence_date(series.next_occurrence);
+        let (open_time, start_time, late_time) = series.times_on(session_date);
+        config.session_time_rules.validate(session_date, open_time, start_time, late_time, current_time)?;
+        
+        let session_id = config.session_count;
+        let (session_key, session_bump) = Pubkey::find_program_address(
+            &[b"session", session_id.to_le_bytes().as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            session_info.key(),
+            session_key,
+            AttendanceError::InvalidRemainingAccounts
+        );
+        
+        create_pda_account(
+            &ctx.accounts.authority.to_account_info(),
+            session_info,
+            &ctx.accounts.system_program.to_account_info(),
+            Session::LEN,
+            &[b"session", session_id.to_le_bytes().as_ref(), &[session_bump]],
+            ctx.program_id,
+        )?;
+        
+        let session = Session {
+            id: session_id,
+            title: series.title.clone(),
+            kind: series.kind.clone(),
+            admin: series.admin,
+            co_hosts: Vec::new(),
+            session_date,
+            start_time,
+            late_time,
+            total_attendees: 0,
+            total_late: 0,
+            total_absent: 0,
+            total_excused: 0,
+            state_override: SessionOverride::Auto,
+            is_finalized: false,
+            point_schedule: config.point_schedule.clone(),
+            point_multiplier: Session::DEFAULT_POINT_MULTIPLIER,
+            presence_window: 0,
+            bump: session_bump,
+            version: Session::VERSION,
+            term: series.term,
+            open_time,
+            series: Some(series_key),
+            is_cancelled: false,
+            reserved: [0; Session::RESERVED_LEN],
+        };
+        session.try_serialize(&mut &mut session_info.try_borrow_mut_data()?[..])?;
+        
+        config.session_count = config.session_count
+            .checked_add(1)
+            .ok_or(AttendanceError::Overflow)?;
+        if let Some(term) = ctx.accounts.term.as_mut() {
+            term.total_sessions = term.total_sessions
+                .checked_add(1)
+                .ok_or(AttendanceError::Overflow)?;
+        }
+        series.session_ids.push(session_id);
+        series.next_occurrence += 1;
+        created += 1;
+        
+        emit!(SessionCreated {
+            session: session_key,
+            session_id,
+            title: session.title.clone(),
+            kind: session.kind.clone(),
+            admin: session.admin,
+            session_date,
+            open_time,
+            start_time,
+            late_time,
+            term: session.term,
+        });
+    }
+    
+    msg!(
+        "Series {} sessions created: {}, next occurrence: {}/{}",
+        series.id,
+        created,
+        series.next_occurrence,
+        series.schedule.occurrence_count
+    );
+    
+    Ok(())
+}
+
+// 반복 세션 일정의 시작 시각/지각 허용 시간 변경
+// remaining_accounts로 전달된 일정의 세션 중 체크인이 시작되지 않은 세션과 앞으로 생성될 세션에 적용
+pub fn reschedule_series<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RescheduleSeries<'info>>,
+    start_time_of_day: u32,
+    late_window: u32,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let series = &mut ctx.accounts.series;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    require!(
+        config.member_has_permission(&ctx.accounts.admin, PERMISSION_CREATE_SESSION),
+        AttendanceError::Unauthorized
+    );
+    
+    // 세션 생성과 같은 기준 (일정을 만든 운영진 또는 최고 관리자)
+    require!(
+        series.is_manager(config, &ctx.accounts.authority.key()),
+        AttendanceError::NotSessionHost
+    );
+    
+    series.schedule.start_time_of_day = start_time_of_day;
+    series.schedule.late_window = late_window;
+    require!(
+        series.schedule.is_valid(),
+        AttendanceError::InvalidSeriesSchedule
+    );
+    
+    let series_key = series.key();
+    let mut sessions_updated: u32 = 0;
+    
+    for session_info in ctx.remaining_accounts {
+        require!(
+            session_info.is_writable,
+            AttendanceError::InvalidRemainingAccounts
+        );
+        
+        let mut session: Account<'info, Session> = Account::try_from(session_info)?;
+        require!(
+            session.series == Some(series_key),
+            AttendanceError::InvalidRemainingAccounts
+        );
+        
+        // 체크인이 시작되었거나 마감/취소된 세션은 그대로 둠
+        // (이미 체크인한 학회원과 다른 기준으로 출석 상태가 정해지지 않도록)
+        if session.check_in_started(current_time) {
+            msg!("Skipping started session {}", session.id);
+            continue;
+        }
+        
+        let (open_time, start_time, late_time) = series.times_on(session.session_date);
+        config.session_time_rules.validate(session.session_date, open_time, start_time, late_time, current_time)?;
+        
+        session.open_time = open_time;
+        session.start_time = start_time;
+        session.late_time = late_time;
+        session.exit(ctx.program_id)?;
+        
+        sessions_updated += 1;
+    }
+    
+    emit!(SessionSeriesRescheduled {
+        series: series_key,
+        start_time_of_day,
+        late_window,
+        sessions_updated,
+    });
+    
+    msg!("Series {} rescheduled, sessions updated: {}", series.id, sessions_updated);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateSessionSeries<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 일정의 세션이 속할 학기 (없으면 학기별 통계 미집계)
+    #[account(
+        seeds = [b"term", term.id.to_le_bytes().as_ref()],
+        bump = term.bump
+    )]
+    pub term: Option<Account<'info, Term>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = SessionSeries::LEN,
+        seeds = [b"series", config.series_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub series: Account<'info, SessionSeries>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateSeriesSessions<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"series", series.id.to_le_bytes().as_ref()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, SessionSeries>,
+    
+    // 일정이 학기에 속한 경우 해당 학기 (세션 수 집계)
+    #[account(
+        mut,
+        seeds = [b"term", term.id.to_le_bytes().as_ref()],
+        bump = term.bump
+    )]
+    pub term: Option<Account<'info, Term>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RescheduleSeries<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"series", series.id.to_le_bytes().as_ref()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, SessionSeries>,
+}
\ No newline at end of file
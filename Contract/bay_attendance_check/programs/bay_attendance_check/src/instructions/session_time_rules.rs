@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*};
+
+// 세션 시간 검증 기준(시간대, 최대 지각 허용 시간) 변경 (최고 관리자만 가능)
+pub fn set_session_time_rules(
+    ctx: Context<SetSessionTimeRules>,
+    session_time_rules: SessionTimeRules,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        (SessionTimeRules::MIN_UTC_OFFSET..=SessionTimeRules::MAX_UTC_OFFSET)
+            .contains(&session_time_rules.utc_offset),
+        AttendanceError::InvalidUtcOffset
+    );
+    
+    config.session_time_rules = session_time_rules;
+    
+    emit!(SessionTimeRulesUpdated {
+        session_time_rules: config.session_time_rules.clone(),
+    });
+    
+    msg!("Session time rules updated: {:?}", config.session_time_rules);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSessionTimeRules<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.super_admin == authority.key() @ AttendanceError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+}
\ No newline at end of file
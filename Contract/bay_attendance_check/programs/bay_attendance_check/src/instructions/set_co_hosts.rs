@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*};
+
+// 세션 공동 진행자 목록 설정 (세션을 만든 운영진만 가능)
+pub fn set_session_co_hosts(
+    ctx: Context<SetSessionCoHosts>,
+    co_hosts: Vec<Pubkey>,
+) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    
+    require!(
+        co_hosts.len() <= Session::MAX_CO_HOSTS,
+        AttendanceError::TooManyCoHosts
+    );
+    
+    session.co_hosts = co_hosts;
+    
+    emit!(SessionCoHostsUpdated {
+        session: session.key(),
+        co_hosts: session.co_hosts.clone(),
+    });
+    
+    msg!("Session co-hosts updated: {:?}", session.co_hosts);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSessionCoHosts<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump,
+        constraint = session.admin == authority.key() @ AttendanceError::NotSessionHost
+    )]
+    pub session: Account<'info, Session>,
+}
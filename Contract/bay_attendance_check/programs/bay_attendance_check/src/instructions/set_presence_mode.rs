@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*};
+
+// 현장 인증(QR) 모드 설정: presence_window 초마다 교체되는 세션 진행자 서명 QR 코드가 있어야 체크인 가능
+// presence_window = 0 이면 현장 인증 해제
+pub fn set_session_presence_mode(
+    ctx: Context<SetSessionPresenceMode>,
+    presence_window: u32,
+) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    
+    require!(
+        session.is_host(&ctx.accounts.authority.key()),
+        AttendanceError::NotSessionHost
+    );
+    
+    require!(
+        !session.is_finalized,
+        AttendanceError::SessionFinalized
+    );
+    
+    require!(
+        !session.is_cancelled,
+        AttendanceError::SessionCancelled
+    );
+    
+    session.presence_window = presence_window;
+    
+    emit!(SessionPresenceModeChanged {
+        session: session.key(),
+        presence_window,
+    });
+    
+    msg!("Session presence window set to: {} seconds", presence_window);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSessionPresenceMode<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump,
+        constraint = admin.is_active @ AttendanceError::MemberNotActive,
+        constraint = config.member_has_permission(&admin, PERMISSION_CREATE_SESSION) @ AttendanceError::Unauthorized
+    )]
+    pub admin: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Account<'info, Session>,
+}
@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*, utils::team_for};
+
+// 팀 생성 (학회원 관리 권한 필요)
+pub fn initialize_team(
+    ctx: Context<InitializeTeam>,
+    name: String,
+    lead: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let team = &mut ctx.accounts.team;
+    
+    require!(
+        config.has_permission(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_MANAGE_MEMBERS
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    require!(
+        name.len() <= Team::MAX_NAME_LEN,
+        AttendanceError::TeamNameTooLong
+    );
+    
+    let team_id = config.team_count;
+    config.team_count = config.team_count
+        .checked_add(1)
+        .ok_or(AttendanceError::Overflow)?;
+    
+    team.id = team_id;
+    team.name = name;
+    team.lead = lead;
+    team.member_count = 0;
+    team.total_attendance = 0;
+    team.total_late = 0;
+    team.total_points = 0;
+    team.bump = ctx.bumps.team;
+    team.version = Team::VERSION;
+    
+    emit!(TeamCreated {
+        team: team.key(),
+        team_id,
+        name: team.name.clone(),
+        lead,
+    });
+    
+    msg!("Team {} initialized: {}", team_id, team.name);
+    
+    Ok(())
+}
+
+// 팀 이름과 팀장 변경 (학회원 관리 권한 필요)
+pub fn update_team(
+    ctx: Context<UpdateTeam>,
+    name: String,
+    lead: Pubkey,
+) -> Result<()> {
+    let team = &mut ctx.accounts.team;
+    
+    require!(
+        ctx.accounts.config.has_permission(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_MANAGE_MEMBERS
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    require!(
+        name.len() <= Team::MAX_NAME_LEN,
+        AttendanceError::TeamNameTooLong
+    );
+    
+    team.name = name;
+    team.lead = lead;
+    
+    emit!(TeamUpdated {
+        team: team.key(),
+        name: team.name.clone(),
+        lead,
+    });
+    
+    msg!("Team {} updated: {} (lead: {})", team.id, team.name, lead);
+    
+    Ok(())
+}
+
+// 학회원 소속 팀 변경 (None이면 팀에서 제외)
+// 기존 출석 집계는 기록 당시 팀에 남음
+pub fn set_member_team(ctx: Context<SetMemberTeam>, team_id: Option<u16>) -> Result<()> {
+    let member = &mut ctx.accounts.member;
+    let authority = ctx.accounts.authority.key();
+    
+    require!(
+        ctx.accounts.config.has_permission(
+            &authority,
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_MANAGE_MEMBERS
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    let previous_team_id = member.team_id;
+    if previous_team_id == team_id {
+        msg!("Member {} is already in team {:?}", member.wallet, team_id);
+        return Ok(());
+    }
+    
+    if let Some(previous_team) = team_for(&mut ctx.accounts.previous_team, previous_team_id)? {
+        previous_team.remove_member()?;
+    }
+    if let Some(team) = team_for(&mut ctx.accounts.team, team_id)? {
+        team.add_member()?;
+    }
+    
+    member.team_id = team_id;
+    
+    emit!(MemberTeamChanged {
+        member: member.wallet,
+        previous_team_id,
+        team_id,
+        changed_by: authority,
+    });
+    
+    msg!("Member {} team changed: {:?} -> {:?}", member.wallet, previous_team_id, team_id);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeTeam<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 운영진 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Team::LEN,
+        seeds = [b"team", config.team_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub team: Account<'info, Team>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTeam<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 운영진 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    #[account(
+        mut,
+        seeds = [b"team", team.id.to_le_bytes().as_ref()],
+        bump = team.bump
+    )]
+    pub team: Account<'info, Team>,
+}
+
+#[derive(Accounts)]
+pub struct SetMemberTeam<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 운영진 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", member.wallet.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    // 학회원의 현재 소속 팀 (소속 팀이 있는 경우 필수)
+    #[account(
+        mut,
+        seeds = [b"team", previous_team.id.to_le_bytes().as_ref()],
+        bump = previous_team.bump
+    )]
+    pub previous_team: Option<Account<'info, Team>>,
+    
+    // 새 소속 팀 (team_id가 있는 경우 필수)
+    #[account(
+        mut,
+        seeds = [b"team", team.id.to_le_bytes().as_ref()],
+        bump = team.bump
+    )]
+    pub team: Option<Account<'info, Team>>,
+}
\ No newline at end of file
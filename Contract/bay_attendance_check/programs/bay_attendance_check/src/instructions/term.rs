@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::AttendanceError, events::*};
+
+// 학기 생성 (세션 생성 권한 필요)
+pub fn initialize_term(
+    ctx: Context<InitializeTerm>,
+    name: String,
+    start_date: i64,
+    end_date: i64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let term = &mut ctx.accounts.term;
+    
+    require!(
+        config.has_permission(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_CREATE_SESSION
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    require!(
+        name.len() <= Term::MAX_NAME_LEN,
+        AttendanceError::TermNameTooLong
+    );
+    
+    require!(
+        start_date < end_date,
+        AttendanceError::InvalidTimeParameters
+    );
+    
+    let term_id = config.term_count;
+    config.term_count = config.term_count
+        .checked_add(1)
+        .ok_or(AttendanceError::Overflow)?;
+    
+    term.id = term_id;
+    term.name = name;
+    term.start_date = start_date;
+    term.end_date = end_date;
+    term.is_active = true;
+    term.total_sessions = 0;
+    term.bump = ctx.bumps.term;
+    term.version = Term::VERSION;
+    
+    emit!(TermCreated {
+        term: term.key(),
+        term_id,
+        name: term.name.clone(),
+        start_date,
+        end_date,
+    });
+    
+    msg!("Term {} initialized: {}", term_id, term.name);
+    
+    Ok(())
+}
+
+// 학기 활성/비활성 전환 (종료된 학기에는 세션 추가 불가)
+pub fn set_term_active(ctx: Context<SetTermActive>, is_active: bool) -> Result<()> {
+    let term = &mut ctx.accounts.term;
+    
+    require!(
+        ctx.accounts.config.has_permission(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.admin.as_deref(),
+            PERMISSION_CREATE_SESSION
+        ),
+        AttendanceError::Unauthorized
+    );
+    
+    term.is_active = is_active;
+    
+    emit!(TermStatusChanged {
+        term: term.key(),
+        is_active,
+        changed_by: ctx.accounts.authority.key(),
+    });
+    
+    msg!("Term {} active status updated to: {}", term.id, is_active);
+    
+    Ok(())
+}
+
+// 학회원의 학기별 통계 계정 생성 (학기 시작 시 운영진 또는 본인이 rent 지불)
+pub fn initialize_term_stats(ctx: Context<InitializeTermStats>) -> Result<()> {
+    let term_stats = &mut ctx.accounts.term_stats;
+    
+    term_stats.term = ctx.accounts.term.key();
+    term_stats.member = ctx.accounts.member.wallet;
+    term_stats.total_attendance = 0;
+    term_stats.total_late = 0;
+    term_stats.total_absence = 0;
+    term_stats.total_excused = 0;
+    term_stats.total_points = 0;
+    term_stats.bump = ctx.bumps.term_stats;
+    term_stats.version = TermStats::VERSION;
+    
+    msg!("Term stats initialized for member: {}", term_stats.member);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeTerm<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 운영진 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Term::LEN,
+        seeds = [b"term", config.term_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub term: Account<'info, Term>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTermActive<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    // 최고 관리자가 아닌 경우 authority의 운영진 학회원 계정
+    #[account(
+        seeds = [b"member", authority.key().as_ref()],
+        bump = admin.bump
+    )]
+    pub admin: Option<Account<'info, Member>>,
+    
+    #[account(
+        mut,
+        seeds = [b"term", term.id.to_le_bytes().as_ref()],
+        bump = term.bump
+    )]
+    pub term: Account<'info, Term>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTermStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"term", term.id.to_le_bytes().as_ref()],
+        bump = term.bump
+    )]
+    pub term: Account<'info, Term>,
+    
+    #[account(
+        seeds = [b"member", member.wallet.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = TermStats::LEN,
+        seeds = [b"term_stats", term.key().as_ref(), member.wallet.as_ref()],
+        bump
+    )]
+    pub term_stats: Account<'info, TermStats>,
+    
+    pub system_program: Program<'info, System>,
+}
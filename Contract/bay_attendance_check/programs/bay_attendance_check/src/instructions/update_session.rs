@@ -1,31 +1,75 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::AttendanceError};
+use crate::{state::*, errors::AttendanceError, events::*};
 
-pub fn update_session_status(
+// 세션 수동 개폐 (Auto로 되돌리면 다시 체크인 가능 시간 기준으로 자동 개폐)
+pub fn set_session_override(
     ctx: Context<UpdateSession>,
-    is_active: bool
+    state_override: SessionOverride
 ) -> Result<()> {
     let session = &mut ctx.accounts.session;
     let admin = &ctx.accounts.admin;
     
-    // Admin 권한 확인
+    // 세션 생성/운영 권한 확인
     require!(
-        admin.role == MemberRole::Admin,
+        ctx.accounts.config.member_has_permission(admin, PERMISSION_CREATE_SESSION),
         AttendanceError::Unauthorized
     );
     
-    session.is_active = is_active;
+    // 세션 진행자(생성자 또는 공동 진행자) 확인
+    require!(
+        session.is_host(&ctx.accounts.authority.key()),
+        AttendanceError::NotSessionHost
+    );
+    
+    // 마감된 세션은 변경 불가
+    require!(
+        !session.is_finalized,
+        AttendanceError::SessionFinalized
+    );
+    
+    // 취소된 세션은 변경 불가
+    require!(
+        !session.is_cancelled,
+        AttendanceError::SessionCancelled
+    );
+    
+    session.state_override = state_override;
+    let state = session.state(Clock::get()?.unix_timestamp);
     
-    msg!("Session status updated to: {}", is_active);
+    emit!(SessionStatusChanged {
+        session: session.key(),
+        state_override,
+        state,
+        changed_by: ctx.accounts.authority.key(),
+    });
+    
+    msg!("Session override set to {:?} (state: {:?})", state_override, state);
     
     Ok(())
 }
 
+// 이전 클라이언트 호환용 활성/비활성 토글
+// (false는 수동 마감, true는 체크인 가능 시간 기준 자동 개폐로 복귀)
+pub fn update_session_status(ctx: Context<UpdateSession>, is_active: bool) -> Result<()> {
+    let state_override = if is_active {
+        SessionOverride::Auto
+    } else {
+        SessionOverride::Closed
+    };
+    set_session_override(ctx, state_override)
+}
+
 #[derive(Accounts)]
 pub struct UpdateSession<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
     #[account(
         seeds = [b"member", authority.key().as_ref()],
         bump = admin.bump,
@@ -33,6 +77,10 @@ pub struct UpdateSession<'info> {
     )]
     pub admin: Account<'info, Member>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"session", session.id.to_le_bytes().as_ref()],
+        bump = session.bump
+    )]
     pub session: Account<'info, Session>,
 }
\ No newline at end of file
@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 
 pub mod state;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod utils;
 
 use instructions::*;
 
@@ -12,40 +14,249 @@ declare_id!("HW4UmSnJfLd8yn8afM3WGz2w52ea7i1oTGqCSAXJmwv5");
 pub mod bay_attendance_check {
     use super::*;
 
+    pub fn initialize_config(ctx: Context<InitializeConfig>, super_admin: Pubkey) -> Result<()> {
+        instructions::initialize_config(ctx, super_admin)
+    }
+
+    pub fn set_point_mint(ctx: Context<SetPointMint>) -> Result<()> {
+        instructions::set_point_mint(ctx)
+    }
+
+    pub fn set_role_permissions(
+        ctx: Context<SetRolePermissions>,
+        role: state::MemberRole,
+        permissions: u16,
+    ) -> Result<()> {
+        instructions::set_role_permissions(ctx, role, permissions)
+    }
+
     pub fn initialize_member(ctx: Context<InitializeMember>, role: state::MemberRole) -> Result<()> {
         instructions::initialize_member(ctx, role)
     }
 
+    pub fn update_member_profile(
+        ctx: Context<UpdateMemberProfile>,
+        profile: state::MemberProfile,
+    ) -> Result<()> {
+        instructions::update_member_profile(ctx, profile)
+    }
+
+    pub fn set_member_active(ctx: Context<SetMemberActive>, is_active: bool) -> Result<()> {
+        instructions::set_member_active(ctx, is_active)
+    }
+
+    pub fn change_member_role(ctx: Context<ChangeMemberRole>, role: state::MemberRole) -> Result<()> {
+        instructions::change_member_role(ctx, role)
+    }
+
+    pub fn close_member<'info>(ctx: Context<'_, '_, 'info, 'info, CloseMember<'info>>) -> Result<()> {
+        instructions::close_member(ctx)
+    }
+
+    pub fn initialize_term(
+        ctx: Context<InitializeTerm>,
+        name: String,
+        start_date: i64,
+        end_date: i64,
+    ) -> Result<()> {
+        instructions::initialize_term(ctx, name, start_date, end_date)
+    }
+
+    pub fn set_term_active(ctx: Context<SetTermActive>, is_active: bool) -> Result<()> {
+        instructions::set_term_active(ctx, is_active)
+    }
+
+    pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>, term: Pubkey) -> Result<()> {
+        instructions::initialize_leaderboard(ctx, term)
+    }
+
+    pub fn refresh_leaderboard<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefreshLeaderboard<'info>>,
+    ) -> Result<()> {
+        instructions::refresh_leaderboard(ctx)
+    }
+
+    pub fn initialize_team(ctx: Context<InitializeTeam>, name: String, lead: Pubkey) -> Result<()> {
+        instructions::initialize_team(ctx, name, lead)
+    }
+
+    pub fn update_team(ctx: Context<UpdateTeam>, name: String, lead: Pubkey) -> Result<()> {
+        instructions::update_team(ctx, name, lead)
+    }
+
+    pub fn set_member_team(ctx: Context<SetMemberTeam>, team_id: Option<u16>) -> Result<()> {
+        instructions::set_member_team(ctx, team_id)
+    }
+
+    pub fn initialize_term_stats(ctx: Context<InitializeTermStats>) -> Result<()> {
+        instructions::initialize_term_stats(ctx)
+    }
+
+    pub fn create_session_series(
+        ctx: Context<CreateSessionSeries>,
+        title: String,
+        kind: state::SessionKind,
+        schedule: state::SeriesSchedule,
+    ) -> Result<()> {
+        instructions::create_session_series(ctx, title, kind, schedule)
+    }
+
+    pub fn create_series_sessions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSeriesSessions<'info>>,
+    ) -> Result<()> {
+        instructions::create_series_sessions(ctx)
+    }
+
+    pub fn reschedule_series<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RescheduleSeries<'info>>,
+        start_time_of_day: u32,
+        late_window: u32,
+    ) -> Result<()> {
+        instructions::reschedule_series(ctx, start_time_of_day, late_window)
+    }
+
     pub fn initialize_session(
         ctx: Context<InitializeSession>,
+        title: String,
+        kind: state::SessionKind,
         session_date: i64,
+        open_time: i64,
         start_time: i64,
         late_time: i64,
     ) -> Result<()> {
-        instructions::initialize_session(ctx, session_date, start_time, late_time)
+        instructions::initialize_session(ctx, title, kind, session_date, open_time, start_time, late_time)
+    }
+
+    pub fn set_point_schedule(
+        ctx: Context<SetPointSchedule>,
+        point_schedule: state::PointSchedule,
+    ) -> Result<()> {
+        instructions::set_point_schedule(ctx, point_schedule)
+    }
+
+    pub fn set_session_time_rules(
+        ctx: Context<SetSessionTimeRules>,
+        session_time_rules: state::SessionTimeRules,
+    ) -> Result<()> {
+        instructions::set_session_time_rules(ctx, session_time_rules)
+    }
+
+    pub fn set_streak_rules(
+        ctx: Context<SetStreakRules>,
+        streak_rules: state::StreakRules,
+    ) -> Result<()> {
+        instructions::set_streak_rules(ctx, streak_rules)
+    }
+
+    pub fn set_session_rewards(
+        ctx: Context<SetSessionRewards>,
+        point_schedule: Option<state::PointSchedule>,
+        point_multiplier: u16,
+    ) -> Result<()> {
+        instructions::set_session_rewards(ctx, point_schedule, point_multiplier)
+    }
+
+    pub fn set_session_presence_mode(
+        ctx: Context<SetSessionPresenceMode>,
+        presence_window: u32,
+    ) -> Result<()> {
+        instructions::set_session_presence_mode(ctx, presence_window)
     }
 
     pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
         instructions::check_in(ctx)
     }
 
+    pub fn record_attendance(
+        ctx: Context<RecordAttendance>,
+        status: state::AttendanceStatus,
+        reason: state::CorrectionReason,
+    ) -> Result<()> {
+        instructions::record_attendance(ctx, status, reason)
+    }
+
+    pub fn submit_excuse(ctx: Context<SubmitExcuse>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::submit_excuse(ctx, reason_hash)
+    }
+
+    pub fn approve_excuse(ctx: Context<ApproveExcuse>) -> Result<()> {
+        instructions::approve_excuse(ctx)
+    }
+
+    pub fn reject_excuse(ctx: Context<RejectExcuse>) -> Result<()> {
+        instructions::reject_excuse(ctx)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_config(ctx)
+    }
+
+    pub fn migrate_session(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_session(ctx)
+    }
+
+    pub fn migrate_member(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_member(ctx)
+    }
+
+    pub fn migrate_attendance_record(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_attendance_record(ctx)
+    }
+
+    pub fn set_session_override(ctx: Context<UpdateSession>, state_override: state::SessionOverride) -> Result<()> {
+        instructions::set_session_override(ctx, state_override)
+    }
+
     pub fn update_session_status(ctx: Context<UpdateSession>, is_active: bool) -> Result<()> {
         instructions::update_session_status(ctx, is_active)
     }
 
-    pub fn get_member_stats(ctx: Context<GetMemberStats>) -> Result<()> {
+    pub fn cancel_session<'info>(ctx: Context<'_, '_, 'info, 'info, CancelSession<'info>>) -> Result<()> {
+        instructions::cancel_session(ctx)
+    }
+
+    pub fn close_session(ctx: Context<CloseSession>) -> Result<()> {
+        instructions::close_session(ctx)
+    }
+
+    pub fn set_session_co_hosts(ctx: Context<SetSessionCoHosts>, co_hosts: Vec<Pubkey>) -> Result<()> {
+        instructions::set_session_co_hosts(ctx, co_hosts)
+    }
+
+    pub fn get_member_stats(ctx: Context<GetMemberStats>) -> Result<MemberStats> {
         instructions::get_member_stats(ctx)
     }
 
-    pub fn get_session_stats(ctx: Context<GetSessionStats>) -> Result<()> {
+    pub fn get_session_stats(ctx: Context<GetSessionStats>) -> Result<SessionStats> {
         instructions::get_session_stats(ctx)
     }
 
-    pub fn reactivate_session(
-        ctx: Context<ReactivateSession>,
+    pub fn get_term_stats(ctx: Context<GetTermStats>) -> Result<TermMemberStats> {
+        instructions::get_term_stats(ctx)
+    }
+
+    pub fn get_leaderboard(ctx: Context<GetLeaderboard>) -> Result<LeaderboardStats> {
+        instructions::get_leaderboard(ctx)
+    }
+
+    pub fn get_team_stats(ctx: Context<GetTeamStats>) -> Result<TeamStats> {
+        instructions::get_team_stats(ctx)
+    }
+
+    pub fn reactivate_session<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReactivateSession<'info>>,
+        mode: state::ReactivateMode,
+        new_open_time: i64,
         new_start_time: i64,
         new_late_time: i64,
     ) -> Result<()> {
-        instructions::reactivate_session(ctx, new_start_time, new_late_time)
+        instructions::reactivate_session(ctx, mode, new_open_time, new_start_time, new_late_time)
+    }
+
+    pub fn finalize_session<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeSession<'info>>,
+        lock: bool,
+    ) -> Result<()> {
+        instructions::finalize_session(ctx, lock)
     }
 }
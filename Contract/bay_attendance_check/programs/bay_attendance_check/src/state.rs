@@ -1,16 +1,71 @@
 use anchor_lang::prelude::*;
+use crate::errors::AttendanceError;
+
+// BAY 포인트 토큰 mint authority PDA seed
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
+
+// 역할별 권한 비트 (Config.role_permissions)
+pub const PERMISSION_CREATE_SESSION: u16 = 1 << 0;     // 세션 생성 및 운영
+pub const PERMISSION_CORRECT_ATTENDANCE: u16 = 1 << 1; // 출석 대리 기록/정정, 공결 승인
+pub const PERMISSION_MANAGE_PRODUCTS: u16 = 1 << 2;    // 마켓플레이스 상품 관리 (bay_point_marketplace에서 확인)
+pub const PERMISSION_MINT_NFTS: u16 = 1 << 3;          // NFT 발행 (bay_nft 발행 스크립트에서 확인)
+pub const PERMISSION_MANAGE_MEMBERS: u16 = 1 << 4;     // 학회원 등록/역할 변경/비활성화
+pub const PERMISSION_ALL: u16 = PERMISSION_CREATE_SESSION
+    | PERMISSION_CORRECT_ATTENDANCE
+    | PERMISSION_MANAGE_PRODUCTS
+    | PERMISSION_MINT_NFTS
+    | PERMISSION_MANAGE_MEMBERS;
+
+// 계정 레이아웃 규칙
+// - 새 필드는 version과 reserved 사이에 추가하고 그만큼 reserved를 줄여 LEN을 유지
+// - 0으로 채워진 값이 새 필드의 기본값이 되도록 설계 (기존 계정은 migrate_*로 확장)
+// - 레이아웃이 바뀌면 해당 계정의 VERSION을 올리고 migrate_* 에서 기본값 처리
+
+// 프로그램 전역 설정 (싱글톤)
+#[account]
+pub struct Config {
+    pub super_admin: Pubkey,     // 최고 관리자
+    pub session_count: u64,      // 생성된 세션 수 (다음 세션 ID)
+    pub point_schedule: PointSchedule, // 새 세션에 적용되는 기본 포인트 지급 기준
+    pub role_permissions: [u16; MemberRole::COUNT], // 역할별 권한 비트마스크 (MemberRole 순서)
+    pub bump: u8,                // PDA bump
+    pub version: u8,             // 계정 레이아웃 버전 (0이면 마이그레이션 이전 계정)
+    pub term_count: u64,         // 생성된 학기 수 (다음 학기 ID)
+    pub streak_rules: StreakRules, // 연속 출석 규칙 및 마일스톤 보너스
+    pub session_time_rules: SessionTimeRules, // 세션 시간 검증 기준 (시간대, 최대 지각 허용 시간)
+    pub team_count: u16,         // 생성된 팀 수 (다음 팀 ID)
+    pub series_count: u64,       // 생성된 반복 세션 일정 수 (다음 일정 ID)
+    pub point_mint: Pubkey,      // BAY 포인트 토큰 mint (체크인/대리 기록 시 이 mint로만 발행)
+    pub reserved: [u8; 45],      // 향후 필드 추가용 예약 공간
+}
 
 // 세션 정보를 저장하는 계정
 #[account]
 pub struct Session {
+    pub id: u64,                 // 세션 ID (Config.session_count 기반)
+    pub title: String,           // 세션 제목
+    pub kind: SessionKind,       // 세션 종류
     pub admin: Pubkey,           // 세션을 만든 운영진
+    pub co_hosts: Vec<Pubkey>,   // 세션 공동 진행자 (운영진)
     pub session_date: i64,       // 세션 날짜 (Unix timestamp)
     pub start_time: i64,         // 세션 시작 시간 (Unix timestamp) 
     pub late_time: i64,          // 지각 기준 시간 (Unix timestamp)
     pub total_attendees: u32,    // 총 출석자 수
     pub total_late: u32,         // 총 지각자 수
-    pub is_active: bool,         // 세션 활성화 상태
+    pub total_absent: u32,       // 총 결석자 수
+    pub total_excused: u32,      // 총 공결자 수
+    pub state_override: SessionOverride, // 운영진 수동 개폐 (Auto면 시간 기준으로 자동 개폐)
+    pub is_finalized: bool,      // 결석 처리 후 영구 마감 여부
+    pub point_schedule: PointSchedule, // 세션 포인트 지급 기준 (생성 시 Config에서 복사)
+    pub point_multiplier: u16,   // 포인트 배율 (100 = 1배, 중요 행사용)
+    pub presence_window: u32,    // QR 코드 교체 주기 (초, 0이면 현장 인증 미사용)
     pub bump: u8,                // PDA bump
+    pub version: u8,             // 계정 레이아웃 버전 (0이면 마이그레이션 이전 계정)
+    pub term: Option<Pubkey>,    // 소속 학기 (None이면 학기별 통계 미집계)
+    pub open_time: i64,          // 체크인 가능 시작 시간 (Unix timestamp, 0이면 제한 없음, 마이그레이션된 이전 세션만 해당)
+    pub series: Option<Pubkey>,  // 반복 세션 일정으로 생성된 경우 해당 일정
+    pub is_cancelled: bool,      // 세션 취소 여부 (취소된 세션은 변경 불가)
+    pub reserved: [u8; 53],      // 향후 필드 추가용 예약 공간
 }
 
 // 학회원의 출석 기록
@@ -20,8 +75,15 @@ pub struct AttendanceRecord {
     pub session: Pubkey,         // 세션 주소
     pub check_in_time: i64,      // 체크인 시간 (Unix timestamp)
     pub status: AttendanceStatus, // 출석 상태
-    pub points_earned: u8,       // 획득한 포인트
+    pub points_earned: u64,      // 획득한 포인트
+    pub payer: Pubkey,           // 계정 rent를 지불한 지갑 (계정 종료 시 환불 대상)
+    pub corrected_by: Option<Pubkey>, // 운영진이 대신 기록/정정한 경우 해당 운영진 (감사용)
+    pub correction_reason: Option<CorrectionReason>, // 대리 기록/정정 사유
     pub bump: u8,                // PDA bump
+    pub version: u8,             // 계정 레이아웃 버전 (0이면 마이그레이션 이전 계정)
+    pub team_id: Option<u16>,    // 기록 당시 학회원 소속 팀 (팀 집계 정정/되돌리기 대상)
+    pub points_minted: u64,      // 이 기록으로 발행된 BAY 토큰 포인트 (정정 시 최고치까지만 발행)
+    pub reserved: [u8; 21],      // 향후 필드 추가용 예약 공간
 }
 
 // 학회원 정보
@@ -32,9 +94,164 @@ pub struct Member {
     pub total_attendance: u32,   // 총 출석 횟수
     pub total_late: u32,         // 총 지각 횟수
     pub total_absence: u32,      // 총 결석 횟수
+    pub total_excused: u32,      // 총 공결 횟수 (결석으로 집계하지 않음)
     pub total_points: u64,       // 총 획득 포인트
     pub is_active: bool,         // 활성 상태
     pub bump: u8,                // PDA bump
+    // 프로필 (0으로 채워진 값은 미설정)
+    pub display_name: String,    // 표시 이름 (예: bay01)
+    pub cohort: Option<u16>,     // 기수 (예: 16)
+    pub team_id: Option<u16>,    // 소속 팀 ID (set_member_team으로 변경)
+    pub profile_uri: String,     // 프로필 이미지/메타데이터 URI
+    pub version: u8,             // 계정 레이아웃 버전 (0이면 마이그레이션 이전 계정)
+    pub current_streak: u16,     // 현재 연속 정시 출석 횟수
+    pub longest_streak: u16,     // 최장 연속 정시 출석 횟수
+    pub minted_credit: u64,      // 종료된 출석 기록으로 이미 발행된 BAY 포인트 (이후 발행분에서 먼저 차감)
+    pub payer: Pubkey,           // 계정 rent를 지불한 지갑 (계정 종료 시 환불 대상, 기본값이면 버전 4 이전 계정)
+    pub reserved: [u8; 20],      // 향후 필드 추가용 예약 공간
+}
+
+// 학회원 프로필 (update_member_profile 입력)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct MemberProfile {
+    pub display_name: String,
+    pub cohort: Option<u16>,
+    pub profile_uri: String,
+}
+
+// 학회원의 공결(사전/사후 불참 사유) 신청
+#[account]
+pub struct ExcuseRequest {
+    pub member: Pubkey,          // 신청한 학회원 지갑 주소
+    pub session: Pubkey,         // 세션 주소
+    pub reason_hash: [u8; 32],   // 불참 사유 해시 (원문은 오프체인 보관)
+    pub submitted_at: i64,       // 신청 시간 (Unix timestamp)
+    pub is_pre_announced: bool,  // 세션 시작 전에 신청했는지 여부
+    pub status: ExcuseStatus,    // 처리 상태
+    pub reviewed_by: Option<Pubkey>, // 승인/반려한 운영진
+    pub bump: u8,                // PDA bump
+    pub version: u8,             // 계정 레이아웃 버전 (0이면 마이그레이션 이전 계정)
+    pub reserved: [u8; 32],      // 향후 필드 추가용 예약 공간
+}
+
+// 학기 (세션 그룹, 학기별 보상/벌점 집계 단위)
+#[account]
+pub struct Term {
+    pub id: u64,                 // 학기 ID (Config.term_count 기반)
+    pub name: String,            // 학기 이름 (예: 2025-1)
+    pub start_date: i64,         // 학기 시작일 (Unix timestamp)
+    pub end_date: i64,           // 학기 종료일 (Unix timestamp)
+    pub is_active: bool,         // 활성 상태 (비활성 학기에는 세션 추가 불가)
+    pub total_sessions: u32,     // 학기에 속한 세션 수
+    pub bump: u8,                // PDA bump
+    pub version: u8,             // 계정 레이아웃 버전
+    pub reserved: [u8; 32],      // 향후 필드 추가용 예약 공간
+}
+
+// 팀 (학회원 소속 단위, 팀별 출석 집계)
+#[account]
+pub struct Team {
+    pub id: u16,                 // 팀 ID (Config.team_count 기반, Member.team_id와 대응)
+    pub name: String,            // 팀 이름
+    pub lead: Pubkey,            // 팀장 지갑 주소
+    pub member_count: u32,       // 소속 학회원 수
+    pub total_attendance: u32,   // 팀 출석 횟수
+    pub total_late: u32,         // 팀 지각 횟수
+    pub total_points: u64,       // 팀 획득 포인트
+    pub bump: u8,                // PDA bump
+    pub version: u8,             // 계정 레이아웃 버전
+    pub reserved: [u8; 32],      // 향후 필드 추가용 예약 공간
+}
+
+// 포인트 상위 학회원 리더보드 (전체 또는 학기별)
+#[account]
+pub struct Leaderboard {
+    pub term: Pubkey,            // 학기 주소 (Pubkey::default()이면 전체 리더보드)
+    pub entries: Vec<LeaderboardEntry>, // 순위순 상위 학회원 (최대 Leaderboard::CAPACITY명)
+    pub bump: u8,                // PDA bump
+    pub version: u8,             // 계정 레이아웃 버전
+    pub reserved: [u8; 32],      // 향후 필드 추가용 예약 공간
+}
+
+// 리더보드 항목 (포인트 내림차순, 동점이면 출석률 내림차순)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct LeaderboardEntry {
+    pub wallet: Pubkey,          // 학회원 지갑 주소
+    pub points: u64,             // 획득 포인트
+    pub attendance_rate_bps: u16, // 출석률 (지각 포함, 10000 = 100%)
+}
+
+// 반복 세션 일정 (매주 같은 요일/시간의 세션을 여러 트랜잭션에 나누어 생성)
+#[account]
+pub struct SessionSeries {
+    pub id: u64,                 // 일정 ID (Config.series_count 기반)
+    pub title: String,           // 생성되는 세션 제목
+    pub kind: SessionKind,       // 생성되는 세션 종류
+    pub admin: Pubkey,           // 일정을 만든 운영진 (생성되는 세션의 admin)
+    pub term: Option<Pubkey>,    // 생성되는 세션의 소속 학기
+    pub schedule: SeriesSchedule, // 반복 규칙
+    pub first_date: i64,         // 첫 세션 날짜 (현지 날짜 시작 시각, Unix timestamp)
+    pub next_occurrence: u16,    // 다음에 생성할 회차 (재개 커서)
+    pub session_ids: Vec<u64>,   // 생성된 세션 ID (회차순, 지난 회차는 건너뜀)
+    pub bump: u8,                // PDA bump
+    pub version: u8,             // 계정 레이아웃 버전
+    pub reserved: [u8; SessionSeries::RESERVED_LEN], // 향후 필드 추가용 예약 공간
+}
+
+// 반복 세션 규칙
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct SeriesSchedule {
+    pub start_date: i64,         // 일정 시작일 (이 날짜 이후 첫 해당 요일부터 생성)
+    pub weekday: u8,             // 요일 (0 = 월요일, 6 = 일요일, 현지 시간 기준)
+    pub start_time_of_day: u32,  // 세션 시작 시각 (현지 자정부터 초)
+    pub open_window: u32,        // 체크인 시작 시간부터 세션 시작 시간까지 (초)
+    pub late_window: u32,        // 시작 시간부터 지각 기준 시간까지 (초)
+    pub occurrence_count: u16,   // 총 회차 수
+}
+
+// 학회원의 학기별 출석 통계
+#[account]
+pub struct TermStats {
+    pub term: Pubkey,            // 학기 주소
+    pub member: Pubkey,          // 학회원 지갑 주소
+    pub total_attendance: u32,   // 학기 출석 횟수
+    pub total_late: u32,         // 학기 지각 횟수
+    pub total_absence: u32,      // 학기 결석 횟수
+    pub total_excused: u32,      // 학기 공결 횟수
+    pub total_points: u64,       // 학기 획득 포인트
+    pub bump: u8,                // PDA bump
+    pub version: u8,             // 계정 레이아웃 버전
+    pub reserved: [u8; 32],      // 향후 필드 추가용 예약 공간
+}
+
+// 포인트 지급 기준
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct PointSchedule {
+    pub present_points: u32,     // 정시 출석 포인트
+    pub late_points: u32,        // 지각 포인트
+    pub early_bird_bonus: u32,   // 조기 체크인 보너스 포인트 (0이면 미사용)
+    pub early_bird_window: i64,  // 세션 시작 최소 몇 초 전 체크인을 조기 체크인으로 인정할지
+}
+
+// 연속 출석 규칙 (0으로 채워진 기본값: 지각 시 초기화, 마일스톤 없음)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct StreakRules {
+    pub keep_streak_on_late: bool, // true면 지각 시 연속 기록 유지 (증가하지 않음)
+    pub milestones: [StreakMilestone; StreakRules::MAX_MILESTONES], // 보너스 지급 마일스톤
+}
+
+// 연속 출석 마일스톤
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct StreakMilestone {
+    pub streak: u16,             // 달성 연속 출석 횟수 (0이면 미사용)
+    pub bonus_points: u32,       // 보너스 포인트
+}
+
+// 세션 시간 검증 기준
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct SessionTimeRules {
+    pub utc_offset: i32,         // 세션 날짜 기준 시간대 (UTC 대비 초, KST = 32400)
+    pub max_late_window: u32,    // 시작 시간부터 지각 기준 시간까지 최대 길이 (초, 0이면 제한 없음)
 }
 
 // 출석 상태 enum
@@ -43,26 +260,410 @@ pub enum AttendanceStatus {
     Present,    // 출석
     Late,       // 지각
     Absent,     // 결석
+    Excused,    // 공결 (승인된 불참)
+}
+
+// 공결 신청 처리 상태 enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum ExcuseStatus {
+    Pending,    // 승인 대기
+    Approved,   // 승인
+    Rejected,   // 반려
+}
+
+// 운영진 대리 출석 기록/정정 사유 enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum CorrectionReason {
+    DeviceFailure,  // 휴대폰 방전/고장
+    WalletFailure,  // 지갑 앱 오류
+    RecordingError, // 잘못 기록된 출석 정정
+    Other,          // 기타
+}
+
+// 세션 종류 enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum SessionKind {
+    Regular,    // 정규 세션
+    Study,      // 스터디
+    Event,      // 행사
+}
+
+// 세션 수동 개폐 enum
+// 기존 is_active(bool) 자리를 그대로 사용하므로 순서 고정: false(0) = Closed, true(1) = Auto
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum SessionOverride {
+    Closed,     // 수동 마감 (체크인 불가)
+    Auto,       // 체크인 가능 시간 기준 자동 개폐
+    Open,       // 수동 개방 (체크인 시작 시간 전이라도 체크인 가능)
+}
+
+// 세션 진행 상태 enum (저장하지 않고 시간과 세션 필드로 계산)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum SessionState {
+    Scheduled,  // 체크인 시작 전
+    Open,       // 체크인 가능
+    Closed,     // 체크인 마감 (결석 처리 전)
+    Finalized,  // 결석 처리 후 영구 마감
+    Cancelled,  // 취소됨
+}
+
+// 세션 재활성화 모드 enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum ReactivateMode {
+    Extend,     // 통계와 출석 기록 유지
+    Reset,      // 출석 기록 종료 및 통계/포인트 되돌리기
 }
 
-// 학회원 역할 enum
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+// 학회원 역할 enum (새 역할은 항상 끝에 추가)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
 pub enum MemberRole {
-    Admin,      // 운영진
-    Member,     // 일반 학회원
+    Admin,          // 운영진
+    Member,         // 일반 학회원
+    President,      // 학회장
+    TrackLead,      // 트랙장
+    TeamLead,       // 팀장
+    Alumni,         // 수료생
+    Probationary,   // 수습 학회원
 }
 
 // 계정 크기 상수
+impl Config {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // super_admin
+        8 + // session_count
+        PointSchedule::LEN + // point_schedule
+        2 * MemberRole::COUNT + // role_permissions
+        1 + // bump
+        1 + // version
+        8 + // term_count
+        StreakRules::LEN + // streak_rules
+        SessionTimeRules::LEN + // session_time_rules
+        2 + // team_count
+        8 + // series_count
+        32 + // point_mint
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 3;
+    pub const RESERVED_LEN: usize = 45;
+    
+    // 기본 역할별 권한 (MemberRole 순서)
+    pub const DEFAULT_ROLE_PERMISSIONS: [u16; MemberRole::COUNT] = [
+        PERMISSION_ALL, // Admin
+        0, // Member
+        PERMISSION_ALL, // President
+        PERMISSION_CREATE_SESSION | PERMISSION_CORRECT_ATTENDANCE, // TrackLead
+        PERMISSION_CREATE_SESSION, // TeamLead
+        0, // Alumni
+        0, // Probationary
+    ];
+    
+    pub fn role_permissions(&self, role: &MemberRole) -> u16 {
+        self.role_permissions[role.index()]
+    }
+    
+    // authority가 가진 권한 (최고 관리자는 전체 권한, 비활성 학회원은 권한 없음)
+    pub fn permissions_of(&self, authority: &Pubkey, member: Option<&Member>) -> u16 {
+        if *authority == self.super_admin {
+            return PERMISSION_ALL;
+        }
+        match member {
+            Some(member) if member.wallet == *authority && member.is_active => {
+                self.role_permissions(&member.role)
+            }
+            _ => 0,
+        }
+    }
+    
+    // 최고 관리자이거나 해당 권한이 있는 활성 학회원인지 확인
+    pub fn has_permission(&self, authority: &Pubkey, member: Option<&Member>, permission: u16) -> bool {
+        self.permissions_of(authority, member) & permission == permission
+    }
+    
+    // 학회원 계정의 역할에 해당 권한이 있는지 확인 (계정 주소는 seeds로 검증)
+    pub fn member_has_permission(&self, member: &Member, permission: u16) -> bool {
+        member.is_active && self.role_permissions(&member.role) & permission == permission
+    }
+    
+    // authority가 가진 권한을 모두 포함하는 역할만 부여/관리 가능
+    pub fn can_grant(&self, authority: &Pubkey, member: Option<&Member>, role: &MemberRole) -> bool {
+        self.role_permissions(role) & !self.permissions_of(authority, member) == 0
+    }
+}
+
 impl Session {
+    pub const MAX_TITLE_LEN: usize = 64;
+    pub const MAX_CO_HOSTS: usize = 5;
+    
     pub const LEN: usize = 8 + // discriminator
+        8 + // id
+        (4 + Self::MAX_TITLE_LEN) + // title
+        1 + // kind
         32 + // admin
+        (4 + 32 * Self::MAX_CO_HOSTS) + // co_hosts
         8 + // session_date
         8 + // start_time
         8 + // late_time
         4 + // total_attendees
         4 + // total_late
-        1 + // is_active
-        1; // bump
+        4 + // total_absent
+        4 + // total_excused
+        1 + // state_override
+        1 + // is_finalized
+        PointSchedule::LEN + // point_schedule
+        2 + // point_multiplier
+        4 + // presence_window
+        1 + // bump
+        1 + // version
+        (1 + 32) + // term
+        8 + // open_time
+        (1 + 32) + // series
+        1 + // is_cancelled
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 2;
+    pub const RESERVED_LEN: usize = 53;
+    
+    // 현재 시간 기준 세션 상태 (취소/마감 > 수동 개폐 > 체크인 가능 시간 순으로 판단)
+    pub fn state(&self, now: i64) -> SessionState {
+        if self.is_cancelled {
+            return SessionState::Cancelled;
+        }
+        if self.is_finalized {
+            return SessionState::Finalized;
+        }
+        
+        match self.state_override {
+            SessionOverride::Closed => SessionState::Closed,
+            // 수동으로 연 세션은 체크인 시작 시간 전이라도 열리지만 지각 기준 시간 이후에는 닫힘
+            SessionOverride::Open if now <= self.late_time => SessionState::Open,
+            SessionOverride::Open => SessionState::Closed,
+            SessionOverride::Auto if now < self.open_time => SessionState::Scheduled,
+            SessionOverride::Auto if now <= self.late_time => SessionState::Open,
+            SessionOverride::Auto => SessionState::Closed,
+        }
+    }
+    
+    // 체크인 가능 여부 확인 (닫힌 이유에 따라 에러 구분)
+    pub fn require_open(&self, now: i64) -> Result<()> {
+        match self.state(now) {
+            SessionState::Open => Ok(()),
+            SessionState::Scheduled => Err(AttendanceError::CheckInNotOpen.into()),
+            SessionState::Closed if now > self.late_time => Err(AttendanceError::CheckInTimePassed.into()),
+            SessionState::Closed => Err(AttendanceError::SessionNotActive.into()),
+            SessionState::Finalized => Err(AttendanceError::SessionFinalized.into()),
+            SessionState::Cancelled => Err(AttendanceError::SessionCancelled.into()),
+        }
+    }
+    
+    // 체크인 시작 <= 세션 시작 < 지각 기준 순서인지 확인
+    pub fn valid_times(open_time: i64, start_time: i64, late_time: i64) -> bool {
+        open_time <= start_time && start_time < late_time
+    }
+    
+    // 세션을 만든 운영진 또는 공동 진행자인지 확인
+    pub fn is_host(&self, key: &Pubkey) -> bool {
+        self.admin == *key || self.co_hosts.contains(key)
+    }
+    
+    // 체크인이 시작되었는지 확인
+    // (재활성화로 체크인 시작 시간이 미뤄져도 이미 체크인한 학회원이 있으면 시작된 것으로 봄)
+    pub fn check_in_started(&self, now: i64) -> bool {
+        self.state(now) != SessionState::Scheduled || self.total_attendees > 0
+    }
+    
+    // 아직 종료되지 않은 출석 기록 수 (출석/지각, 결석, 공결 기록은 각각 하나씩)
+    pub fn open_record_count(&self) -> u32 {
+        self.total_attendees
+            .saturating_add(self.total_absent)
+            .saturating_add(self.total_excused)
+    }
+    
+    // 출석 상태에 따른 세션 통계 반영
+    pub fn record_status(&mut self, status: &AttendanceStatus) -> Result<()> {
+        match status {
+            AttendanceStatus::Present => {
+                self.total_attendees = checked_inc(self.total_attendees)?;
+            }
+            AttendanceStatus::Late => {
+                self.total_attendees = checked_inc(self.total_attendees)?;
+                self.total_late = checked_inc(self.total_late)?;
+            }
+            AttendanceStatus::Absent => {
+                self.total_absent = checked_inc(self.total_absent)?;
+            }
+            AttendanceStatus::Excused => {
+                self.total_excused = checked_inc(self.total_excused)?;
+            }
+        }
+        Ok(())
+    }
+    
+    pub const DEFAULT_POINT_MULTIPLIER: u16 = 100;
+    pub const MAX_POINT_MULTIPLIER: u16 = 1000;
+    
+    // 현장 인증 QR 코드 서명 메시지 접두사
+    pub const PRESENCE_MESSAGE_PREFIX: &'static [u8] = b"BAY_CHECKIN";
+    
+    // 현장 인증 QR 코드의 서명 메시지: 접두사 + 세션 주소 + QR 교체 구간 번호
+    pub fn presence_message(session: &Pubkey, window_index: i64) -> Vec<u8> {
+        [
+            Self::PRESENCE_MESSAGE_PREFIX,
+            session.as_ref(),
+            &window_index.to_le_bytes(),
+        ]
+        .concat()
+    }
+    
+    // 출석 상태와 체크인 시간에 따른 획득 포인트 (배율 적용)
+    pub fn points_for(&self, status: &AttendanceStatus, check_in_time: i64) -> Result<u64> {
+        let schedule = &self.point_schedule;
+        let base = match status {
+            AttendanceStatus::Present => {
+                let is_early_bird = schedule.early_bird_bonus > 0
+                    && check_in_time <= self.start_time.saturating_sub(schedule.early_bird_window);
+                if is_early_bird {
+                    schedule.present_points as u64 + schedule.early_bird_bonus as u64
+                } else {
+                    schedule.present_points as u64
+                }
+            }
+            AttendanceStatus::Late => schedule.late_points as u64,
+            AttendanceStatus::Absent | AttendanceStatus::Excused => 0,
+        };
+        
+        let points = base
+            .checked_mul(self.point_multiplier as u64)
+            .ok_or(AttendanceError::Overflow)?
+            / Self::DEFAULT_POINT_MULTIPLIER as u64;
+        Ok(points)
+    }
+    
+    // 정시 출석률 (basis points, 10000 = 100%)
+    pub fn on_time_rate_bps(&self) -> u16 {
+        rate_bps(
+            self.total_attendees.saturating_sub(self.total_late) as u64,
+            self.total_attendees as u64,
+        )
+    }
+    
+    // 출석 기록 종료 시 세션 통계 되돌리기
+    pub fn revert_status(&mut self, status: &AttendanceStatus) -> Result<()> {
+        match status {
+            AttendanceStatus::Present => {
+                self.total_attendees = checked_dec(self.total_attendees)?;
+            }
+            AttendanceStatus::Late => {
+                self.total_attendees = checked_dec(self.total_attendees)?;
+                self.total_late = checked_dec(self.total_late)?;
+            }
+            AttendanceStatus::Absent => {
+                self.total_absent = checked_dec(self.total_absent)?;
+            }
+            AttendanceStatus::Excused => {
+                self.total_excused = checked_dec(self.total_excused)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PointSchedule {
+    pub const LEN: usize = 4 + // present_points
+        4 + // late_points
+        4 + // early_bird_bonus
+        8; // early_bird_window
+    
+    pub const MAX_ATTENDANCE_POINTS: u32 = 1_000;
+    pub const MAX_EARLY_BIRD_BONUS_POINTS: u32 = 1_000;
+    
+    // 기본 지급 기준: 출석 10포인트, 지각 5포인트
+    pub const DEFAULT: PointSchedule = PointSchedule {
+        present_points: 10,
+        late_points: 5,
+        early_bird_bonus: 0,
+        early_bird_window: 0,
+    };
+    
+    // 포인트 상한 이내이고 지각 포인트가 출석 포인트를 넘지 않으며
+    // 조기 체크인 기준이 하루 이내인 지급 기준인지 확인
+    pub fn is_valid(&self) -> bool {
+        self.present_points <= Self::MAX_ATTENDANCE_POINTS
+            && self.late_points <= self.present_points
+            && self.early_bird_bonus <= Self::MAX_EARLY_BIRD_BONUS_POINTS
+            && (0..=SessionTimeRules::SECONDS_PER_DAY).contains(&self.early_bird_window)
+    }
+}
+
+impl SessionTimeRules {
+    pub const LEN: usize = 4 + // utc_offset
+        4; // max_late_window
+    
+    pub const SECONDS_PER_DAY: i64 = 86_400;
+    pub const MIN_UTC_OFFSET: i32 = -12 * 3600;
+    pub const MAX_UTC_OFFSET: i32 = 14 * 3600;
+    
+    // 기본 기준: KST, 지각 허용 최대 3시간
+    pub const DEFAULT: SessionTimeRules = SessionTimeRules {
+        utc_offset: 9 * 3600,
+        max_late_window: 3 * 3600,
+    };
+    
+    // session_date가 속한 현지 날짜의 시작 시각 (Unix timestamp)
+    pub fn day_start(&self, session_date: i64) -> i64 {
+        let offset = self.utc_offset as i64;
+        (session_date + offset).div_euclid(Self::SECONDS_PER_DAY) * Self::SECONDS_PER_DAY - offset
+    }
+    
+    // session_date가 속한 현지 날짜의 요일 (0 = 월요일, 1970-01-01은 목요일)
+    pub fn weekday(&self, session_date: i64) -> u8 {
+        let day = (session_date + self.utc_offset as i64).div_euclid(Self::SECONDS_PER_DAY);
+        (day + 3).rem_euclid(7) as u8
+    }
+    
+    // 체크인 시작부터 지각 기준까지 세션 날짜(현지 기준) 안에 있고, 아직 끝나지 않았으며, 지각 허용 시간이 제한 이내인지 확인
+    // (체크인 시작 시간이 전날로 가면 며칠 전부터 체크인할 수 있으므로 함께 확인)
+    pub fn validate(
+        &self,
+        session_date: i64,
+        open_time: i64,
+        start_time: i64,
+        late_time: i64,
+        current_time: i64,
+    ) -> Result<()> {
+        let day_start = self.day_start(session_date);
+        require!(
+            open_time >= day_start
+                && start_time >= day_start
+                && late_time < day_start + Self::SECONDS_PER_DAY,
+            AttendanceError::SessionTimeOutsideDate
+        );
+        require!(
+            late_time > current_time,
+            AttendanceError::SessionAlreadyEnded
+        );
+        require!(
+            self.max_late_window == 0 || late_time - start_time <= self.max_late_window as i64,
+            AttendanceError::LateWindowTooLong
+        );
+        Ok(())
+    }
+}
+
+impl StreakRules {
+    pub const MAX_MILESTONES: usize = 4;
+    pub const MAX_BONUS_POINTS: u32 = 1_000;
+    
+    pub const LEN: usize = 1 + // keep_streak_on_late
+        (2 + 4) * Self::MAX_MILESTONES; // milestones
+    
+    // 해당 연속 출석 횟수에 지급할 마일스톤 보너스
+    pub fn bonus_for(&self, streak: u16) -> u64 {
+        self.milestones
+            .iter()
+            .filter(|milestone| milestone.streak > 0 && milestone.streak == streak)
+            .map(|milestone| milestone.bonus_points as u64)
+            .sum()
+    }
 }
 
 impl AttendanceRecord {
@@ -71,8 +672,315 @@ impl AttendanceRecord {
         32 + // session
         8 + // check_in_time
         1 + // status
-        1 + // points_earned
-        1; // bump
+        8 + // points_earned
+        32 + // payer
+        (1 + 32) + // corrected_by
+        (1 + 1) + // correction_reason
+        1 + // bump
+        1 + // version
+        (1 + 2) + // team_id
+        8 + // points_minted
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 3;
+    pub const RESERVED_LEN: usize = 21;
+    
+    // 포인트가 이미 발행된 최고치를 넘는 만큼만 추가 발행 대상으로 기록하고 그 양을 반환
+    // (발행된 토큰은 회수할 수 없으므로 정정으로 포인트가 줄었다 늘어도 다시 발행하지 않음)
+    pub fn take_unminted_points(&mut self, points: u64) -> u64 {
+        let unminted = points.saturating_sub(self.points_minted);
+        self.points_minted = self.points_minted.max(points);
+        unminted
+    }
+}
+
+impl Term {
+    pub const MAX_NAME_LEN: usize = 32;
+    
+    pub const LEN: usize = 8 + // discriminator
+        8 + // id
+        (4 + Self::MAX_NAME_LEN) + // name
+        8 + // start_date
+        8 + // end_date
+        1 + // is_active
+        4 + // total_sessions
+        1 + // bump
+        1 + // version
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 1;
+    pub const RESERVED_LEN: usize = 32;
+    
+    // 세션 날짜가 학기 기간 안에 있는지 확인
+    pub fn contains(&self, date: i64) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+}
+
+impl Team {
+    pub const MAX_NAME_LEN: usize = 32;
+    
+    pub const LEN: usize = 8 + // discriminator
+        2 + // id
+        (4 + Self::MAX_NAME_LEN) + // name
+        32 + // lead
+        4 + // member_count
+        4 + // total_attendance
+        4 + // total_late
+        8 + // total_points
+        1 + // bump
+        1 + // version
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 1;
+    pub const RESERVED_LEN: usize = 32;
+    
+    // 학회원 1인당 평균 포인트 (팀 간 비교용)
+    pub fn points_per_member(&self) -> u64 {
+        if self.member_count == 0 {
+            return 0;
+        }
+        self.total_points / self.member_count as u64
+    }
+    
+    // 소속 학회원 수 증감
+    pub fn add_member(&mut self) -> Result<()> {
+        self.member_count = checked_inc(self.member_count)?;
+        Ok(())
+    }
+    
+    pub fn remove_member(&mut self) -> Result<()> {
+        self.member_count = checked_dec(self.member_count)?;
+        Ok(())
+    }
+    
+    // 출석 상태와 획득 포인트를 팀 집계에 반영 (결석/공결은 집계하지 않음)
+    pub fn record_status(&mut self, status: &AttendanceStatus, points: u64) -> Result<()> {
+        match status {
+            AttendanceStatus::Present => self.total_attendance = checked_inc(self.total_attendance)?,
+            AttendanceStatus::Late => self.total_late = checked_inc(self.total_late)?,
+            AttendanceStatus::Absent | AttendanceStatus::Excused => {}
+        }
+        self.total_points = self.total_points
+            .checked_add(points)
+            .ok_or(AttendanceError::Overflow)?;
+        Ok(())
+    }
+    
+    // 출석 기록 종료/정정 시 팀 집계와 포인트 되돌리기
+    pub fn revert_status(&mut self, status: &AttendanceStatus, points: u64) -> Result<()> {
+        match status {
+            AttendanceStatus::Present => self.total_attendance = checked_dec(self.total_attendance)?,
+            AttendanceStatus::Late => self.total_late = checked_dec(self.total_late)?,
+            AttendanceStatus::Absent | AttendanceStatus::Excused => {}
+        }
+        self.total_points = self.total_points
+            .checked_sub(points)
+            .ok_or(AttendanceError::Overflow)?;
+        Ok(())
+    }
+}
+
+impl Leaderboard {
+    pub const CAPACITY: usize = 10;
+    
+    pub const LEN: usize = 8 + // discriminator
+        32 + // term
+        (4 + LeaderboardEntry::LEN * Self::CAPACITY) + // entries
+        1 + // bump
+        1 + // version
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 1;
+    pub const RESERVED_LEN: usize = 32;
+    
+    pub fn is_global(&self) -> bool {
+        self.term == Pubkey::default()
+    }
+    
+    // 학회원의 최신 통계를 반영해 순위 재배치 (순위 밖으로 밀리면 제외)
+    pub fn record(&mut self, wallet: Pubkey, points: u64, attendance_rate_bps: u16) {
+        self.entries.retain(|entry| entry.wallet != wallet);
+        
+        let entry = LeaderboardEntry {
+            wallet,
+            points,
+            attendance_rate_bps,
+        };
+        let rank = self.entries
+            .iter()
+            .position(|other| entry.ranks_above(other))
+            .unwrap_or(self.entries.len());
+        if rank < Self::CAPACITY {
+            self.entries.insert(rank, entry);
+            self.entries.truncate(Self::CAPACITY);
+        }
+    }
+    
+    // 학회원을 순위에서 제외 (제외했으면 true)
+    pub fn remove(&mut self, wallet: &Pubkey) -> bool {
+        let previous_len = self.entries.len();
+        self.entries.retain(|entry| entry.wallet != *wallet);
+        self.entries.len() != previous_len
+    }
+}
+
+impl LeaderboardEntry {
+    pub const LEN: usize = 32 + // wallet
+        8 + // points
+        2; // attendance_rate_bps
+    
+    pub fn ranks_above(&self, other: &LeaderboardEntry) -> bool {
+        (self.points, self.attendance_rate_bps) > (other.points, other.attendance_rate_bps)
+    }
+}
+
+impl SessionSeries {
+    pub const MAX_OCCURRENCES: usize = 26;
+    
+    pub const LEN: usize = 8 + // discriminator
+        8 + // id
+        (4 + Session::MAX_TITLE_LEN) + // title
+        1 + // kind
+        32 + // admin
+        (1 + 32) + // term
+        SeriesSchedule::LEN + // schedule
+        8 + // first_date
+        2 + // next_occurrence
+        (4 + 8 * Self::MAX_OCCURRENCES) + // session_ids
+        1 + // bump
+        1 + // version
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 1;
+    pub const RESERVED_LEN: usize = 28;
+    
+    const SECONDS_PER_WEEK: i64 = 7 * SessionTimeRules::SECONDS_PER_DAY;
+    
+    pub fn is_complete(&self) -> bool {
+        self.next_occurrence >= self.schedule.occurrence_count
+    }
+    
+    // 일정을 만든 운영진(생성되는 세션의 admin) 또는 최고 관리자인지 확인
+    pub fn is_manager(&self, config: &Config, authority: &Pubkey) -> bool {
+        self.admin == *authority || config.super_admin == *authority
+    }
+    
+    // n번째 회차의 현지 날짜 시작 시각
+    pub fn occurrence_date(&self, occurrence: u16) -> i64 {
+        self.first_date + occurrence as i64 * Self::SECONDS_PER_WEEK
+    }
+    
+    // 해당 날짜의 (체크인 시작, 세션 시작, 지각 기준) 시간
+    pub fn times_on(&self, date: i64) -> (i64, i64, i64) {
+        let start_time = date + self.schedule.start_time_of_day as i64;
+        (
+            start_time - self.schedule.open_window as i64,
+            start_time,
+            start_time + self.schedule.late_window as i64,
+        )
+    }
+}
+
+impl SeriesSchedule {
+    pub const LEN: usize = 8 + // start_date
+        1 + // weekday
+        4 + // start_time_of_day
+        4 + // open_window
+        4 + // late_window
+        2; // occurrence_count
+    
+    // 체크인 시작부터 지각 기준까지 하루 안에 끝나는 유효한 반복 규칙인지 확인
+    pub fn is_valid(&self) -> bool {
+        self.weekday < 7
+            && self.open_window > 0
+            && self.open_window <= self.start_time_of_day
+            && self.late_window > 0
+            && (self.start_time_of_day as i64 + self.late_window as i64) < SessionTimeRules::SECONDS_PER_DAY
+            && self.occurrence_count > 0
+            && self.occurrence_count as usize <= SessionSeries::MAX_OCCURRENCES
+    }
+}
+
+impl TermStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // term
+        32 + // member
+        4 + // total_attendance
+        4 + // total_late
+        4 + // total_absence
+        4 + // total_excused
+        8 + // total_points
+        1 + // bump
+        1 + // version
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 1;
+    pub const RESERVED_LEN: usize = 32;
+    
+    // 세션이 속한 학기와 학회원의 통계 계정인지 확인
+    pub fn is_for(&self, session: &Session, wallet: &Pubkey) -> bool {
+        session.term == Some(self.term) && self.member == *wallet
+    }
+    
+    // 출석률 (지각 포함, basis points, 10000 = 100%)
+    pub fn attendance_rate_bps(&self) -> u16 {
+        let attended = self.total_attendance as u64 + self.total_late as u64;
+        rate_bps(attended, attended + self.total_absence as u64)
+    }
+    
+    // 출석 상태와 획득 포인트를 학기 통계에 반영
+    pub fn record_status(&mut self, status: &AttendanceStatus, points: u64) -> Result<()> {
+        match status {
+            AttendanceStatus::Present => self.total_attendance = checked_inc(self.total_attendance)?,
+            AttendanceStatus::Late => self.total_late = checked_inc(self.total_late)?,
+            AttendanceStatus::Absent => self.total_absence = checked_inc(self.total_absence)?,
+            AttendanceStatus::Excused => self.total_excused = checked_inc(self.total_excused)?,
+        }
+        self.total_points = self.total_points
+            .checked_add(points)
+            .ok_or(AttendanceError::Overflow)?;
+        Ok(())
+    }
+    
+    // 출석 기록 종료/정정 시 학기 통계와 포인트 되돌리기
+    pub fn revert_status(&mut self, status: &AttendanceStatus, points: u64) -> Result<()> {
+        match status {
+            AttendanceStatus::Present => self.total_attendance = checked_dec(self.total_attendance)?,
+            AttendanceStatus::Late => self.total_late = checked_dec(self.total_late)?,
+            AttendanceStatus::Absent => self.total_absence = checked_dec(self.total_absence)?,
+            AttendanceStatus::Excused => self.total_excused = checked_dec(self.total_excused)?,
+        }
+        self.total_points = self.total_points
+            .checked_sub(points)
+            .ok_or(AttendanceError::Overflow)?;
+        Ok(())
+    }
+}
+
+impl MemberRole {
+    pub const COUNT: usize = 7;
+    
+    pub fn index(&self) -> usize {
+        self.clone() as usize
+    }
+}
+
+impl ExcuseRequest {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // member
+        32 + // session
+        32 + // reason_hash
+        8 + // submitted_at
+        1 + // is_pre_announced
+        1 + // status
+        (1 + 32) + // reviewed_by
+        1 + // bump
+        1 + // version
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 1;
+    pub const RESERVED_LEN: usize = 32;
 }
 
 impl Member {
@@ -82,7 +990,476 @@ impl Member {
         4 + // total_attendance
         4 + // total_late
         4 + // total_absence
+        4 + // total_excused
         8 + // total_points
         1 + // is_active
-        1; // bump
-}
\ No newline at end of file
+        1 + // bump
+        (4 + Self::MAX_DISPLAY_NAME_LEN) + // display_name
+        (1 + 2) + // cohort
+        (1 + 2) + // team_id
+        (4 + Self::MAX_PROFILE_URI_LEN) + // profile_uri
+        1 + // version
+        2 + // current_streak
+        2 + // longest_streak
+        8 + // minted_credit
+        32 + // payer
+        Self::RESERVED_LEN; // reserved
+    
+    pub const VERSION: u8 = 4;
+    pub const RESERVED_LEN: usize = 20;
+    
+    pub const MAX_DISPLAY_NAME_LEN: usize = 32;
+    pub const MAX_PROFILE_URI_LEN: usize = 200;
+    
+    // 계정 종료 시 rent 환불 대상 (payer를 기록하기 전에 등록된 학회원은 최고 관리자에게 환불)
+    pub fn rent_payer(&self, config: &Config) -> Pubkey {
+        if self.payer == Pubkey::default() {
+            config.super_admin
+        } else {
+            self.payer
+        }
+    }
+    
+    // 출석률 (지각 포함, basis points, 10000 = 100%)
+    pub fn attendance_rate_bps(&self) -> u16 {
+        let attended = self.total_attendance as u64 + self.total_late as u64;
+        rate_bps(attended, attended + self.total_absence as u64)
+    }
+    
+    // 출석 상태에 따라 연속 출석 갱신 후 달성한 마일스톤 보너스 반환
+    // (결석은 항상 초기화, 공결은 영향 없음)
+    pub fn update_streak(&mut self, status: &AttendanceStatus, rules: &StreakRules) -> u64 {
+        match status {
+            AttendanceStatus::Present => {
+                self.current_streak = self.current_streak.saturating_add(1);
+                self.longest_streak = self.longest_streak.max(self.current_streak);
+                rules.bonus_for(self.current_streak)
+            }
+            AttendanceStatus::Late if rules.keep_streak_on_late => 0,
+            AttendanceStatus::Late | AttendanceStatus::Absent => {
+                self.current_streak = 0;
+                0
+            }
+            AttendanceStatus::Excused => 0,
+        }
+    }
+    
+    // 출석 기록 종료 시 그 체크인으로 늘어난 연속 출석 되돌리기
+    // (지각/결석으로 초기화된 연속 출석은 복원할 수 없고, 최장 기록은 이력으로 유지)
+    pub fn revert_streak(&mut self, status: &AttendanceStatus) {
+        if *status == AttendanceStatus::Present {
+            self.current_streak = self.current_streak.saturating_sub(1);
+        }
+    }
+    
+    // 종료된 출석 기록으로 이미 발행된 포인트를 적립
+    // (토큰은 회수할 수 없으므로 같은 포인트를 다시 얻어도 재발행하지 않도록 함)
+    pub fn credit_minted_points(&mut self, points: u64) -> Result<()> {
+        self.minted_credit = self.minted_credit
+            .checked_add(points)
+            .ok_or(AttendanceError::Overflow)?;
+        Ok(())
+    }
+    
+    // 발행할 포인트에서 적립된 발행분을 먼저 차감하고 실제 발행할 양을 반환
+    pub fn take_mint_amount(&mut self, points: u64) -> u64 {
+        let covered = self.minted_credit.min(points);
+        self.minted_credit -= covered;
+        points - covered
+    }
+    
+    // 출석 상태와 획득 포인트를 학회원 통계에 반영
+    pub fn record_status(&mut self, status: &AttendanceStatus, points: u64) -> Result<()> {
+        match status {
+            AttendanceStatus::Present => self.total_attendance = checked_inc(self.total_attendance)?,
+            AttendanceStatus::Late => self.total_late = checked_inc(self.total_late)?,
+            AttendanceStatus::Absent => self.total_absence = checked_inc(self.total_absence)?,
+            AttendanceStatus::Excused => self.total_excused = checked_inc(self.total_excused)?,
+        }
+        self.total_points = self.total_points
+            .checked_add(points)
+            .ok_or(AttendanceError::Overflow)?;
+        Ok(())
+    }
+    
+    // 출석 기록 종료 시 학회원 통계와 포인트 되돌리기
+    pub fn revert_status(&mut self, status: &AttendanceStatus, points: u64) -> Result<()> {
+        match status {
+            AttendanceStatus::Present => self.total_attendance = checked_dec(self.total_attendance)?,
+            AttendanceStatus::Late => self.total_late = checked_dec(self.total_late)?,
+            AttendanceStatus::Absent => self.total_absence = checked_dec(self.total_absence)?,
+            AttendanceStatus::Excused => self.total_excused = checked_dec(self.total_excused)?,
+        }
+        self.total_points = self.total_points
+            .checked_sub(points)
+            .ok_or(AttendanceError::Overflow)?;
+        Ok(())
+    }
+}
+
+// 버전 필드 도입 이전(baseline) 계정 레이아웃 (migrate_* 전용)
+// 현재 레이아웃과 필드 순서/크기가 달라 0으로 확장해 읽을 수 없으므로 필드별로 변환
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SessionV0 {
+    pub admin: Pubkey,
+    pub session_date: i64,       // PDA seed ([b"session", session_date])
+    pub start_time: i64,
+    pub late_time: i64,
+    pub total_attendees: u32,
+    pub total_late: u32,
+    pub is_active: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MemberV0 {
+    pub wallet: Pubkey,
+    pub role: MemberRole,        // Admin, Member 순서 동일
+    pub total_attendance: u32,
+    pub total_late: u32,
+    pub total_absence: u32,
+    pub total_points: u64,
+    pub is_active: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AttendanceRecordV0 {
+    pub member: Pubkey,
+    pub session: Pubkey,
+    pub check_in_time: i64,
+    pub status: AttendanceStatus, // Present, Late, Absent 순서 동일
+    pub points_earned: u8,
+    pub bump: u8,
+}
+
+impl SessionV0 {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 4 + 4 + 1 + 1;
+    
+    // 세션 ID를 세션 날짜로 설정해 [b"session", id] seed가 기존 주소와 일치하도록 함
+    // (새 세션 ID는 Config.session_count 기반의 작은 값이라 겹치지 않음)
+    pub fn into_current(self) -> Session {
+        Session {
+            id: self.session_date as u64,
+            title: String::new(),
+            kind: SessionKind::Regular,
+            admin: self.admin,
+            co_hosts: Vec::new(),
+            session_date: self.session_date,
+            start_time: self.start_time,
+            late_time: self.late_time,
+            total_attendees: self.total_attendees,
+            total_late: self.total_late,
+            total_absent: 0,
+            total_excused: 0,
+            state_override: if self.is_active { SessionOverride::Auto } else { SessionOverride::Closed },
+            is_finalized: false,
+            point_schedule: PointSchedule::DEFAULT,
+            point_multiplier: Session::DEFAULT_POINT_MULTIPLIER,
+            presence_window: 0,
+            bump: self.bump,
+            version: Session::VERSION,
+            term: None,
+            open_time: 0,
+            series: None,
+            is_cancelled: false,
+            reserved: [0; Session::RESERVED_LEN],
+        }
+    }
+}
+
+impl MemberV0 {
+    pub const LEN: usize = 8 + 32 + 1 + 4 + 4 + 4 + 8 + 1 + 1;
+    
+    pub fn into_current(self) -> Member {
+        Member {
+            wallet: self.wallet,
+            role: self.role,
+            total_attendance: self.total_attendance,
+            total_late: self.total_late,
+            total_absence: self.total_absence,
+            total_excused: 0,
+            total_points: self.total_points,
+            is_active: self.is_active,
+            bump: self.bump,
+            display_name: String::new(),
+            cohort: None,
+            team_id: None,
+            profile_uri: String::new(),
+            version: Member::VERSION,
+            current_streak: 0,
+            longest_streak: 0,
+            minted_credit: 0,
+            payer: Pubkey::default(),
+            reserved: [0; Member::RESERVED_LEN],
+        }
+    }
+}
+
+impl AttendanceRecordV0 {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 1;
+    
+    // baseline 체크인은 학회원이 직접 rent를 지불하고 BAY 토큰은 발행되지 않았음
+    pub fn into_current(self) -> AttendanceRecord {
+        AttendanceRecord {
+            member: self.member,
+            session: self.session,
+            check_in_time: self.check_in_time,
+            status: self.status,
+            points_earned: self.points_earned as u64,
+            payer: self.member,
+            corrected_by: None,
+            correction_reason: None,
+            bump: self.bump,
+            version: AttendanceRecord::VERSION,
+            team_id: None,
+            points_minted: 0,
+            reserved: [0; AttendanceRecord::RESERVED_LEN],
+        }
+    }
+}
+
+fn rate_bps(numerator: u64, denominator: u64) -> u16 {
+    if denominator == 0 {
+        return 0;
+    }
+    (numerator * 10_000 / denominator) as u16
+}
+
+fn checked_inc(value: u32) -> Result<u32> {
+    value.checked_add(1).ok_or(AttendanceError::Overflow.into())
+}
+
+fn checked_dec(value: u32) -> Result<u32> {
+    value.checked_sub(1).ok_or(AttendanceError::Overflow.into())
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    // discriminator 포함 직렬화 크기
+    fn serialized_len<T: AccountSerialize>(account: &T) -> usize {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.len()
+    }
+    
+    fn max_string(len: usize) -> String {
+        "가".repeat(len / 3) + &"a".repeat(len % 3)
+    }
+    
+    fn key() -> Pubkey {
+        Pubkey::new_unique()
+    }
+    
+    #[test]
+    fn config_len_matches_serialized_size() {
+        let config = Config {
+            super_admin: key(),
+            session_count: u64::MAX,
+            point_schedule: PointSchedule::DEFAULT,
+            role_permissions: Config::DEFAULT_ROLE_PERMISSIONS,
+            bump: 255,
+            version: Config::VERSION,
+            term_count: u64::MAX,
+            streak_rules: StreakRules {
+                keep_streak_on_late: true,
+                milestones: [StreakMilestone { streak: 5, bonus_points: 10 }; StreakRules::MAX_MILESTONES],
+            },
+            session_time_rules: SessionTimeRules::DEFAULT,
+            team_count: u16::MAX,
+            series_count: u64::MAX,
+            point_mint: key(),
+            reserved: [0; Config::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&config), Config::LEN);
+    }
+    
+    #[test]
+    fn session_len_matches_serialized_size() {
+        let session = Session {
+            id: u64::MAX,
+            title: max_string(Session::MAX_TITLE_LEN),
+            kind: SessionKind::Event,
+            admin: key(),
+            co_hosts: (0..Session::MAX_CO_HOSTS).map(|_| key()).collect(),
+            session_date: i64::MAX,
+            start_time: i64::MAX,
+            late_time: i64::MAX,
+            total_attendees: u32::MAX,
+            total_late: u32::MAX,
+            total_absent: u32::MAX,
+            total_excused: u32::MAX,
+            state_override: SessionOverride::Open,
+            is_finalized: true,
+            point_schedule: PointSchedule::DEFAULT,
+            point_multiplier: u16::MAX,
+            presence_window: u32::MAX,
+            bump: 255,
+            version: Session::VERSION,
+            term: Some(key()),
+            open_time: i64::MAX,
+            series: Some(key()),
+            is_cancelled: true,
+            reserved: [0; Session::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&session), Session::LEN);
+    }
+    
+    #[test]
+    fn attendance_record_len_matches_serialized_size() {
+        let record = AttendanceRecord {
+            member: key(),
+            session: key(),
+            check_in_time: i64::MAX,
+            status: AttendanceStatus::Excused,
+            points_earned: u64::MAX,
+            payer: key(),
+            corrected_by: Some(key()),
+            correction_reason: Some(CorrectionReason::Other),
+            bump: 255,
+            version: AttendanceRecord::VERSION,
+            team_id: Some(u16::MAX),
+            points_minted: u64::MAX,
+            reserved: [0; AttendanceRecord::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&record), AttendanceRecord::LEN);
+    }
+    
+    #[test]
+    fn member_len_matches_serialized_size() {
+        let member = Member {
+            wallet: key(),
+            role: MemberRole::Probationary,
+            total_attendance: u32::MAX,
+            total_late: u32::MAX,
+            total_absence: u32::MAX,
+            total_excused: u32::MAX,
+            total_points: u64::MAX,
+            is_active: true,
+            bump: 255,
+            display_name: max_string(Member::MAX_DISPLAY_NAME_LEN),
+            cohort: Some(u16::MAX),
+            team_id: Some(u16::MAX),
+            profile_uri: max_string(Member::MAX_PROFILE_URI_LEN),
+            version: Member::VERSION,
+            current_streak: u16::MAX,
+            longest_streak: u16::MAX,
+            minted_credit: u64::MAX,
+            payer: key(),
+            reserved: [0; Member::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&member), Member::LEN);
+    }
+    
+    #[test]
+    fn excuse_request_len_matches_serialized_size() {
+        let excuse = ExcuseRequest {
+            member: key(),
+            session: key(),
+            reason_hash: [0xff; 32],
+            submitted_at: i64::MAX,
+            is_pre_announced: true,
+            status: ExcuseStatus::Rejected,
+            reviewed_by: Some(key()),
+            bump: 255,
+            version: ExcuseRequest::VERSION,
+            reserved: [0; ExcuseRequest::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&excuse), ExcuseRequest::LEN);
+    }
+    
+    #[test]
+    fn term_len_matches_serialized_size() {
+        let term = Term {
+            id: u64::MAX,
+            name: max_string(Term::MAX_NAME_LEN),
+            start_date: i64::MAX,
+            end_date: i64::MAX,
+            is_active: true,
+            total_sessions: u32::MAX,
+            bump: 255,
+            version: Term::VERSION,
+            reserved: [0; Term::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&term), Term::LEN);
+    }
+    
+    #[test]
+    fn team_len_matches_serialized_size() {
+        let team = Team {
+            id: u16::MAX,
+            name: max_string(Team::MAX_NAME_LEN),
+            lead: key(),
+            member_count: u32::MAX,
+            total_attendance: u32::MAX,
+            total_late: u32::MAX,
+            total_points: u64::MAX,
+            bump: 255,
+            version: Team::VERSION,
+            reserved: [0; Team::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&team), Team::LEN);
+    }
+    
+    #[test]
+    fn leaderboard_len_matches_serialized_size() {
+        let leaderboard = Leaderboard {
+            term: key(),
+            entries: (0..Leaderboard::CAPACITY)
+                .map(|_| LeaderboardEntry {
+                    wallet: key(),
+                    points: u64::MAX,
+                    attendance_rate_bps: 10_000,
+                })
+                .collect(),
+            bump: 255,
+            version: Leaderboard::VERSION,
+            reserved: [0; Leaderboard::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&leaderboard), Leaderboard::LEN);
+    }
+    
+    #[test]
+    fn session_series_len_matches_serialized_size() {
+        let series = SessionSeries {
+            id: u64::MAX,
+            title: max_string(Session::MAX_TITLE_LEN),
+            kind: SessionKind::Event,
+            admin: key(),
+            term: Some(key()),
+            schedule: SeriesSchedule {
+                start_date: i64::MAX,
+                weekday: 6,
+                start_time_of_day: u32::MAX,
+                open_window: u32::MAX,
+                late_window: u32::MAX,
+                occurrence_count: SessionSeries::MAX_OCCURRENCES as u16,
+            },
+            first_date: i64::MAX,
+            next_occurrence: SessionSeries::MAX_OCCURRENCES as u16,
+            session_ids: (0..SessionSeries::MAX_OCCURRENCES as u64).collect(),
+            bump: 255,
+            version: SessionSeries::VERSION,
+            reserved: [0; SessionSeries::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&series), SessionSeries::LEN);
+    }
+    
+    #[test]
+    fn term_stats_len_matches_serialized_size() {
+        let term_stats = TermStats {
+            term: key(),
+            member: key(),
+            total_attendance: u32::MAX,
+            total_late: u32::MAX,
+            total_absence: u32::MAX,
+            total_excused: u32::MAX,
+            total_points: u64::MAX,
+            bump: 255,
+            version: TermStats::VERSION,
+            reserved: [0; TermStats::RESERVED_LEN],
+        };
+        assert_eq!(serialized_len(&term_stats), TermStats::LEN);
+    }
+}
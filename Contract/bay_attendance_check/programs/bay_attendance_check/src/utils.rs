@@ -0,0 +1,372 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, instruction::Instruction};
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::{errors::AttendanceError, state::{AttendanceRecord, Leaderboard, Member, Session, Team, TermStats, MINT_AUTHORITY_SEED}};
+
+// remaining_accounts로 전달된 PDA 계정을 프로그램 소유 계정으로 생성
+// (lamports가 미리 입금된 경우에도 생성 가능하도록 Anchor init과 동일하게 처리)
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = target.lamports();
+    
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            program_id,
+        )?;
+    } else {
+        let required_lamports = rent.saturating_sub(current_lamports);
+        if required_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: target.clone(),
+                    },
+                ),
+                required_lamports,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate {
+                    account_to_allocate: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Assign {
+                    account_to_assign: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            program_id,
+        )?;
+    }
+    
+    Ok(())
+}
+
+// 계정 데이터 크기 확장 (추가 rent는 payer가 지불, 늘어난 영역은 0으로 채움)
+pub fn grow_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(new_len);
+    let required_lamports = rent.saturating_sub(target.lamports());
+    
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+    target.resize(new_len)?;
+    
+    Ok(())
+}
+
+// 이전 레이아웃의 계정을 현재 크기로 확장한 뒤 역직렬화
+// (늘어난 영역은 0으로 채워져 새 필드의 기본값이 됨)
+pub fn load_for_migration<'info, T: AccountDeserialize + Discriminator>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    len: usize,
+) -> Result<T> {
+    require_keys_eq!(
+        *target.owner,
+        *program_id,
+        AttendanceError::InvalidMigrationAccount
+    );
+    require!(
+        target.try_borrow_data()?.starts_with(T::DISCRIMINATOR),
+        AttendanceError::InvalidMigrationAccount
+    );
+    
+    if target.data_len() < len {
+        grow_account(payer, target, system_program, len)?;
+    }
+    
+    let data = target.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..])
+}
+
+// 학기에 속한 세션이면 해당 학기/학회원의 학기별 통계 계정 반환 (학기가 없으면 None)
+pub fn term_stats_for<'a, 'info>(
+    term_stats: &'a mut Option<Account<'info, TermStats>>,
+    session: &Session,
+    wallet: &Pubkey,
+) -> Result<Option<&'a mut Account<'info, TermStats>>> {
+    if session.term.is_none() {
+        return Ok(None);
+    }
+    
+    let term_stats = term_stats.as_mut().ok_or(AttendanceError::TermStatsRequired)?;
+    require!(
+        term_stats.is_for(session, wallet),
+        AttendanceError::TermStatsRequired
+    );
+    Ok(Some(term_stats))
+}
+
+// 출석 기록의 소속 팀 집계 계정 (팀이 없으면 None, 팀이 있는데 계정이 없거나 다른 팀이면 에러)
+pub fn team_for<'a, 'info>(
+    team: &'a mut Option<Account<'info, Team>>,
+    team_id: Option<u16>,
+) -> Result<Option<&'a mut Account<'info, Team>>> {
+    let Some(team_id) = team_id else {
+        return Ok(None);
+    };
+    
+    let team = team.as_mut().ok_or(AttendanceError::TeamRequired)?;
+    require!(
+        team.id == team_id,
+        AttendanceError::TeamRequired
+    );
+    Ok(Some(team))
+}
+
+// 전달된 전체/학기 리더보드에 학회원의 최신 통계 반영
+// (리더보드 계정은 선택 사항, 누락된 갱신은 refresh_leaderboard로 보정)
+pub fn update_leaderboards(
+    leaderboard: Option<&mut Leaderboard>,
+    term_leaderboard: Option<&mut Leaderboard>,
+    session: &Session,
+    member: &Member,
+    term_stats: Option<&TermStats>,
+) -> Result<()> {
+    if let Some(leaderboard) = leaderboard {
+        require!(leaderboard.is_global(), AttendanceError::InvalidLeaderboard);
+        leaderboard.record(member.wallet, member.total_points, member.attendance_rate_bps());
+    }
+    
+    if let Some(term_leaderboard) = term_leaderboard {
+        require!(
+            session.term == Some(term_leaderboard.term),
+            AttendanceError::InvalidLeaderboard
+        );
+        let term_stats = term_stats.ok_or(AttendanceError::TermStatsRequired)?;
+        term_leaderboard.record(member.wallet, term_stats.total_points, term_stats.attendance_rate_bps());
+    }
+    
+    Ok(())
+}
+
+// remaining_accounts의 [attendance_record, member, payer, (term_stats), (team)] 묶음 하나를 처리
+// 세션/학회원/학기/팀 통계와 포인트를 되돌리고 출석 기록을 종료해 rent를 payer에게 환불
+// (학기에 속한 세션이면 term_stats, 출석 기록에 팀이 있으면 team 계정이 묶음에 포함,
+// close_member로 이미 종료된 학회원이면 member 자리에 빈 학회원 PDA를 전달하고 학회원 통계는 생략)
+// 전달된 전체/학기 리더보드에는 되돌린 학회원 통계를 반영
+// 반환값: 출석 기록의 학회원 지갑
+pub fn close_attendance_record<'info, I: Iterator<Item = &'info AccountInfo<'info>>>(
+    remaining_accounts: &mut I,
+    record_info: &'info AccountInfo<'info>,
+    session: &mut Account<'info, Session>,
+    leaderboard: Option<&mut Leaderboard>,
+    term_leaderboard: Option<&mut Leaderboard>,
+    program_id: &Pubkey,
+) -> Result<Pubkey> {
+    let member_info = next_account_info(remaining_accounts)
+        .map_err(|_| AttendanceError::InvalidRemainingAccounts)?;
+    let payer_info = next_account_info(remaining_accounts)
+        .map_err(|_| AttendanceError::InvalidRemainingAccounts)?;
+    
+    require!(
+        record_info.is_writable && member_info.is_writable && payer_info.is_writable,
+        AttendanceError::InvalidRemainingAccounts
+    );
+    
+    let record: Account<'info, AttendanceRecord> = Account::try_from(record_info)?;
+    
+    require_keys_eq!(record.session, session.key(), AttendanceError::InvalidRemainingAccounts);
+    require_keys_eq!(payer_info.key(), record.payer, AttendanceError::InvalidRemainingAccounts);
+    
+    // 세션 통계 되돌리기
+    session.revert_status(&record.status)?;
+    
+    let member = if member_info.data_is_empty() {
+        // 종료된 학회원은 되돌릴 통계가 없으므로 학회원 PDA 주소만 검증
+        let (member_key, _) = Pubkey::find_program_address(
+            &[b"member", record.member.as_ref()],
+            program_id,
+        );
+        require_keys_eq!(member_info.key(), member_key, AttendanceError::InvalidRemainingAccounts);
+        None
+    } else {
+        // 학회원 통계/포인트/연속 출석 되돌리기
+        // (이미 발행된 BAY 토큰은 회수할 수 없으므로 학회원에게 적립해 재발행 방지)
+        let mut member: Account<'info, Member> = Account::try_from(member_info)?;
+        require_keys_eq!(record.member, member.wallet, AttendanceError::InvalidRemainingAccounts);
+        member.revert_status(&record.status, record.points_earned)?;
+        member.revert_streak(&record.status);
+        member.credit_minted_points(record.points_minted)?;
+        member.exit(program_id)?;
+        Some(member)
+    };
+    
+    let term_stats = if session.term.is_some() {
+        let term_stats_info = next_account_info(remaining_accounts)
+            .map_err(|_| AttendanceError::InvalidRemainingAccounts)?;
+        let mut term_stats = load_term_stats(term_stats_info, session, &record.member)?;
+        term_stats.revert_status(&record.status, record.points_earned)?;
+        term_stats.exit(program_id)?;
+        Some(term_stats)
+    } else {
+        None
+    };
+    
+    if let Some(member) = member.as_ref() {
+        update_leaderboards(leaderboard, term_leaderboard, session, member, term_stats.as_deref())?;
+    }
+    
+    if let Some(team_id) = record.team_id {
+        let team_info = next_account_info(remaining_accounts)
+            .map_err(|_| AttendanceError::InvalidRemainingAccounts)?;
+        let mut team = load_team(team_info, team_id)?;
+        team.revert_status(&record.status, record.points_earned)?;
+        team.exit(program_id)?;
+    }
+    
+    // 출석 기록 종료 후 rent 환불
+    let wallet = record.member;
+    record.close(payer_info.clone())?;
+    
+    Ok(wallet)
+}
+
+// remaining_accounts로 전달된 팀 계정 로드 및 검증
+pub fn load_team<'info>(
+    info: &'info AccountInfo<'info>,
+    team_id: u16,
+) -> Result<Account<'info, Team>> {
+    require!(info.is_writable, AttendanceError::InvalidRemainingAccounts);
+    
+    let team: Account<'info, Team> = Account::try_from(info)?;
+    require!(
+        team.id == team_id,
+        AttendanceError::TeamRequired
+    );
+    Ok(team)
+}
+
+// remaining_accounts로 전달된 학기별 통계 계정 로드 및 검증
+pub fn load_term_stats<'info>(
+    info: &'info AccountInfo<'info>,
+    session: &Session,
+    wallet: &Pubkey,
+) -> Result<Account<'info, TermStats>> {
+    require!(info.is_writable, AttendanceError::InvalidRemainingAccounts);
+    
+    let term_stats: Account<'info, TermStats> = Account::try_from(info)?;
+    require!(
+        term_stats.is_for(session, wallet),
+        AttendanceError::TermStatsRequired
+    );
+    Ok(term_stats)
+}
+
+// 획득한 포인트만큼 BAY 토큰 발행 (mint authority PDA 서명)
+pub fn mint_points<'info>(
+    token_program: &Program<'info, Token>,
+    point_mint: &Account<'info, Mint>,
+    to: &Account<'info, TokenAccount>,
+    mint_authority: &AccountInfo<'info>,
+    mint_authority_bump: u8,
+    points: u64,
+) -> Result<()> {
+    let amount = points
+        .checked_mul(10u64.pow(point_mint.decimals as u32))
+        .ok_or(AttendanceError::Overflow)?;
+    let signer_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[mint_authority_bump]]];
+    
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: point_mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: mint_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+// Ed25519 서명 검증 프로그램 instruction에서 서명한 공개키와 메시지 추출
+// (서명 검증 자체는 런타임이 Ed25519 프로그램 실행 시 수행)
+pub fn parse_ed25519_instruction(ix: &Instruction) -> Result<(Pubkey, Vec<u8>)> {
+    const HEADER_LEN: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    
+    require_keys_eq!(ix.program_id, ed25519_program::ID, AttendanceError::InvalidPresenceProof);
+    require!(ix.accounts.is_empty(), AttendanceError::InvalidPresenceProof);
+    
+    let data = &ix.data;
+    require!(
+        data.len() >= HEADER_LEN + OFFSETS_LEN && data[0] == 1,
+        AttendanceError::InvalidPresenceProof
+    );
+    
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+    let offsets = HEADER_LEN;
+    let signature_ix_index = read_u16(offsets + 2);
+    let public_key_offset = read_u16(offsets + 4) as usize;
+    let public_key_ix_index = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+    let message_ix_index = read_u16(offsets + 12);
+    
+    // 서명/공개키/메시지가 모두 같은 instruction 안에 있어야 함
+    require!(
+        signature_ix_index == u16::MAX
+            && public_key_ix_index == u16::MAX
+            && message_ix_index == u16::MAX,
+        AttendanceError::InvalidPresenceProof
+    );
+    
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(AttendanceError::InvalidPresenceProof)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(AttendanceError::InvalidPresenceProof)?;
+    
+    let public_key = Pubkey::try_from(public_key)
+        .map_err(|_| AttendanceError::InvalidPresenceProof)?;
+    
+    Ok((public_key, message.to_vec()))
+}
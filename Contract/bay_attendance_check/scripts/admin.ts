@@ -5,6 +5,18 @@ import { PublicKey, Keypair } from "@solana/web3.js";
 import * as fs from "fs";
 import * as path from "path";
 
+const BAY_TOKEN_MINT = new PublicKey("bay3egCym863ziQsvesuGptuGDkekVN6jwwdPd3Ywu2");
+
+const ROLES = {
+  admin: { admin: {} },
+  member: { member: {} },
+  president: { president: {} },
+  "track-lead": { trackLead: {} },
+  "team-lead": { teamLead: {} },
+  alumni: { alumni: {} },
+  probationary: { probationary: {} },
+};
+
 // Admin script for managing BAY attendance system
 async function main() {
   // Setup provider
@@ -20,28 +32,141 @@ async function main() {
   if (!command) {
     console.log("Usage: ts-node admin.ts <command> [options]");
     console.log("\nCommands:");
+    console.log("  init-config <super-admin>    - Initialize program config with the BAY mint (upgrade authority only)");
+    console.log("  set-point-mint               - Point the config at the BAY mint (super admin only)");
     console.log("  init-admin <wallet-path>     - Initialize an admin member");
-    console.log("  init-session <date> <start-time> <late-time> - Create a new session");
-    console.log("  close-session <date>         - Close an active session");
-    console.log("  reactivate-session <date> <start-time> <late-time> - Reactivate a closed session");
-    console.log("  session-stats <date>         - Get session statistics");
+    console.log("  init-member <wallet>         - Register a regular member");
+    console.log("  set-member-active <wallet> <true|false> - Activate or deactivate a member");
+    console.log("  change-member-role <wallet> <role> - Change a member's role");
+    console.log("  set-role-permissions <role> <bitmask> - Set a role's permissions (super admin only)");
+    console.log("  close-member <wallet>        - Close a deactivated member account (refunds rent to the registering wallet, removes leaderboard entries)");
+    console.log("  init-team <name> <lead-wallet> - Create a team");
+    console.log("  update-team <team-id> <name> <lead-wallet> - Rename a team or change its lead");
+    console.log("  set-member-team <wallet> <team-id|none> - Move a member to a team");
+    console.log("  team-stats <team-id>         - Get team statistics");
+    console.log("  init-leaderboard [term-id]   - Create the global leaderboard (or a term leaderboard)");
+    console.log("  refresh-leaderboard [term-id] - Re-rank a leaderboard from every member's stats");
+    console.log("  init-term <name> <start-date> <end-date> - Create a term (semester)");
+    console.log("  close-term <term-id>         - Stop adding sessions to a term");
+    console.log("  init-term-stats <term-id> <wallet...> - Create term statistics accounts for members");
+    console.log("  init-session <date> <start-time> <late-time> [title] [regular|study|event] [term-id] - Create a new session (check-in opens at the start of the date)");
+    console.log("  create-series <start-date> <weekday 0=Mon> <start-time> <open-minutes> <late-minutes> <count> [title] [regular|study|event] [term-id] - Schedule weekly sessions");
+    console.log("  create-series-sessions <series-id> - Create the remaining sessions of a series");
+    console.log("  reschedule-series <series-id> <start-time> <late-minutes> - Move sessions of a series whose check-in has not started");
+    console.log("  close-session <session-id>   - Close an active session");
+    console.log("  set-session-override <session-id> <auto|open|closed> - Open or close a session manually, or return it to the clock");
+    console.log("  cancel-session <session-id>  - Cancel a session, reverting its attendance and refunding record and session rent");
+    console.log("  reactivate-session <session-id> <date> <start-time> <late-time> [extend|reset] - Reactivate a closed session");
+    console.log("  finalize-session <session-id> - Mark absentees and lock the session");
+    console.log("  set-point-schedule <present> <late> [early-bird-bonus] [early-bird-window-secs] - Set default rewards for new sessions");
+    console.log("  set-session-time-rules <utc-offset-hours> <max-late-minutes> - Set session timezone and late window cap (super admin only)");
+    console.log("  set-streak-rules <keep-on-late> [streak:bonus...] - Set streak milestones (e.g. false 5:10 10:30)");
+    console.log("  set-session-rewards <session-id> <multiplier-percent> - Set a session's reward multiplier before check-in opens (100 = 1x)");
+    console.log("  record-attendance <session-id> <wallet> <present|late|absent> [device|wallet|error|other] - Record or correct a member's attendance");
+    console.log("  set-presence-mode <session-id> <window-secs> - Require rotating QR codes for check-in (0 = off)");
+    console.log("  session-stats <session-id>   - Get session statistics");
     console.log("  member-stats <wallet>        - Get member statistics");
+    console.log("  migrate-accounts [config|session|member|record] - Migrate accounts created with an older layout");
     return;
   }
 
   try {
     switch (command) {
+      case "init-config":
+        await initializeConfig(program, args[1]);
+        break;
+      case "set-point-mint":
+        await setPointMint(program);
+        break;
       case "init-admin":
         await initializeAdmin(program, args[1]);
         break;
+      case "init-member":
+        await initializeRegularMember(program, args[1]);
+        break;
+      case "set-member-active":
+        await setMemberActive(program, args[1], args[2]);
+        break;
+      case "change-member-role":
+        await changeMemberRole(program, args[1], args[2]);
+        break;
+      case "set-role-permissions":
+        await setRolePermissions(program, args[1], args[2]);
+        break;
+      case "close-member":
+        await closeMember(program, args[1]);
+        break;
+      case "init-team":
+        await initializeTeam(program, args[1], args[2]);
+        break;
+      case "update-team":
+        await updateTeam(program, args[1], args[2], args[3]);
+        break;
+      case "set-member-team":
+        await setMemberTeam(program, args[1], args[2]);
+        break;
+      case "team-stats":
+        await getTeamStats(program, args[1]);
+        break;
+      case "init-leaderboard":
+        await initializeLeaderboard(program, args[1]);
+        break;
+      case "refresh-leaderboard":
+        await refreshLeaderboard(program, args[1]);
+        break;
+      case "init-term":
+        await initializeTerm(program, args[1], args[2], args[3]);
+        break;
+      case "close-term":
+        await closeTerm(program, args[1]);
+        break;
+      case "init-term-stats":
+        await initializeTermStats(program, args[1], args.slice(2));
+        break;
       case "init-session":
-        await initializeSession(program, args[1], args[2], args[3]);
+        await initializeSession(program, args[1], args[2], args[3], args[4], args[5], args[6]);
+        break;
+      case "create-series":
+        await createSessionSeries(program, args[1], args[2], args[3], args[4], args[5], args[6], args[7], args[8], args[9]);
+        break;
+      case "create-series-sessions":
+        await createSeriesSessions(program, args[1]);
+        break;
+      case "reschedule-series":
+        await rescheduleSeries(program, args[1], args[2], args[3]);
         break;
       case "close-session":
-        await closeSession(program, args[1]);
+        await setSessionOverride(program, args[1], "closed");
+        break;
+      case "set-session-override":
+        await setSessionOverride(program, args[1], args[2]);
+        break;
+      case "cancel-session":
+        await cancelSession(program, args[1]);
         break;
       case "reactivate-session":
-        await reactivateSession(program, args[1], args[2], args[3]);
+        await reactivateSession(program, args[1], args[2], args[3], args[4], args[5]);
+        break;
+      case "finalize-session":
+        await finalizeSession(program, args[1]);
+        break;
+      case "set-point-schedule":
+        await setPointSchedule(program, args[1], args[2], args[3], args[4]);
+        break;
+      case "set-session-time-rules":
+        await setSessionTimeRules(program, args[1], args[2]);
+        break;
+      case "set-streak-rules":
+        await setStreakRules(program, args[1], args.slice(2));
+        break;
+      case "set-session-rewards":
+        await setSessionRewards(program, args[1], args[2]);
+        break;
+      case "record-attendance":
+        await recordAttendance(program, args[1], args[2], args[3], args[4]);
+        break;
+      case "set-presence-mode":
+        await setPresenceMode(program, args[1], args[2]);
         break;
       case "session-stats":
         await getSessionStats(program, args[1]);
@@ -49,6 +174,9 @@ async function main() {
       case "member-stats":
         await getMemberStats(program, args[1]);
         break;
+      case "migrate-accounts":
+        await migrateAccounts(program, args[1]);
+        break;
       default:
         console.error("Unknown command:", command);
     }
@@ -57,6 +185,235 @@ async function main() {
   }
 }
 
+// Returns the authority's Admin member PDA, or null if it has none (super admin bootstrap)
+// Session PDAs are derived from the session counter in Config
+function sessionPDAFor(program: Program<BayAttendanceCheck>, sessionId: string | number) {
+  const [sessionPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("session"), new anchor.BN(sessionId).toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return sessionPDA;
+}
+
+async function adminMemberFor(program: Program<BayAttendanceCheck>, authority: PublicKey) {
+  const [adminMemberPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("member"), authority.toBuffer()],
+    program.programId
+  );
+  const info = await program.provider.connection.getAccountInfo(adminMemberPDA);
+  return info ? adminMemberPDA : null;
+}
+
+async function initializeConfig(program: Program<BayAttendanceCheck>, superAdminStr: string) {
+  if (!superAdminStr) {
+    console.error("Please provide super admin wallet address");
+    return;
+  }
+
+  const superAdmin = new PublicKey(superAdminStr);
+  const authority = (program.provider as anchor.AnchorProvider).wallet;
+
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+
+  console.log("Initializing config...");
+  console.log("Super admin:", superAdmin.toString());
+
+  const tx = await program.methods
+    .initializeConfig(superAdmin)
+    .accounts({
+      authority: authority.publicKey,
+      programData,
+      pointMint: BAY_TOKEN_MINT,
+    })
+    .rpc();
+
+  console.log("Config initialized successfully!");
+  console.log("Transaction:", tx);
+}
+
+// Configs migrated from before the point mint was stored need this once
+async function setPointMint(program: Program<BayAttendanceCheck>) {
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+
+  console.log("Setting point mint:", BAY_TOKEN_MINT.toString());
+
+  const tx = await program.methods
+    .setPointMint()
+    .accounts({
+      authority: adminWallet.publicKey,
+      pointMint: BAY_TOKEN_MINT,
+    })
+    .rpc();
+
+  console.log("Point mint updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function initializeRegularMember(program: Program<BayAttendanceCheck>, walletStr: string) {
+  if (!walletStr) {
+    console.error("Please provide member wallet address");
+    return;
+  }
+
+  const memberWallet = new PublicKey(walletStr);
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+
+  const [memberPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("member"), memberWallet.toBuffer()],
+    program.programId
+  );
+
+  console.log("Registering member...");
+  console.log("Member wallet:", memberWallet.toString());
+  console.log("Member PDA:", memberPDA.toString());
+
+  const tx = await program.methods
+    .initializeMember({ member: {} })
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+      memberWallet,
+    })
+    .rpc();
+
+  console.log("Member registered successfully!");
+  console.log("Transaction:", tx);
+}
+
+function memberPDAFor(program: Program<BayAttendanceCheck>, wallet: PublicKey) {
+  const [memberPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("member"), wallet.toBuffer()],
+    program.programId
+  );
+  return memberPDA;
+}
+
+async function setMemberActive(program: Program<BayAttendanceCheck>, walletStr: string, activeStr: string) {
+  if (!walletStr || (activeStr !== "true" && activeStr !== "false")) {
+    console.error("Please provide member wallet address and true/false");
+    console.error("Example: set-member-active <wallet> false");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const memberPDA = memberPDAFor(program, new PublicKey(walletStr));
+
+  console.log("Updating member status...");
+  console.log("Member PDA:", memberPDA.toString());
+
+  const tx = await program.methods
+    .setMemberActive(activeStr === "true")
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+      member: memberPDA,
+    })
+    .rpc();
+
+  console.log("Member status updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function changeMemberRole(program: Program<BayAttendanceCheck>, walletStr: string, roleStr: string) {
+  if (!walletStr || !ROLES[roleStr]) {
+    console.error(`Please provide member wallet address and role (${Object.keys(ROLES).join("|")})`);
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const memberPDA = memberPDAFor(program, new PublicKey(walletStr));
+
+  console.log("Changing member role...");
+  console.log("Member PDA:", memberPDA.toString());
+  console.log("New role:", roleStr);
+
+  const tx = await program.methods
+    .changeMemberRole(ROLES[roleStr])
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+      member: memberPDA,
+    })
+    .rpc();
+
+  console.log("Member role changed successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function setRolePermissions(program: Program<BayAttendanceCheck>, roleStr: string, permissionsStr: string) {
+  if (!ROLES[roleStr] || permissionsStr === undefined) {
+    console.error("Please provide role and permission bitmask");
+    console.error("Bits: 1=create session, 2=correct attendance, 4=manage products (marketplace), 8=mint NFTs (bay_nft scripts), 16=manage members");
+    console.error("Example: set-role-permissions track-lead 3");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+
+  console.log("Updating role permissions...");
+  console.log("Role:", roleStr);
+  console.log("Permissions:", Number(permissionsStr).toString(2).padStart(5, "0"));
+
+  const tx = await program.methods
+    .setRolePermissions(ROLES[roleStr], Number(permissionsStr))
+    .accounts({
+      authority: adminWallet.publicKey,
+    })
+    .rpc();
+
+  console.log("Role permissions updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function closeMember(program: Program<BayAttendanceCheck>, walletStr: string) {
+  if (!walletStr) {
+    console.error("Please provide member wallet address");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const wallet = new PublicKey(walletStr);
+  const memberPDA = memberPDAFor(program, wallet);
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const config = await program.account.config.fetch(configPDA);
+  const member = await program.account.member.fetch(memberPDA);
+
+  // Members registered before the payer was recorded are refunded to the super admin
+  const payer = member.payer.equals(PublicKey.default) ? config.superAdmin : member.payer;
+
+  // Remove the member from every leaderboard it still ranks on
+  const leaderboards = (await program.account.leaderboard.all())
+    .filter((l) => l.account.entries.some((e) => e.wallet.equals(wallet)))
+    .map((l) => l.publicKey);
+
+  console.log("Closing member account...");
+  console.log("Member PDA:", memberPDA.toString());
+  console.log("Rent refunded to:", payer.toString());
+  console.log("Leaderboards updated:", leaderboards.length);
+
+  const tx = await program.methods
+    .closeMember()
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+      member: memberPDA,
+      payer,
+    })
+    .remainingAccounts(
+      leaderboards.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+    )
+    .rpc();
+
+  console.log("Member account closed successfully!");
+  console.log("Transaction:", tx);
+}
+
 async function initializeAdmin(program: Program<BayAttendanceCheck>, walletPath: string) {
   if (!walletPath) {
     console.error("Please provide wallet path");
@@ -77,34 +434,312 @@ async function initializeAdmin(program: Program<BayAttendanceCheck>, walletPath:
   console.log("Admin wallet:", adminKeypair.publicKey.toString());
   console.log("Member PDA:", memberPDA.toString());
 
+  // Registration must be signed by the super admin or an existing Admin member
+  const authority = (program.provider as anchor.AnchorProvider).wallet;
+
   const tx = await program.methods
     .initializeMember({ admin: {} })
-    .accounts({
-      authority: adminKeypair.publicKey,
-      admin: adminKeypair.publicKey,
+    .accountsPartial({
+      authority: authority.publicKey,
+      admin: await adminMemberFor(program, authority.publicKey),
       memberWallet: adminKeypair.publicKey,
     })
-    .signers([adminKeypair])
     .rpc();
 
   console.log("Admin initialized successfully!");
   console.log("Transaction:", tx);
 }
 
+function teamPDAFor(program: Program<BayAttendanceCheck>, teamId: string | number) {
+  const [teamPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("team"), new anchor.BN(teamId).toArrayLike(Buffer, "le", 2)],
+    program.programId
+  );
+  return teamPDA;
+}
+
+async function initializeTeam(program: Program<BayAttendanceCheck>, name: string, leadStr: string) {
+  if (!name || !leadStr) {
+    console.error("Please provide team name and lead wallet");
+    console.error("Example: init-team \"Team Solana\" <wallet>");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const config = await program.account.config.fetch(configPDA);
+
+  console.log("Creating team...");
+  console.log("Team ID:", config.teamCount);
+  console.log("Name:", name, "Lead:", leadStr);
+
+  const tx = await program.methods
+    .initializeTeam(name, new PublicKey(leadStr))
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+    })
+    .rpc();
+
+  console.log("Team created successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function updateTeam(program: Program<BayAttendanceCheck>, teamIdStr: string, name: string, leadStr: string) {
+  if (!teamIdStr || !name || !leadStr) {
+    console.error("Please provide team id, name and lead wallet");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+
+  const tx = await program.methods
+    .updateTeam(name, new PublicKey(leadStr))
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+      team: teamPDAFor(program, teamIdStr),
+    })
+    .rpc();
+
+  console.log("Team updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function setMemberTeam(program: Program<BayAttendanceCheck>, walletStr: string, teamIdStr: string) {
+  if (!walletStr || !teamIdStr) {
+    console.error("Please provide member wallet and team id (or none)");
+    console.error("Example: set-member-team <wallet> 2");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const memberPDA = memberPDAFor(program, new PublicKey(walletStr));
+  const member = await program.account.member.fetch(memberPDA);
+  const teamId = teamIdStr === "none" ? null : Number(teamIdStr);
+
+  console.log("Changing member team...");
+  console.log("Member:", walletStr);
+  console.log("Team:", member.teamId, "->", teamId);
+
+  const tx = await program.methods
+    .setMemberTeam(teamId)
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+      member: memberPDA,
+      previousTeam: member.teamId !== null ? teamPDAFor(program, member.teamId) : null,
+      team: teamId !== null ? teamPDAFor(program, teamId) : null,
+    })
+    .rpc();
+
+  console.log("Member team changed successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function getTeamStats(program: Program<BayAttendanceCheck>, teamIdStr: string) {
+  if (!teamIdStr) {
+    console.error("Please provide team id");
+    return;
+  }
+
+  const stats = await program.methods
+    .getTeamStats()
+    .accounts({
+      team: teamPDAFor(program, teamIdStr),
+    })
+    .view();
+
+  console.log("\n=== Team Statistics ===");
+  console.log("Team:", stats.name, `(#${stats.teamId})`);
+  console.log("Lead:", stats.lead.toString());
+  console.log("Members:", stats.memberCount);
+  console.log("Total Attendance:", stats.totalAttendance);
+  console.log("Total Late:", stats.totalLate);
+  console.log("Total Points:", stats.totalPoints.toString());
+  console.log("Points per Member:", stats.pointsPerMember.toString());
+}
+
+function leaderboardPDAFor(program: Program<BayAttendanceCheck>, termIdStr?: string) {
+  // The global leaderboard is keyed by the default public key
+  const term = termIdStr ? termPDAFor(program, termIdStr) : PublicKey.default;
+  const [leaderboardPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("leaderboard"), term.toBuffer()],
+    program.programId
+  );
+  return { term, leaderboardPDA };
+}
+
+async function initializeLeaderboard(program: Program<BayAttendanceCheck>, termIdStr?: string) {
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const { term, leaderboardPDA } = leaderboardPDAFor(program, termIdStr);
+
+  console.log("Creating leaderboard...");
+  console.log("Term:", termIdStr ?? "global");
+  console.log("Leaderboard PDA:", leaderboardPDA.toString());
+
+  const tx = await program.methods
+    .initializeLeaderboard(term)
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+      termAccount: termIdStr ? term : null,
+    })
+    .rpc();
+
+  console.log("Leaderboard created successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function refreshLeaderboard(program: Program<BayAttendanceCheck>, termIdStr?: string) {
+  const { term, leaderboardPDA } = leaderboardPDAFor(program, termIdStr);
+
+  // Global leaderboards rank Member accounts, term leaderboards rank TermStats accounts
+  const accounts = termIdStr
+    ? (await program.account.termStats.all([{ memcmp: { offset: 8, bytes: term.toBase58() } }])).map((a) => a.publicKey)
+    : (await program.account.member.all()).map((a) => a.publicKey);
+  console.log("Refreshing leaderboard with", accounts.length, "accounts...");
+
+  const BATCH_SIZE = 20;
+  for (let index = 0; index < accounts.length; index += BATCH_SIZE) {
+    const tx = await program.methods
+      .refreshLeaderboard()
+      .accounts({
+        leaderboard: leaderboardPDA,
+      })
+      .remainingAccounts(
+        accounts.slice(index, index + BATCH_SIZE).map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+      )
+      .rpc();
+    console.log("Transaction:", tx);
+  }
+
+  const leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+  leaderboard.entries.forEach((entry, index) => {
+    console.log(
+      `${(index + 1).toString().padStart(2)}. ${entry.wallet.toString()} ` +
+      `${entry.points.toString()} points, ${(entry.attendanceRateBps / 100).toFixed(1)}%`
+    );
+  });
+}
+
+function termPDAFor(program: Program<BayAttendanceCheck>, termId: string | number) {
+  const [termPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("term"), new anchor.BN(termId).toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return termPDA;
+}
+
+async function initializeTerm(
+  program: Program<BayAttendanceCheck>,
+  name: string,
+  startDateStr: string,
+  endDateStr: string
+) {
+  if (!name || !startDateStr || !endDateStr) {
+    console.error("Please provide term name, start date and end date");
+    console.error("Example: init-term 2025-2 2025-09-01 2025-12-20");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const startDate = new Date(startDateStr).getTime() / 1000;
+  const endDate = new Date(`${endDateStr} 23:59:59`).getTime() / 1000;
+
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const config = await program.account.config.fetch(configPDA);
+
+  console.log("Creating term...");
+  console.log("Term ID:", config.termCount.toString());
+  console.log("Name:", name);
+  console.log("Period:", new Date(startDate * 1000).toISOString(), "~", new Date(endDate * 1000).toISOString());
+
+  const tx = await program.methods
+    .initializeTerm(name, new anchor.BN(startDate), new anchor.BN(endDate))
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+    })
+    .rpc();
+
+  console.log("Term created successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function closeTerm(program: Program<BayAttendanceCheck>, termIdStr: string) {
+  if (!termIdStr) {
+    console.error("Please provide term id");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+
+  const tx = await program.methods
+    .setTermActive(false)
+    .accountsPartial({
+      authority: adminWallet.publicKey,
+      admin: await adminMemberFor(program, adminWallet.publicKey),
+      term: termPDAFor(program, termIdStr),
+    })
+    .rpc();
+
+  console.log("Term closed successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function initializeTermStats(program: Program<BayAttendanceCheck>, termIdStr: string, wallets: string[]) {
+  if (!termIdStr || wallets.length === 0) {
+    console.error("Please provide term id and member wallet addresses");
+    console.error("Example: init-term-stats 0 <wallet1> <wallet2>");
+    return;
+  }
+
+  const termPDA = termPDAFor(program, termIdStr);
+
+  for (const walletStr of wallets) {
+    const [memberPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("member"), new PublicKey(walletStr).toBuffer()],
+      program.programId
+    );
+
+    const tx = await program.methods
+      .initializeTermStats()
+      .accounts({
+        term: termPDA,
+        member: memberPDA,
+      })
+      .rpc();
+
+    console.log(`Term stats created for ${walletStr}: ${tx}`);
+  }
+}
+
 async function initializeSession(
   program: Program<BayAttendanceCheck>, 
   dateStr: string, 
   startTimeStr: string, 
-  lateTimeStr: string
+  lateTimeStr: string,
+  title: string = "BAY Session",
+  kind: string = "regular",
+  termIdStr?: string
 ) {
   if (!dateStr || !startTimeStr || !lateTimeStr) {
     console.error("Please provide date, start time, and late time");
-    console.error("Example: init-session 2024-07-30 19:30 20:00");
+    console.error("Example: init-session 2024-07-30 19:30 20:00 \"Main Session\" regular");
     return;
   }
 
   // Parse dates
   const sessionDate = new Date(dateStr).getTime() / 1000;
+  // Check-in opens at local midnight of the session date
+  const openTime = new Date(`${dateStr} 00:00`).getTime() / 1000;
   const startTime = new Date(`${dateStr} ${startTimeStr}`).getTime() / 1000;
   const lateTime = new Date(`${dateStr} ${lateTimeStr}`).getTime() / 1000;
 
@@ -117,25 +752,39 @@ async function initializeSession(
     program.programId
   );
 
-  const [sessionPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("session"), new anchor.BN(sessionDate).toArrayLike(Buffer, "le", 8)],
+  // The next session id comes from the Config session counter
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
     program.programId
   );
+  const config = await program.account.config.fetch(configPDA);
+  const sessionPDA = sessionPDAFor(program, config.sessionCount.toString());
 
   console.log("Creating session...");
+  console.log("Session ID:", config.sessionCount.toString());
+  console.log("Title:", title, `(${kind})`);
   console.log("Date:", new Date(sessionDate * 1000).toISOString());
+  console.log("Check-in opens:", new Date(openTime * 1000).toISOString());
   console.log("Start time:", new Date(startTime * 1000).toISOString());
   console.log("Late time:", new Date(lateTime * 1000).toISOString());
   console.log("Session PDA:", sessionPDA.toString());
+  const termPDA = termIdStr ? termPDAFor(program, termIdStr) : null;
+  if (termPDA) {
+    console.log("Term PDA:", termPDA.toString());
+  }
 
   const tx = await program.methods
     .initializeSession(
+      title,
+      { [kind]: {} } as any,
       new anchor.BN(sessionDate),
+      new anchor.BN(openTime),
       new anchor.BN(startTime),
       new anchor.BN(lateTime)
     )
     .accounts({
       authority: adminWallet.publicKey,
+      term: termPDA,
     })
     .rpc();
 
@@ -143,13 +792,165 @@ async function initializeSession(
   console.log("Transaction:", tx);
 }
 
-async function closeSession(program: Program<BayAttendanceCheck>, dateStr: string) {
-  if (!dateStr) {
-    console.error("Please provide session date");
+// Mirrors Session::state on-chain: cancelled/finalized, then manual override, then the check-in window
+function sessionStateOf(session: any, now: number = Math.floor(Date.now() / 1000)) {
+  if (session.isCancelled) return "Cancelled";
+  if (session.isFinalized) return "Finalized";
+  const override = Object.keys(session.stateOverride)[0];
+  if (override === "closed") return "Closed";
+  if (override === "auto" && now < session.openTime.toNumber()) return "Scheduled";
+  return now <= session.lateTime.toNumber() ? "Open" : "Closed";
+}
+
+function seriesPDAFor(program: Program<BayAttendanceCheck>, seriesId: string | number) {
+  const [seriesPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("series"), new anchor.BN(seriesId).toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return seriesPDA;
+}
+
+// "HH:MM" -> seconds from local midnight
+function timeOfDay(timeStr: string) {
+  const [hours, minutes] = timeStr.split(":").map(Number);
+  return hours * 3600 + minutes * 60;
+}
+
+async function createSessionSeries(
+  program: Program<BayAttendanceCheck>,
+  startDateStr: string,
+  weekdayStr: string,
+  startTimeStr: string,
+  openMinutesStr: string,
+  lateMinutesStr: string,
+  countStr: string,
+  title: string = "BAY Session",
+  kind: string = "regular",
+  termIdStr?: string
+) {
+  if (!startDateStr || !weekdayStr || !startTimeStr || !openMinutesStr || !lateMinutesStr || !countStr) {
+    console.error("Please provide start date, weekday, start time, check-in window, late window and occurrence count");
+    console.error("Example: create-series 2025-09-01 2 19:00 60 30 16 \"Weekly Session\" regular 0");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const config = await program.account.config.fetch(configPDA);
+  const seriesPDA = seriesPDAFor(program, config.seriesCount.toString());
+
+  const schedule = {
+    startDate: new anchor.BN(new Date(`${startDateStr} 00:00`).getTime() / 1000),
+    weekday: Number(weekdayStr),
+    startTimeOfDay: timeOfDay(startTimeStr),
+    openWindow: Number(openMinutesStr) * 60,
+    lateWindow: Number(lateMinutesStr) * 60,
+    occurrenceCount: Number(countStr),
+  };
+
+  console.log("Creating session series...");
+  console.log("Series ID:", config.seriesCount.toString());
+  console.log("Title:", title, `(${kind})`);
+  console.log("Weekday:", schedule.weekday, "Start:", startTimeStr);
+  console.log("Check-in opens", openMinutesStr, "minutes before start, late window:", lateMinutesStr, "minutes");
+  console.log("Occurrences:", schedule.occurrenceCount);
+
+  const tx = await program.methods
+    .createSessionSeries(title, { [kind]: {} } as any, schedule)
+    .accounts({
+      authority: adminWallet.publicKey,
+      term: termIdStr ? termPDAFor(program, termIdStr) : null,
+    })
+    .rpc();
+
+  console.log("Session series created successfully!");
+  console.log("Transaction:", tx);
+
+  await createSeriesSessions(program, config.seriesCount.toString());
+}
+
+async function createSeriesSessions(program: Program<BayAttendanceCheck>, seriesIdStr: string) {
+  if (!seriesIdStr) {
+    console.error("Please provide series id");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const seriesPDA = seriesPDAFor(program, seriesIdStr);
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+
+  // Each call creates up to BATCH_SIZE sessions and advances the series cursor
+  const BATCH_SIZE = 4;
+  let series = await program.account.sessionSeries.fetch(seriesPDA);
+  while (series.nextOccurrence < series.schedule.occurrenceCount) {
+    const config = await program.account.config.fetch(configPDA);
+    const count = Math.min(BATCH_SIZE, series.schedule.occurrenceCount - series.nextOccurrence);
+    const sessionPDAs = Array.from({ length: count }, (_, offset) =>
+      sessionPDAFor(program, config.sessionCount.addn(offset).toString())
+    );
+
+    const tx = await program.methods
+      .createSeriesSessions()
+      .accounts({
+        authority: adminWallet.publicKey,
+        series: seriesPDA,
+        term: series.term,
+      })
+      .remainingAccounts(sessionPDAs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+    console.log("Transaction:", tx);
+
+    series = await program.account.sessionSeries.fetch(seriesPDA);
+    console.log(`Created ${series.nextOccurrence}/${series.schedule.occurrenceCount} occurrences`);
+  }
+
+  console.log("Session IDs:", series.sessionIds.map((id) => id.toString()).join(", "));
+}
+
+async function rescheduleSeries(
+  program: Program<BayAttendanceCheck>,
+  seriesIdStr: string,
+  startTimeStr: string,
+  lateMinutesStr: string
+) {
+  if (!seriesIdStr || !startTimeStr || !lateMinutesStr) {
+    console.error("Please provide series id, new start time and late window");
+    console.error("Example: reschedule-series 0 19:30 30");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const seriesPDA = seriesPDAFor(program, seriesIdStr);
+  const series = await program.account.sessionSeries.fetch(seriesPDA);
+  const sessionPDAs = series.sessionIds.map((id) => sessionPDAFor(program, id.toString()));
+
+  console.log("Rescheduling series", seriesIdStr, "to", startTimeStr, `(${lateMinutesStr} minute late window)`);
+
+  const tx = await program.methods
+    .rescheduleSeries(timeOfDay(startTimeStr), Number(lateMinutesStr) * 60)
+    .accounts({
+      authority: adminWallet.publicKey,
+      series: seriesPDA,
+    })
+    .remainingAccounts(sessionPDAs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+    .rpc();
+
+  console.log("Series rescheduled successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function setSessionOverride(program: Program<BayAttendanceCheck>, sessionIdStr: string, stateOverride: string) {
+  if (!sessionIdStr || !["auto", "open", "closed"].includes(stateOverride)) {
+    console.error("Please provide session id and override (auto, open or closed)");
     return;
   }
 
-  const sessionDate = new Date(dateStr).getTime() / 1000;
   const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
 
   // Derive PDAs
@@ -158,56 +959,442 @@ async function closeSession(program: Program<BayAttendanceCheck>, dateStr: strin
     program.programId
   );
 
-  const [sessionPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("session"), new anchor.BN(sessionDate).toArrayLike(Buffer, "le", 8)],
-    program.programId
-  );
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
 
-  console.log("Closing session...");
+  console.log(`Setting session override to ${stateOverride}...`);
   console.log("Admin PDA:", adminMemberPDA.toString());
   console.log("Session PDA:", sessionPDA.toString());
 
   const tx = await program.methods
-    .updateSessionStatus(false)
+    .setSessionOverride({ [stateOverride]: {} } as any)
     .accounts({
       authority: adminWallet.publicKey,
       session: sessionPDA,
     })
     .rpc();
 
-  console.log("Session closed successfully!");
+  const session = await program.account.session.fetch(sessionPDA);
+  console.log("Session state:", sessionStateOf(session));
   console.log("Transaction:", tx);
 }
 
-async function getSessionStats(program: Program<BayAttendanceCheck>, dateStr: string) {
-  if (!dateStr) {
-    console.error("Please provide session date");
+async function cancelSession(program: Program<BayAttendanceCheck>, sessionIdStr: string) {
+  if (!sessionIdStr) {
+    console.error("Please provide session id");
     return;
   }
 
-  const sessionDate = new Date(dateStr).getTime() / 1000;
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
+  const session = await program.account.session.fetch(sessionPDA);
 
-  const [sessionPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("session"), new anchor.BN(sessionDate).toArrayLike(Buffer, "le", 8)],
+  console.log("Cancelling session", sessionIdStr, `(${session.title})...`);
+
+  // Attendance points are reverted and record rent is refunded to whoever paid for it
+  const groups = await attendanceRecordGroups(program, sessionPDA);
+  console.log("Attendance records to close:", groups.length);
+
+  // Leaderboards are re-ranked with the reverted stats when they exist
+  const { leaderboard, termLeaderboard } = await sessionLeaderboards(program, session.term);
+
+  // The first call cancels the session; later calls close the remaining records in batches
+  const BATCH_SIZE = 5;
+  let index = 0;
+  do {
+    const tx = await program.methods
+      .cancelSession()
+      .accounts({
+        authority: adminWallet.publicKey,
+        session: sessionPDA,
+        term: session.term,
+        leaderboard,
+        termLeaderboard,
+      })
+      .remainingAccounts(groups.slice(index, index + BATCH_SIZE).flat())
+      .rpc();
+    index += BATCH_SIZE;
+
+    console.log("Transaction:", tx);
+  } while (index < groups.length);
+
+  console.log("Session cancelled successfully!");
+
+  // With every record closed, the session account rent returns to the session admin
+  const tx = await program.methods
+    .closeSession()
+    .accounts({
+      authority: adminWallet.publicKey,
+      session: sessionPDA,
+      sessionAdmin: session.admin,
+    })
+    .rpc();
+
+  console.log("Session account closed, rent refunded to:", session.admin.toString());
+  console.log("Transaction:", tx);
+}
+
+async function finalizeSession(program: Program<BayAttendanceCheck>, sessionIdStr: string) {
+  if (!sessionIdStr) {
+    console.error("Please provide session id");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
+
+  // Find active members without an attendance record for this session
+  const members = await program.account.member.all();
+  const absentees: { memberPDA: PublicKey; recordPDA: PublicKey }[] = [];
+  for (const { publicKey, account } of members) {
+    if (!account.isActive) continue;
+    const [recordPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("attendance"), sessionPDA.toBuffer(), account.wallet.toBuffer()],
+      program.programId
+    );
+    const recordInfo = await program.provider.connection.getAccountInfo(recordPDA);
+    if (!recordInfo) {
+      absentees.push({ memberPDA: publicKey, recordPDA });
+    }
+  }
+
+  console.log("Finalizing session...");
+  console.log("Session PDA:", sessionPDA.toString());
+  console.log("Absent members:", absentees.length);
+
+  // Process absentees in batches; lock the session with the last batch
+  const BATCH_SIZE = 8;
+  let index = 0;
+  do {
+    const batch = absentees.slice(index, index + BATCH_SIZE);
+    index += BATCH_SIZE;
+    const isLast = index >= absentees.length;
+
+    const tx = await program.methods
+      .finalizeSession(isLast)
+      .accounts({
+        authority: adminWallet.publicKey,
+        session: sessionPDA,
+      })
+      .remainingAccounts(
+        batch.flatMap(({ memberPDA, recordPDA }) => [
+          { pubkey: memberPDA, isSigner: false, isWritable: true },
+          { pubkey: recordPDA, isSigner: false, isWritable: true },
+        ])
+      )
+      .rpc();
+
+    console.log("Batch processed:", batch.length, "Transaction:", tx);
+  } while (index < absentees.length);
+
+  console.log("Session finalized successfully!");
+}
+
+async function setPointSchedule(
+  program: Program<BayAttendanceCheck>,
+  presentStr: string,
+  lateStr: string,
+  earlyBirdBonusStr: string = "0",
+  earlyBirdWindowStr: string = "0"
+) {
+  if (!presentStr || !lateStr) {
+    console.error("Please provide present and late points");
+    console.error("Example: set-point-schedule 10 5 2 600");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const pointSchedule = {
+    presentPoints: Number(presentStr),
+    latePoints: Number(lateStr),
+    earlyBirdBonus: Number(earlyBirdBonusStr),
+    earlyBirdWindow: new anchor.BN(earlyBirdWindowStr),
+  };
+
+  console.log("Updating default point schedule...");
+  console.log("Present:", pointSchedule.presentPoints, "Late:", pointSchedule.latePoints);
+  console.log("Early bird bonus:", pointSchedule.earlyBirdBonus, "window:", earlyBirdWindowStr, "seconds");
+
+  const tx = await program.methods
+    .setPointSchedule(pointSchedule)
+    .accounts({
+      authority: adminWallet.publicKey,
+    })
+    .rpc();
+
+  console.log("Point schedule updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function setSessionTimeRules(
+  program: Program<BayAttendanceCheck>,
+  utcOffsetHoursStr: string,
+  maxLateMinutesStr: string
+) {
+  if (utcOffsetHoursStr === undefined || maxLateMinutesStr === undefined) {
+    console.error("Please provide UTC offset in hours and maximum late window in minutes (0 = no limit)");
+    console.error("Example: set-session-time-rules 9 180");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const sessionTimeRules = {
+    utcOffset: Math.round(Number(utcOffsetHoursStr) * 3600),
+    maxLateWindow: Number(maxLateMinutesStr) * 60,
+  };
+
+  console.log("Updating session time rules...");
+  console.log("UTC offset:", utcOffsetHoursStr, "hours");
+  console.log("Max late window:", maxLateMinutesStr, "minutes");
+
+  const tx = await program.methods
+    .setSessionTimeRules(sessionTimeRules)
+    .accounts({
+      authority: adminWallet.publicKey,
+    })
+    .rpc();
+
+  console.log("Session time rules updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function setStreakRules(
+  program: Program<BayAttendanceCheck>,
+  keepOnLateStr: string,
+  milestoneArgs: string[]
+) {
+  const maxMilestones = 4;
+  if (!keepOnLateStr || milestoneArgs.length > maxMilestones) {
+    console.error(`Please provide keep-on-late (true|false) and up to ${maxMilestones} streak:bonus pairs`);
+    console.error("Example: set-streak-rules false 5:10 10:30");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const milestones = milestoneArgs.map((arg) => {
+    const [streak, bonusPoints] = arg.split(":").map(Number);
+    return { streak, bonusPoints };
+  });
+  // Unused milestone slots are zeroed
+  while (milestones.length < maxMilestones) {
+    milestones.push({ streak: 0, bonusPoints: 0 });
+  }
+  const streakRules = {
+    keepStreakOnLate: keepOnLateStr === "true",
+    milestones,
+  };
+
+  console.log("Updating streak rules...");
+  console.log("Keep streak on late:", streakRules.keepStreakOnLate);
+  milestones
+    .filter((milestone) => milestone.streak > 0)
+    .forEach((milestone) => console.log(`  ${milestone.streak} in a row: +${milestone.bonusPoints} points`));
+
+  const tx = await program.methods
+    .setStreakRules(streakRules)
+    .accounts({
+      authority: adminWallet.publicKey,
+    })
+    .rpc();
+
+  console.log("Streak rules updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function setSessionRewards(
+  program: Program<BayAttendanceCheck>,
+  sessionIdStr: string,
+  multiplierStr: string
+) {
+  if (!sessionIdStr || !multiplierStr) {
+    console.error("Please provide session id and multiplier percent");
+    console.error("Example: set-session-rewards 3 200");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
+
+  console.log("Updating session rewards...");
+  console.log("Session PDA:", sessionPDA.toString());
+  console.log("Multiplier:", Number(multiplierStr) / 100, "x");
+
+  const tx = await program.methods
+    .setSessionRewards(null, Number(multiplierStr))
+    .accounts({
+      authority: adminWallet.publicKey,
+      session: sessionPDA,
+    })
+    .rpc();
+
+  console.log("Session rewards updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function recordAttendance(
+  program: Program<BayAttendanceCheck>,
+  sessionIdStr: string,
+  walletStr: string,
+  statusStr: string,
+  reasonStr: string = "other"
+) {
+  const statuses = { present: { present: {} }, late: { late: {} }, absent: { absent: {} } };
+  const reasons = {
+    device: { deviceFailure: {} },
+    wallet: { walletFailure: {} },
+    error: { recordingError: {} },
+    other: { other: {} },
+  };
+
+  if (!sessionIdStr || !walletStr || !statuses[statusStr] || !reasons[reasonStr]) {
+    console.error("Please provide session id, member wallet, status and reason");
+    console.error("Example: record-attendance 3 <wallet> present device");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const memberWallet = new PublicKey(walletStr);
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
+  const [memberPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("member"), memberWallet.toBuffer()],
+    program.programId
+  );
+
+  // Corrections update the team the member was in when the record was created
+  const [recordPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("attendance"), sessionPDA.toBuffer(), memberWallet.toBuffer()],
     program.programId
   );
+  const record = await program.account.attendanceRecord.fetchNullable(recordPDA);
+  const teamId = record ? record.teamId : (await program.account.member.fetch(memberPDA)).teamId;
+
+  console.log("Recording attendance...");
+  console.log("Session PDA:", sessionPDA.toString());
+  console.log("Member:", memberWallet.toString());
+  console.log("Status:", statusStr, "Reason:", reasonStr);
+
+  const tx = await program.methods
+    .recordAttendance(statuses[statusStr], reasons[reasonStr])
+    .accounts({
+      authority: adminWallet.publicKey,
+      member: memberPDA,
+      memberWallet,
+      session: sessionPDA,
+      pointMint: BAY_TOKEN_MINT,
+      team: teamId !== null ? teamPDAFor(program, teamId) : null,
+    })
+    .rpc();
+
+  console.log("Attendance recorded successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function setPresenceMode(
+  program: Program<BayAttendanceCheck>,
+  sessionIdStr: string,
+  windowStr: string
+) {
+  if (!sessionIdStr || windowStr === undefined) {
+    console.error("Please provide session id and QR rotation window in seconds");
+    console.error("Example: set-presence-mode 3 30");
+    return;
+  }
+
+  const adminWallet = (program.provider as anchor.AnchorProvider).wallet;
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
+
+  console.log("Updating presence mode...");
+  console.log("Session PDA:", sessionPDA.toString());
+  console.log("Window:", Number(windowStr) === 0 ? "off" : `${windowStr}s`);
+
+  const tx = await program.methods
+    .setSessionPresenceMode(Number(windowStr))
+    .accounts({
+      authority: adminWallet.publicKey,
+      session: sessionPDA,
+    })
+    .rpc();
+
+  console.log("Presence mode updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function getSessionStats(program: Program<BayAttendanceCheck>, sessionIdStr: string) {
+  if (!sessionIdStr) {
+    console.error("Please provide session id");
+    return;
+  }
+
+
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
 
   try {
     const session = await program.account.session.fetch(sessionPDA);
     
     console.log("\n=== Session Statistics ===");
+    console.log("Session ID:", session.id.toString());
+    console.log("Title:", session.title, `(${Object.keys(session.kind)[0]})`);
     console.log("Date:", new Date(session.sessionDate.toNumber() * 1000).toDateString());
     console.log("Start Time:", new Date(session.startTime.toNumber() * 1000).toLocaleTimeString());
     console.log("Late Time:", new Date(session.lateTime.toNumber() * 1000).toLocaleTimeString());
     console.log("Total Attendees:", session.totalAttendees);
     console.log("Total Late:", session.totalLate);
-    console.log("Status:", session.isActive ? "Active" : "Closed");
+    console.log("Total Absent:", session.totalAbsent);
+    console.log("Status:", sessionStateOf(session));
     console.log("Admin:", session.admin.toString());
   } catch (error) {
     console.error("Failed to fetch session data. Session might not exist.");
   }
 }
 
+// Finds accounts that are shorter than the current layout or at an older version and migrates them
+async function migrateAccounts(program: Program<BayAttendanceCheck>, kind?: string) {
+  // version: the account's current VERSION constant in state.rs
+  const targets = {
+    config: { account: "config", method: "migrateConfig", version: 3 },
+    session: { account: "session", method: "migrateSession", version: 2 },
+    member: { account: "member", method: "migrateMember", version: 4 },
+    record: { account: "attendanceRecord", method: "migrateAttendanceRecord", version: 3 },
+  };
+  const kinds = kind ? [kind] : Object.keys(targets);
+
+  for (const name of kinds) {
+    const target = targets[name];
+    if (!target) {
+      console.error("Unknown account kind:", name);
+      return;
+    }
+
+    const accountClient = program.account[target.account];
+    const accounts = await program.provider.connection.getProgramAccounts(program.programId, {
+      filters: [{ memcmp: accountClient.coder.accounts.memcmp(accountClient.idlAccount.name) }],
+    });
+
+    let migrated = 0;
+    for (const { pubkey, account } of accounts) {
+      let outdated = account.data.length < accountClient.size;
+      if (!outdated) {
+        try {
+          outdated = accountClient.coder.accounts.decode(accountClient.idlAccount.name, account.data).version < target.version;
+        } catch {
+          outdated = true;
+        }
+      }
+      if (!outdated) {
+        continue;
+      }
+
+      const tx = await program.methods[target.method]()
+        .accounts({ account: pubkey })
+        .rpc();
+      console.log(`Migrated ${name} ${pubkey.toString()}: ${tx}`);
+      migrated++;
+    }
+
+    console.log(`${name}: ${migrated}/${accounts.length} accounts migrated`);
+  }
+}
+
 async function getMemberStats(program: Program<BayAttendanceCheck>, walletStr: string) {
   if (!walletStr) {
     console.error("Please provide member wallet address");
@@ -231,26 +1418,78 @@ async function getMemberStats(program: Program<BayAttendanceCheck>, walletStr: s
     console.log("Total Late:", member.totalLate);
     console.log("Total Absence:", member.totalAbsence);
     console.log("Total Points:", member.totalPoints.toString());
+    console.log("Current Streak:", member.currentStreak, "(longest:", member.longestStreak + ")");
     console.log("Status:", member.isActive ? "Active" : "Inactive");
   } catch (error) {
     console.error("Failed to fetch member data. Member might not be initialized.");
   }
 }
 
+// Global and term leaderboards of a session, or null for those that do not exist
+async function sessionLeaderboards(program: Program<BayAttendanceCheck>, term: PublicKey | null) {
+  const connection = program.provider.connection;
+  const existing = async (key: PublicKey) => {
+    const [leaderboardPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), key.toBuffer()],
+      program.programId
+    );
+    return (await connection.getAccountInfo(leaderboardPDA)) ? leaderboardPDA : null;
+  };
+  return {
+    leaderboard: await existing(PublicKey.default),
+    termLeaderboard: term ? await existing(term) : null,
+  };
+}
+
+// Remaining account groups for closing every attendance record of a session:
+// [record, member, payer, term stats (term sessions only), team (records with a team only)]
+async function attendanceRecordGroups(program: Program<BayAttendanceCheck>, sessionPDA: PublicKey) {
+  const session = await program.account.session.fetch(sessionPDA);
+  const records = await program.account.attendanceRecord.all([
+    { memcmp: { offset: 8 + 32, bytes: sessionPDA.toBase58() } },
+  ]);
+  const groups = [];
+  for (const { publicKey, account } of records) {
+    const [memberPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("member"), account.member.toBuffer()],
+      program.programId
+    );
+    const group = [
+      { pubkey: publicKey, isSigner: false, isWritable: true },
+      { pubkey: memberPDA, isSigner: false, isWritable: true },
+      { pubkey: account.payer, isSigner: false, isWritable: true },
+    ];
+    if (session.term) {
+      const [termStatsPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("term_stats"), session.term.toBuffer(), account.member.toBuffer()],
+        program.programId
+      );
+      group.push({ pubkey: termStatsPDA, isSigner: false, isWritable: true });
+    }
+    if (account.teamId !== null) {
+      group.push({ pubkey: teamPDAFor(program, account.teamId), isSigner: false, isWritable: true });
+    }
+    groups.push(group);
+  }
+  return groups;
+}
+
 async function reactivateSession(
   program: Program<BayAttendanceCheck>,
+  sessionIdStr: string,
   dateStr: string,
   startTimeStr: string,
-  lateTimeStr: string
+  lateTimeStr: string,
+  mode: string = "extend"
 ) {
-  if (!dateStr || !startTimeStr || !lateTimeStr) {
-    console.error("Please provide date, new start time, and new late time");
-    console.error("Example: reactivate-session 2024-07-28 19:30 20:00");
+  if (!sessionIdStr || !dateStr || !startTimeStr || !lateTimeStr) {
+    console.error("Please provide session id, date, new start time, and new late time");
+    console.error("Example: reactivate-session 3 2024-07-28 19:30 20:00");
     return;
   }
 
-  // Parse dates
-  const sessionDate = new Date(dateStr).getTime() / 1000;
+  // Parse dates (check-in reopens at the start of the date)
+  const newOpenTime = new Date(`${dateStr} 00:00`).getTime() / 1000;
   const newStartTime = new Date(`${dateStr} ${startTimeStr}`).getTime() / 1000;
   const newLateTime = new Date(`${dateStr} ${lateTimeStr}`).getTime() / 1000;
 
@@ -263,30 +1502,50 @@ async function reactivateSession(
     program.programId
   );
 
-  const [sessionPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("session"), new anchor.BN(sessionDate).toArrayLike(Buffer, "le", 8)],
-    program.programId
-  );
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
 
   console.log("Reactivating session...");
-  console.log("Date:", new Date(sessionDate * 1000).toDateString());
+  console.log("Session ID:", sessionIdStr);
   console.log("New start time:", new Date(newStartTime * 1000).toLocaleTimeString());
   console.log("New late time:", new Date(newLateTime * 1000).toLocaleTimeString());
   console.log("Session PDA:", sessionPDA.toString());
 
-  const tx = await program.methods
-    .reactivateSession(
-      new anchor.BN(newStartTime),
-      new anchor.BN(newLateTime)
-    )
-    .accounts({
-      authority: adminWallet.publicKey,
-      session: sessionPDA,
-    })
-    .rpc();
+  // Reset mode closes every attendance record of the session
+  const resetGroups = mode === "reset" ? await attendanceRecordGroups(program, sessionPDA) : [];
+  if (mode === "reset") {
+    console.log("Attendance records to reset:", resetGroups.length);
+  }
+  const session = await program.account.session.fetch(sessionPDA);
+  const { leaderboard, termLeaderboard } =
+    mode === "reset"
+      ? await sessionLeaderboards(program, session.term)
+      : { leaderboard: null, termLeaderboard: null };
+
+  // Process records in batches; each call also applies the new times
+  const BATCH_SIZE = 5;
+  let index = 0;
+  do {
+    const tx = await program.methods
+      .reactivateSession(
+        { [mode]: {} } as any,
+        new anchor.BN(newOpenTime),
+        new anchor.BN(newStartTime),
+        new anchor.BN(newLateTime)
+      )
+      .accounts({
+        authority: adminWallet.publicKey,
+        session: sessionPDA,
+        leaderboard,
+        termLeaderboard,
+      })
+      .remainingAccounts(resetGroups.slice(index, index + BATCH_SIZE).flat())
+      .rpc();
+    index += BATCH_SIZE;
+
+    console.log("Transaction:", tx);
+  } while (index < resetGroups.length);
 
   console.log("Session reactivated successfully!");
-  console.log("Transaction:", tx);
 }
 
 main().catch(console.error);
\ No newline at end of file
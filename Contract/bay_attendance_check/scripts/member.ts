@@ -4,6 +4,9 @@ import { BayAttendanceCheck } from "../target/types/bay_attendance_check";
 import { PublicKey, Keypair } from "@solana/web3.js";
 import * as fs from "fs";
 
+// BAY point token mint (mint authority must be the program's "mint_authority" PDA)
+const BAY_TOKEN_MINT = new PublicKey("bay3egCym863ziQsvesuGptuGDkekVN6jwwdPd3Ywu2");
+
 // Member script for BAY attendance system
 async function main() {
   // Setup provider
@@ -19,18 +22,16 @@ async function main() {
   if (!command) {
     console.log("Usage: ts-node member.ts <command> [options]");
     console.log("\nCommands:");
-    console.log("  register <wallet-path>       - Register as a new member");
-    console.log("  check-in <wallet-path> <session-date> - Check in to a session");
+    console.log("  check-in <wallet-path> <session-id> - Check in to a session");
     console.log("  my-stats <wallet-path>       - View your statistics");
-    console.log("  attendance <wallet-path> <session-date> - Check attendance status");
+    console.log("  attendance <wallet-path> <session-id> - Check attendance status");
+    console.log("  set-profile <wallet-path> <display-name> [cohort] [profile-uri] - Update your profile");
+    console.log("\nMember registration is done by an admin: ts-node admin.ts init-member <wallet>");
     return;
   }
 
   try {
     switch (command) {
-      case "register":
-        await registerMember(program, args[1]);
-        break;
       case "check-in":
         await checkIn(program, args[1], args[2]);
         break;
@@ -40,6 +41,9 @@ async function main() {
       case "attendance":
         await checkAttendance(program, args[1], args[2]);
         break;
+      case "set-profile":
+        await setProfile(program, args[1], args[2], args[3], args[4]);
+        break;
       default:
         console.error("Unknown command:", command);
     }
@@ -48,46 +52,37 @@ async function main() {
   }
 }
 
-async function registerMember(program: Program<BayAttendanceCheck>, walletPath: string) {
-  if (!walletPath) {
-    console.error("Please provide wallet path");
-    return;
-  }
-
-  // Load wallet from file
-  const walletData = JSON.parse(fs.readFileSync(walletPath, 'utf-8'));
-  const memberKeypair = Keypair.fromSecretKey(new Uint8Array(walletData));
-
-  // Derive member PDA
-  const [memberPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("member"), memberKeypair.publicKey.toBuffer()],
+// Team PDAs are derived from the team counter in Config
+function teamPDAFor(program: Program<BayAttendanceCheck>, teamId: number) {
+  const [teamPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("team"), new anchor.BN(teamId).toArrayLike(Buffer, "le", 2)],
     program.programId
   );
+  return teamPDA;
+}
 
-  console.log("Registering as member...");
-  console.log("Your wallet:", memberKeypair.publicKey.toString());
-  console.log("Member PDA:", memberPDA.toString());
-
-  // For regular members, we use a dummy admin account (in production, this would be the actual admin)
-  const dummyAdmin = Keypair.generate();
-
-  const tx = await program.methods
-    .initializeMember({ member: {} })
-    .accounts({
-      authority: memberKeypair.publicKey,
-      admin: dummyAdmin.publicKey,
-      memberWallet: memberKeypair.publicKey,
-    })
-    .signers([memberKeypair])
-    .rpc();
+// Session PDAs are derived from the session counter in Config
+function sessionPDAFor(program: Program<BayAttendanceCheck>, sessionId: string | number) {
+  const [sessionPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("session"), new anchor.BN(sessionId).toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return sessionPDA;
+}
 
-  console.log("Registration successful!");
-  console.log("Transaction:", tx);
+// Mirrors Session::state on-chain: cancelled/finalized, then manual override, then the check-in window
+function sessionStateOf(session: any, now: number = Math.floor(Date.now() / 1000)) {
+  if (session.isCancelled) return "Cancelled";
+  if (session.isFinalized) return "Finalized";
+  const override = Object.keys(session.stateOverride)[0];
+  if (override === "closed") return "Closed";
+  if (override === "auto" && now < session.openTime.toNumber()) return "Scheduled";
+  return now <= session.lateTime.toNumber() ? "Open" : "Closed";
 }
 
-async function checkIn(program: Program<BayAttendanceCheck>, walletPath: string, dateStr: string) {
-  if (!walletPath || !dateStr) {
-    console.error("Please provide wallet path and session date");
+async function checkIn(program: Program<BayAttendanceCheck>, walletPath: string, sessionIdStr: string) {
+  if (!walletPath || !sessionIdStr) {
+    console.error("Please provide wallet path and session id");
     return;
   }
 
@@ -95,18 +90,13 @@ async function checkIn(program: Program<BayAttendanceCheck>, walletPath: string,
   const walletData = JSON.parse(fs.readFileSync(walletPath, 'utf-8'));
   const memberKeypair = Keypair.fromSecretKey(new Uint8Array(walletData));
 
-  const sessionDate = new Date(dateStr).getTime() / 1000;
-
   // Derive PDAs
   const [memberPDA] = PublicKey.findProgramAddressSync(
     [Buffer.from("member"), memberKeypair.publicKey.toBuffer()],
     program.programId
   );
 
-  const [sessionPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("session"), new anchor.BN(sessionDate).toArrayLike(Buffer, "le", 8)],
-    program.programId
-  );
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
 
   const [attendanceRecordPDA] = PublicKey.findProgramAddressSync(
     [
@@ -118,36 +108,87 @@ async function checkIn(program: Program<BayAttendanceCheck>, walletPath: string,
   );
 
   console.log("Checking in...");
-  console.log("Session date:", new Date(sessionDate * 1000).toDateString());
+  console.log("Session ID:", sessionIdStr);
   console.log("Current time:", new Date().toLocaleString());
   
   // Fetch session data to show time information
+  let session;
   try {
-    const session = await program.account.session.fetch(sessionPDA);
+    session = await program.account.session.fetch(sessionPDA);
     const currentTime = Math.floor(Date.now() / 1000);
     
     console.log("\n=== Session Time Debug Info ===");
     console.log("Current timestamp:", currentTime);
+    console.log("Session open timestamp:", session.openTime.toNumber());
     console.log("Session start timestamp:", session.startTime.toNumber());
     console.log("Session late timestamp:", session.lateTime.toNumber());
     console.log("Session start time:", new Date(session.startTime.toNumber() * 1000).toLocaleString());
     console.log("Session late time:", new Date(session.lateTime.toNumber() * 1000).toLocaleString());
+    console.log("Time until open:", (session.openTime.toNumber() - currentTime), "seconds");
     console.log("Time until start:", (session.startTime.toNumber() - currentTime), "seconds");
     console.log("Time until late:", (session.lateTime.toNumber() - currentTime), "seconds");
-    console.log("Session is active:", session.isActive);
+    console.log("Session state:", sessionStateOf(session, currentTime));
     console.log("================================\n");
   } catch (error) {
     console.error("Failed to fetch session data. The session might not exist.");
     return;
   }
 
+  // Sessions that belong to a term also update the member's term statistics
+  let termStatsPDA: PublicKey | null = null;
+  const preInstructions = [];
+  if (session.term) {
+    [termStatsPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("term_stats"), session.term.toBuffer(), memberKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+    if (!(await program.provider.connection.getAccountInfo(termStatsPDA))) {
+      preInstructions.push(
+        await program.methods
+          .initializeTermStats()
+          .accounts({
+            payer: memberKeypair.publicKey,
+            term: session.term,
+            member: memberPDA,
+          })
+          .instruction()
+      );
+    }
+  }
+
+  // Leaderboards are updated when they exist
+  const connection = program.provider.connection;
+  const [leaderboardPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("leaderboard"), PublicKey.default.toBuffer()],
+    program.programId
+  );
+  const leaderboard = (await connection.getAccountInfo(leaderboardPDA)) ? leaderboardPDA : null;
+  let termLeaderboard: PublicKey | null = null;
+  if (session.term) {
+    const [termLeaderboardPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), session.term.toBuffer()],
+      program.programId
+    );
+    termLeaderboard = (await connection.getAccountInfo(termLeaderboardPDA)) ? termLeaderboardPDA : null;
+  }
+
+  // Members in a team also update the team's attendance totals
+  const member = await program.account.member.fetch(memberPDA);
+  const teamPDA = member.teamId !== null ? teamPDAFor(program, member.teamId) : null;
+
   try {
     const tx = await program.methods
       .checkIn()
       .accounts({
         memberWallet: memberKeypair.publicKey,
         session: sessionPDA,
+        pointMint: BAY_TOKEN_MINT,
+        termStats: termStatsPDA,
+        team: teamPDA,
+        leaderboard,
+        termLeaderboard,
       })
+      .preInstructions(preInstructions)
       .signers([memberKeypair])
       .rpc();
 
@@ -158,12 +199,16 @@ async function checkIn(program: Program<BayAttendanceCheck>, walletPath: string,
     const attendanceRecord = await program.account.attendanceRecord.fetch(attendanceRecordPDA);
     const status = Object.keys(attendanceRecord.status)[0];
     console.log("\nAttendance Status:", status.charAt(0).toUpperCase() + status.slice(1));
-    console.log("Points Earned:", attendanceRecord.pointsEarned);
+    console.log("Points Earned:", attendanceRecord.pointsEarned.toString());
   } catch (error) {
     if (error.toString().includes("already in use")) {
       console.error("You have already checked in for this session!");
     } else if (error.toString().includes("SessionNotActive")) {
-      console.error("This session is not active!");
+      console.error("This session has been closed by an admin!");
+    } else if (error.toString().includes("CheckInNotOpen")) {
+      console.error("Check-in has not opened yet!");
+    } else if (error.toString().includes("SessionCancelled")) {
+      console.error("This session has been cancelled!");
     } else if (error.toString().includes("CheckInTimePassed")) {
       console.error("Check-in time has passed! You cannot check in anymore.");
     } else {
@@ -192,6 +237,15 @@ async function getMyStats(program: Program<BayAttendanceCheck>, walletPath: stri
     
     console.log("\n=== Your Statistics ===");
     console.log("Wallet:", member.wallet.toString());
+    if (member.displayName) {
+      console.log("Name:", member.displayName);
+    }
+    if (member.cohort !== null) {
+      console.log("Cohort:", member.cohort);
+    }
+    if (member.teamId !== null) {
+      console.log("Team:", member.teamId);
+    }
     console.log("Role:", Object.keys(member.role)[0]);
     console.log("Total Attendance:", member.totalAttendance);
     console.log("Total Late:", member.totalLate);
@@ -210,9 +264,16 @@ async function getMyStats(program: Program<BayAttendanceCheck>, walletPath: stri
   }
 }
 
-async function checkAttendance(program: Program<BayAttendanceCheck>, walletPath: string, dateStr: string) {
-  if (!walletPath || !dateStr) {
-    console.error("Please provide wallet path and session date");
+async function setProfile(
+  program: Program<BayAttendanceCheck>,
+  walletPath: string,
+  displayName: string,
+  cohortStr?: string,
+  profileUri: string = ""
+) {
+  if (!walletPath || !displayName) {
+    console.error("Please provide wallet path and display name");
+    console.error("Example: set-profile ~/bay01.json bay01 16");
     return;
   }
 
@@ -220,14 +281,59 @@ async function checkAttendance(program: Program<BayAttendanceCheck>, walletPath:
   const walletData = JSON.parse(fs.readFileSync(walletPath, 'utf-8'));
   const memberKeypair = Keypair.fromSecretKey(new Uint8Array(walletData));
 
-  const sessionDate = new Date(dateStr).getTime() / 1000;
-
-  // Derive PDAs
-  const [sessionPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("session"), new anchor.BN(sessionDate).toArrayLike(Buffer, "le", 8)],
+  const [memberPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("member"), memberKeypair.publicKey.toBuffer()],
     program.programId
   );
 
+  const memberInfo = await program.provider.connection.getAccountInfo(memberPDA);
+  if (!memberInfo) {
+    console.error("Failed to fetch your data. Please make sure you are registered.");
+    return;
+  }
+  const profile = {
+    displayName,
+    cohort: cohortStr ? Number(cohortStr) : null,
+    profileUri,
+  };
+
+  const updateProfile = program.methods
+    .updateMemberProfile(profile)
+    .accountsPartial({
+      authority: memberKeypair.publicKey,
+      admin: null,
+      member: memberPDA,
+    })
+    .signers([memberKeypair]);
+
+  // Accounts created before profile fields existed must be resized first (no-op otherwise)
+  const migrate = await program.methods
+    .migrateMember()
+    .accounts({
+      payer: memberKeypair.publicKey,
+      account: memberPDA,
+    })
+    .instruction();
+
+  const tx = await updateProfile.preInstructions([migrate]).rpc();
+
+  console.log("Profile updated successfully!");
+  console.log("Transaction:", tx);
+}
+
+async function checkAttendance(program: Program<BayAttendanceCheck>, walletPath: string, sessionIdStr: string) {
+  if (!walletPath || !sessionIdStr) {
+    console.error("Please provide wallet path and session id");
+    return;
+  }
+
+  // Load wallet from file
+  const walletData = JSON.parse(fs.readFileSync(walletPath, 'utf-8'));
+  const memberKeypair = Keypair.fromSecretKey(new Uint8Array(walletData));
+
+  // Derive PDAs
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
+
   const [attendanceRecordPDA] = PublicKey.findProgramAddressSync(
     [
       Buffer.from("attendance"),
@@ -241,10 +347,10 @@ async function checkAttendance(program: Program<BayAttendanceCheck>, walletPath:
     const attendanceRecord = await program.account.attendanceRecord.fetch(attendanceRecordPDA);
     
     console.log("\n=== Attendance Record ===");
-    console.log("Session Date:", new Date(sessionDate * 1000).toDateString());
+    console.log("Session ID:", sessionIdStr);
     console.log("Check-in Time:", new Date(attendanceRecord.checkInTime.toNumber() * 1000).toLocaleString());
     console.log("Status:", Object.keys(attendanceRecord.status)[0].charAt(0).toUpperCase() + Object.keys(attendanceRecord.status)[0].slice(1));
-    console.log("Points Earned:", attendanceRecord.pointsEarned);
+    console.log("Points Earned:", attendanceRecord.pointsEarned.toString());
   } catch (error) {
     console.log("No attendance record found for this session.");
     console.log("You have not checked in yet.");
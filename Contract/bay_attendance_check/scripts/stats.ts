@@ -18,9 +18,10 @@ async function main() {
   if (!command) {
     console.log("Usage: ts-node stats.ts <command> [options]");
     console.log("\nCommands:");
-    console.log("  session-summary <date>       - Get detailed session summary");
+    console.log("  session-summary <session-id> - Get detailed session summary");
     console.log("  member-ranking               - Show member attendance ranking");
-    console.log("  all-sessions                 - List all sessions (requires additional implementation)");
+    console.log("  team-ranking                 - Show team attendance ranking");
+    console.log("  all-sessions                 - List all sessions");
     return;
   }
 
@@ -32,6 +33,9 @@ async function main() {
       case "member-ranking":
         await getMemberRanking(program);
         break;
+      case "team-ranking":
+        await getTeamRanking(program);
+        break;
       case "all-sessions":
         await getAllSessions(program);
         break;
@@ -43,24 +47,40 @@ async function main() {
   }
 }
 
-async function getSessionSummary(program: Program<BayAttendanceCheck>, dateStr: string) {
-  if (!dateStr) {
-    console.error("Please provide session date");
-    return;
-  }
-
-  const sessionDate = new Date(dateStr).getTime() / 1000;
-
+// Session PDAs are derived from the session counter in Config
+function sessionPDAFor(program: Program<BayAttendanceCheck>, sessionId: string | number) {
   const [sessionPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("session"), new anchor.BN(sessionDate).toArrayLike(Buffer, "le", 8)],
+    [Buffer.from("session"), new anchor.BN(sessionId).toArrayLike(Buffer, "le", 8)],
     program.programId
   );
+  return sessionPDA;
+}
+
+// Mirrors Session::state on-chain: cancelled/finalized, then manual override, then the check-in window
+function sessionStateOf(session: any, now: number = Math.floor(Date.now() / 1000)) {
+  if (session.isCancelled) return "Cancelled";
+  if (session.isFinalized) return "Finalized";
+  const override = Object.keys(session.stateOverride)[0];
+  if (override === "closed") return "Closed";
+  if (override === "auto" && now < session.openTime.toNumber()) return "Scheduled";
+  return now <= session.lateTime.toNumber() ? "Open" : "Closed";
+}
+
+async function getSessionSummary(program: Program<BayAttendanceCheck>, sessionIdStr: string) {
+  if (!sessionIdStr) {
+    console.error("Please provide session id");
+    return;
+  }
+
+  const sessionPDA = sessionPDAFor(program, sessionIdStr);
 
   try {
     const session = await program.account.session.fetch(sessionPDA);
     
     console.log("\n=== Session Summary ===");
+    console.log("Title:", session.title, `(${Object.keys(session.kind)[0]})`);
     console.log("Date:", new Date(session.sessionDate.toNumber() * 1000).toDateString());
+    console.log("Check-in Opens:", new Date(session.openTime.toNumber() * 1000).toLocaleString());
     console.log("Start Time:", new Date(session.startTime.toNumber() * 1000).toLocaleTimeString());
     console.log("Late Time:", new Date(session.lateTime.toNumber() * 1000).toLocaleTimeString());
     console.log("\n--- Attendance Statistics ---");
@@ -74,7 +94,7 @@ async function getSessionSummary(program: Program<BayAttendanceCheck>, dateStr:
     }
     
     console.log("\n--- Session Info ---");
-    console.log("Status:", session.isActive ? "Active" : "Closed");
+    console.log("Status:", sessionStateOf(session));
     console.log("Admin:", session.admin.toString());
 
     // Calculate session duration
@@ -86,6 +106,39 @@ async function getSessionSummary(program: Program<BayAttendanceCheck>, dateStr:
   }
 }
 
+async function getTeamRanking(program: Program<BayAttendanceCheck>) {
+  console.log("\n=== Team Attendance Ranking ===");
+
+  try {
+    const teams = await program.account.team.all();
+
+    // Rank by average points per member so team size does not decide the ranking
+    const perMember = (team: typeof teams[number]) =>
+      team.account.memberCount > 0 ? team.account.totalPoints.toNumber() / team.account.memberCount : 0;
+    teams.sort((a, b) => perMember(b) - perMember(a));
+
+    console.log("Rank | Team | Members | Points | Points/Member | Attendance | Late");
+    console.log("-".repeat(80));
+
+    teams.forEach((team, index) => {
+      const acc = team.account;
+      console.log(
+        `${(index + 1).toString().padStart(4)} | ` +
+        `${acc.name} (#${acc.id}) | ` +
+        `${acc.memberCount.toString().padStart(7)} | ` +
+        `${acc.totalPoints.toString().padStart(6)} | ` +
+        `${perMember(team).toFixed(1).padStart(13)} | ` +
+        `${acc.totalAttendance.toString().padStart(10)} | ` +
+        `${acc.totalLate.toString().padStart(4)}`
+      );
+    });
+
+    console.log("\nTotal Teams:", teams.length);
+  } catch (error) {
+    console.error("Failed to fetch team data:", error);
+  }
+}
+
 async function getMemberRanking(program: Program<BayAttendanceCheck>) {
   console.log("\n=== Member Attendance Ranking ===");
   console.log("(This feature requires fetching all member accounts)");
@@ -139,16 +192,29 @@ async function getMemberRanking(program: Program<BayAttendanceCheck>) {
 }
 
 async function getAllSessions(program: Program<BayAttendanceCheck>) {
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const config = await program.account.config.fetch(configPDA);
+  const sessionCount = config.sessionCount.toNumber();
+
   console.log("\n=== All Sessions ===");
-  console.log("(This feature requires additional implementation to track all session dates)");
-  console.log("\nIn a production system, you would:");
-  console.log("1. Maintain a separate account that stores all session dates");
-  console.log("2. Or emit events that can be queried");
-  console.log("3. Or use an off-chain database to track sessions");
-  
-  // For now, we can show how to fetch a specific session if we know the date
-  console.log("\nExample: To fetch a session for July 30, 2024:");
-  console.log("ts-node stats.ts session-summary 2024-07-30");
+  console.log("Total sessions:", sessionCount);
+
+  // Sessions are enumerated by their id, from 0 to Config.sessionCount - 1
+  for (let id = 0; id < sessionCount; id++) {
+    const session = await program.account.session.fetchNullable(sessionPDAFor(program, id));
+    if (!session) continue;
+
+    console.log(
+      `#${id}`,
+      new Date(session.startTime.toNumber() * 1000).toLocaleString(),
+      `- ${session.title} (${Object.keys(session.kind)[0]})`,
+      `| attendees: ${session.totalAttendees}, late: ${session.totalLate}`,
+      `| ${sessionStateOf(session)}`
+    );
+  }
 }
 
 main().catch(console.error);
\ No newline at end of file
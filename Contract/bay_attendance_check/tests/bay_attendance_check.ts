@@ -1,6 +1,11 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { BayAttendanceCheck } from "../target/types/bay_attendance_check";
+import {
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import { assert } from "chai";
 
 describe("bay_attendance_check", () => {
@@ -16,14 +21,22 @@ describe("bay_attendance_check", () => {
   let member2: anchor.web3.Keypair;
 
   // PDAs
+  let configPDA: anchor.web3.PublicKey;
   let adminMemberPDA: anchor.web3.PublicKey;
   let member1PDA: anchor.web3.PublicKey;
   let member2PDA: anchor.web3.PublicKey;
   let sessionPDA: anchor.web3.PublicKey;
+  let studySessionPDA: anchor.web3.PublicKey;
   let attendanceRecordPDA: anchor.web3.PublicKey;
+  let mintAuthorityPDA: anchor.web3.PublicKey;
+
+  // BAY point token
+  let pointMint: anchor.web3.PublicKey;
+  const TOKEN_DECIMALS = 6;
 
   // Test data
   const sessionDate = new anchor.BN(Date.now() / 1000); // Current timestamp
+  const openTime = new anchor.BN(Date.now() / 1000 - 600); // 10 minutes ago
   const startTime = new anchor.BN(Date.now() / 1000 + 3600); // 1 hour from now
   const lateTime = new anchor.BN(Date.now() / 1000 + 5400); // 1.5 hours from now
 
@@ -53,6 +66,11 @@ describe("bay_attendance_check", () => {
     await provider.connection.confirmTransaction(airdropMember2);
 
     // Derive PDAs
+    [configPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      program.programId
+    );
+
     [adminMemberPDA] = anchor.web3.PublicKey.findProgramAddressSync(
       [Buffer.from("member"), admin.publicKey.toBuffer()],
       program.programId
@@ -68,18 +86,57 @@ describe("bay_attendance_check", () => {
       program.programId
     );
 
+    // First session created after config initialization has id 0
     [sessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("session"), sessionDate.toArrayLike(Buffer, "le", 8)],
+      [Buffer.from("session"), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
       program.programId
     );
+
+    [mintAuthorityPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_authority")],
+      program.programId
+    );
+
+    // Test mint whose mint authority is the program PDA
+    pointMint = await createMint(
+      provider.connection,
+      admin,
+      mintAuthorityPDA,
+      null,
+      TOKEN_DECIMALS
+    );
+  });
+
+  it("Initialize config with the upgrade authority", async () => {
+    const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
+
+    await program.methods
+      .initializeConfig(admin.publicKey)
+      .accounts({
+        authority: provider.publicKey,
+        programData,
+        pointMint,
+      })
+      .rpc();
+
+    const configAccount = await program.account.config.fetch(configPDA);
+    assert.equal(configAccount.superAdmin.toString(), admin.publicKey.toString());
+    assert.equal(configAccount.pointMint.toString(), pointMint.toString());
+    // Session dates default to KST with a 3 hour late window cap
+    assert.equal(configAccount.sessionTimeRules.utcOffset, 9 * 3600);
+    assert.equal(configAccount.sessionTimeRules.maxLateWindow, 3 * 3600);
   });
 
   it("Initialize admin member", async () => {
+    // Super admin registers itself without an existing admin member account
     await program.methods
       .initializeMember({ admin: {} })
-      .accounts({
+      .accountsPartial({
         authority: admin.publicKey,
-        admin: admin.publicKey,
+        admin: null,
         memberWallet: admin.publicKey,
       })
       .signers([admin])
@@ -98,12 +155,12 @@ describe("bay_attendance_check", () => {
   it("Initialize regular member", async () => {
     await program.methods
       .initializeMember({ member: {} })
-      .accounts({
-        authority: member1.publicKey,
-        admin: admin.publicKey,
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
         memberWallet: member1.publicKey,
       })
-      .signers([member1])
+      .signers([admin])
       .rpc();
 
     const memberAccount = await program.account.member.fetch(member1PDA);
@@ -112,9 +169,106 @@ describe("bay_attendance_check", () => {
     assert.equal(memberAccount.isActive, true);
   });
 
+  it("Non-admin cannot register members", async () => {
+    try {
+      await program.methods
+        .initializeMember({ admin: {} })
+        .accountsPartial({
+          authority: member2.publicKey,
+          admin: null,
+          memberWallet: member2.publicKey,
+        })
+        .signers([member2])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "Unauthorized");
+    }
+  });
+
+  it("Initialize a second regular member", async () => {
+    await program.methods
+      .initializeMember({ member: {} })
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        memberWallet: member2.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const memberAccount = await program.account.member.fetch(member2PDA);
+    assert.deepEqual(memberAccount.role, { member: {} });
+  });
+
+  it("Session times are validated against the session date", async () => {
+    // Pin the timezone so that the current time is local noon and the test
+    // session times always fall on the same calendar day
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .setSessionTimeRules({
+        utcOffset: 43200 - (now % 86400),
+        maxLateWindow: 3 * 3600,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const expectFailure = async (date: number, start: number, late: number, error: string, open = start) => {
+      try {
+        await program.methods
+          .initializeSession(
+            "Invalid session",
+            { regular: {} },
+            new anchor.BN(date),
+            new anchor.BN(open),
+            new anchor.BN(start),
+            new anchor.BN(late)
+          )
+          .accounts({
+            authority: admin.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (err) {
+        assert.include(err.toString(), error);
+      }
+    };
+
+    // Session date two days before the session times
+    await expectFailure(now - 2 * 86400, now + 3600, now + 5400, "SessionTimeOutsideDate");
+    // Check-in opening the day before the session, or without any opening time
+    await expectFailure(now, now + 3600, now + 5400, "SessionTimeOutsideDate", now - 86400);
+    await expectFailure(now, now + 3600, now + 5400, "SessionTimeOutsideDate", 0);
+    // Session that already ended
+    await expectFailure(now, now - 7200, now - 3600, "SessionAlreadyEnded");
+    // Four hour late window
+    await expectFailure(now, now + 600, now + 600 + 4 * 3600, "LateWindowTooLong");
+
+    // Only the super admin can change the rules
+    try {
+      await program.methods
+        .setSessionTimeRules({ utcOffset: 0, maxLateWindow: 0 })
+        .accounts({
+          authority: member1.publicKey,
+        })
+        .signers([member1])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "Unauthorized");
+    }
+  });
+
   it("Initialize session", async () => {
     await program.methods
-      .initializeSession(sessionDate, startTime, lateTime)
+      .initializeSession("Main session", { regular: {} }, sessionDate, openTime, startTime, lateTime)
       .accounts({
         authority: admin.publicKey,
       })
@@ -122,13 +276,156 @@ describe("bay_attendance_check", () => {
       .rpc();
 
     const sessionAccount = await program.account.session.fetch(sessionPDA);
+    assert.equal(sessionAccount.id.toNumber(), 0);
+    assert.equal(sessionAccount.title, "Main session");
+    assert.deepEqual(sessionAccount.kind, { regular: {} });
     assert.equal(sessionAccount.admin.toString(), admin.publicKey.toString());
+    assert.deepEqual(sessionAccount.coHosts, []);
     assert.equal(sessionAccount.sessionDate.toNumber(), sessionDate.toNumber());
+    assert.equal(sessionAccount.openTime.toNumber(), openTime.toNumber());
     assert.equal(sessionAccount.startTime.toNumber(), startTime.toNumber());
     assert.equal(sessionAccount.lateTime.toNumber(), lateTime.toNumber());
     assert.equal(sessionAccount.totalAttendees, 0);
     assert.equal(sessionAccount.totalLate, 0);
-    assert.equal(sessionAccount.isActive, true);
+    assert.equal(sessionAccount.totalAbsent, 0);
+    assert.deepEqual(sessionAccount.stateOverride, { auto: {} });
+    assert.equal(sessionAccount.isFinalized, false);
+    assert.equal(sessionAccount.isCancelled, false);
+    assert.equal(sessionAccount.pointSchedule.presentPoints, 10);
+    assert.equal(sessionAccount.pointSchedule.latePoints, 5);
+    assert.equal(sessionAccount.pointMultiplier, 100);
+  });
+
+  it("Initialize a second session on the same date", async () => {
+    [studySessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), new anchor.BN(1).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .initializeSession("Evening study", { study: {} }, sessionDate, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const sessionAccount = await program.account.session.fetch(studySessionPDA);
+    assert.equal(sessionAccount.id.toNumber(), 1);
+    assert.deepEqual(sessionAccount.kind, { study: {} });
+
+    const configAccount = await program.account.config.fetch(configPDA);
+    assert.equal(configAccount.sessionCount.toNumber(), 2);
+  });
+
+  it("Session admin can set co-hosts", async () => {
+    await program.methods
+      .setSessionCoHosts([member2.publicKey])
+      .accounts({
+        authority: admin.publicKey,
+        session: sessionPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const sessionAccount = await program.account.session.fetch(sessionPDA);
+    assert.deepEqual(
+      sessionAccount.coHosts.map((key) => key.toString()),
+      [member2.publicKey.toString()]
+    );
+  });
+
+  it("Only the session admin can change co-hosts", async () => {
+    try {
+      await program.methods
+        .setSessionCoHosts([member1.publicKey])
+        .accounts({
+          authority: member1.publicKey,
+          session: sessionPDA,
+        })
+        .signers([member1])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "NotSessionHost");
+    }
+  });
+
+  it("Session host can double the rewards for an important session", async () => {
+    const setRewards = (pointSchedule: any, multiplier: number) =>
+      program.methods
+        .setSessionRewards(pointSchedule, multiplier)
+        .accounts({
+          authority: admin.publicKey,
+          session: sessionPDA,
+        })
+        .signers([admin])
+        .rpc();
+    const reschedule = (open: anchor.BN) =>
+      program.methods
+        .reactivateSession({ extend: {} }, open, startTime, lateTime)
+        .accounts({
+          authority: admin.publicKey,
+          session: sessionPDA,
+        })
+        .signers([admin])
+        .rpc();
+
+    // Check-in has already opened, so the rewards are locked
+    try {
+      await setRewards(null, 200);
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "RewardsLocked");
+    }
+
+    // Push the opening back, change the rewards, then reopen check-in
+    await reschedule(startTime.subn(60));
+
+    try {
+      await setRewards({ presentPoints: 5, latePoints: 10, earlyBirdBonus: 0, earlyBirdWindow: new anchor.BN(0) }, 200);
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "InvalidPointSchedule");
+    }
+
+    await setRewards(null, 200);
+    await reschedule(openTime);
+
+    const sessionAccount = await program.account.session.fetch(sessionPDA);
+    assert.equal(sessionAccount.pointMultiplier, 200);
+  });
+
+  it("Only the super admin can change the default point schedule", async () => {
+    const schedule = (presentPoints: number) => ({
+      presentPoints,
+      latePoints: 5,
+      earlyBirdBonus: 0,
+      earlyBirdWindow: new anchor.BN(0),
+    });
+
+    for (const [pointSchedule, signer, error] of [
+      [schedule(10), member1, "Unauthorized"],
+      [schedule(1001), admin, "InvalidPointSchedule"],
+    ] as [ReturnType<typeof schedule>, anchor.web3.Keypair, string][]) {
+      try {
+        await program.methods
+          .setPointSchedule(pointSchedule)
+          .accounts({
+            authority: signer.publicKey,
+          })
+          .signers([signer])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (err) {
+        assert.include(err.toString(), error);
+      }
+    }
+
+    const configAccount = await program.account.config.fetch(configPDA);
+    assert.equal(configAccount.pointSchedule.presentPoints, 10);
   });
 
   it("Member checks in on time", async () => {
@@ -143,14 +440,26 @@ describe("bay_attendance_check", () => {
     );
 
     // Simulate on-time check-in by manipulating clock (in real test, would need mock)
-    await program.methods
+    const tx = await program.methods
       .checkIn()
       .accounts({
         memberWallet: member1.publicKey,
         session: sessionPDA,
+        pointMint,
       })
       .signers([member1])
-      .rpc();
+      .rpc({ commitment: "confirmed" });
+
+    // CheckedIn event is emitted with the resulting status and points
+    const txInfo = await provider.connection.getTransaction(tx, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const eventParser = new anchor.EventParser(program.programId, program.coder);
+    const events = [...eventParser.parseLogs(txInfo.meta.logMessages)];
+    const checkedIn = events.find((event) => event.name === "checkedIn");
+    assert.isDefined(checkedIn);
+    assert.equal(checkedIn.data.member.toString(), member1.publicKey.toString());
 
     const attendanceRecord = await program.account.attendanceRecord.fetch(
       attendanceRecordPDA
@@ -160,10 +469,71 @@ describe("bay_attendance_check", () => {
       member1.publicKey.toString()
     );
     assert.equal(attendanceRecord.session.toString(), sessionPDA.toString());
+    assert.equal(attendanceRecord.payer.toString(), member1.publicKey.toString());
+    // 10 present points with a 2x multiplier
+    assert.deepEqual(attendanceRecord.status, { present: {} });
+    assert.equal(attendanceRecord.pointsEarned.toNumber(), 20);
     
     // Check member stats updated
     const memberAccount = await program.account.member.fetch(member1PDA);
     assert.isTrue(memberAccount.totalPoints.toNumber() > 0);
+    assert.equal(
+      checkedIn.data.points.toNumber(),
+      memberAccount.totalPoints.toNumber()
+    );
+
+    // Earned points are minted as BAY tokens
+    const tokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(pointMint, member1.publicKey)
+    );
+    assert.equal(
+      Number(tokenAccount.amount),
+      attendanceRecord.pointsEarned.toNumber() * 10 ** TOKEN_DECIMALS
+    );
+  });
+
+  it("Check-in only mints through the configured BAY mint", async () => {
+    // Another mint owned by the same mint authority PDA
+    const otherMint = await createMint(
+      provider.connection,
+      admin,
+      mintAuthorityPDA,
+      null,
+      TOKEN_DECIMALS
+    );
+
+    try {
+      await program.methods
+        .checkIn()
+        .accounts({
+          memberWallet: member2.publicKey,
+          session: sessionPDA,
+          pointMint: otherMint,
+        })
+        .signers([member2])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "InvalidPointMint");
+    }
+
+    // Only the super admin can point the config at a different mint
+    try {
+      await program.methods
+        .setPointMint()
+        .accounts({
+          authority: member2.publicKey,
+          pointMint: otherMint,
+        })
+        .signers([member2])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "Unauthorized");
+    }
   });
 
   it("Cannot check in twice", async () => {
@@ -173,6 +543,7 @@ describe("bay_attendance_check", () => {
         .accounts({
           memberWallet: member1.publicKey,
           session: sessionPDA,
+          pointMint,
         })
         .signers([member1])
         .rpc();
@@ -183,9 +554,26 @@ describe("bay_attendance_check", () => {
     }
   });
 
-  it("Admin can update session status", async () => {
+  it("Cannot finalize session before late time", async () => {
+    try {
+      await program.methods
+        .finalizeSession(true)
+        .accounts({
+          authority: admin.publicKey,
+          session: sessionPDA,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "SessionNotEnded");
+    }
+  });
+
+  it("Admin can close a session manually", async () => {
     await program.methods
-      .updateSessionStatus(false)
+      .setSessionOverride({ closed: {} })
       .accounts({
         authority: admin.publicKey,
         session: sessionPDA,
@@ -194,26 +582,1895 @@ describe("bay_attendance_check", () => {
       .rpc();
 
     const sessionAccount = await program.account.session.fetch(sessionPDA);
-    assert.equal(sessionAccount.isActive, false);
+    assert.deepEqual(sessionAccount.stateOverride, { closed: {} });
   });
 
-  it("Get member stats", async () => {
+  it("Reactivate session in extend mode keeps stats and records", async () => {
     await program.methods
-      .getMemberStats()
+      .reactivateSession({ extend: {} }, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+        session: sessionPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const sessionAccount = await program.account.session.fetch(sessionPDA);
+    assert.deepEqual(sessionAccount.stateOverride, { auto: {} });
+    assert.equal(sessionAccount.totalAttendees, 1);
+
+    const attendanceRecord = await program.account.attendanceRecord.fetchNullable(
+      attendanceRecordPDA
+    );
+    assert.isNotNull(attendanceRecord);
+  });
+
+  it("Reactivate session in reset mode closes records and reverts stats", async () => {
+    await program.methods
+      .reactivateSession({ reset: {} }, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+        session: sessionPDA,
+      })
+      .remainingAccounts([
+        { pubkey: attendanceRecordPDA, isSigner: false, isWritable: true },
+        { pubkey: member1PDA, isSigner: false, isWritable: true },
+        { pubkey: member1.publicKey, isSigner: false, isWritable: true },
+      ])
+      .signers([admin])
+      .rpc();
+
+    const sessionAccount = await program.account.session.fetch(sessionPDA);
+    assert.equal(sessionAccount.totalAttendees, 0);
+    assert.equal(sessionAccount.totalLate, 0);
+
+    const memberAccount = await program.account.member.fetch(member1PDA);
+    assert.equal(memberAccount.totalAttendance + memberAccount.totalLate, 0);
+    assert.equal(memberAccount.totalPoints.toNumber(), 0);
+    assert.equal(memberAccount.currentStreak, 0);
+    // The 20 BAY minted by the closed check-in stay in the wallet as a credit
+    assert.equal(memberAccount.mintedCredit.toNumber(), 20);
+
+    const attendanceRecord = await program.account.attendanceRecord.fetchNullable(
+      attendanceRecordPDA
+    );
+    assert.isNull(attendanceRecord);
+  });
+
+  it("Admin can record attendance for a member whose phone died", async () => {
+    await program.methods
+      .recordAttendance({ present: {} }, { deviceFailure: {} })
       .accounts({
+        authority: admin.publicKey,
         member: member1PDA,
+        memberWallet: member1.publicKey,
+        session: sessionPDA,
+        pointMint,
       })
+      .signers([admin])
       .rpc();
-    // Check logs for stats output
+
+    const record = await program.account.attendanceRecord.fetch(attendanceRecordPDA);
+    assert.deepEqual(record.status, { present: {} });
+    assert.equal(record.correctedBy.toString(), admin.publicKey.toString());
+    assert.deepEqual(record.correctionReason, { deviceFailure: {} });
+    assert.equal(record.pointsEarned.toNumber(), 20);
+
+    const sessionAccount = await program.account.session.fetch(sessionPDA);
+    assert.equal(sessionAccount.totalAttendees, 1);
+
+    // The re-recorded points are covered by the credit from the reset, not minted again
+    const memberAccount = await program.account.member.fetch(member1PDA);
+    assert.equal(memberAccount.mintedCredit.toNumber(), 0);
+    const tokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(pointMint, member1.publicKey)
+    );
+    assert.equal(Number(tokenAccount.amount), 20 * 10 ** TOKEN_DECIMALS);
   });
 
-  it("Get session stats", async () => {
+  it("Admin correction keeps member and session counters consistent", async () => {
     await program.methods
-      .getSessionStats()
+      .recordAttendance({ late: {} }, { recordingError: {} })
       .accounts({
+        authority: admin.publicKey,
+        member: member1PDA,
+        memberWallet: member1.publicKey,
         session: sessionPDA,
+        pointMint,
       })
+      .signers([admin])
       .rpc();
-    // Check logs for stats output
+
+    const record = await program.account.attendanceRecord.fetch(attendanceRecordPDA);
+    assert.deepEqual(record.status, { late: {} });
+    assert.equal(record.pointsEarned.toNumber(), 10);
+
+    const sessionAccount = await program.account.session.fetch(sessionPDA);
+    assert.equal(sessionAccount.totalAttendees, 1);
+    assert.equal(sessionAccount.totalLate, 1);
+
+    const memberAccount = await program.account.member.fetch(member1PDA);
+    assert.equal(memberAccount.totalAttendance, 0);
+    assert.equal(memberAccount.totalLate, 1);
+    assert.equal(memberAccount.totalPoints.toNumber(), 10);
+  });
+
+  it("Correction round trips do not mint BAY tokens twice", async () => {
+    const member1TokenAccount = getAssociatedTokenAddressSync(pointMint, member1.publicKey);
+    const before = await getAccount(provider.connection, member1TokenAccount);
+
+    // Late -> Present -> Late: the Present points were already minted once
+    for (const status of [{ present: {} }, { late: {} }]) {
+      await program.methods
+        .recordAttendance(status, { recordingError: {} })
+        .accounts({
+          authority: admin.publicKey,
+          member: member1PDA,
+          memberWallet: member1.publicKey,
+          session: sessionPDA,
+          pointMint,
+        })
+        .signers([admin])
+        .rpc();
+    }
+
+    const after = await getAccount(provider.connection, member1TokenAccount);
+    assert.equal(after.amount.toString(), before.amount.toString());
+
+    const record = await program.account.attendanceRecord.fetch(attendanceRecordPDA);
+    assert.deepEqual(record.status, { late: {} });
+    assert.equal(record.pointsEarned.toNumber(), 10);
+    assert.equal(record.pointsMinted.toNumber(), 20);
+
+    const memberAccount = await program.account.member.fetch(member1PDA);
+    assert.equal(memberAccount.totalPoints.toNumber(), 10);
+  });
+
+  it("Regular members cannot record attendance", async () => {
+    try {
+      await program.methods
+        .recordAttendance({ present: {} }, { other: {} })
+        .accounts({
+          authority: member1.publicKey,
+          member: member1PDA,
+          memberWallet: member1.publicKey,
+          session: sessionPDA,
+          pointMint,
+        })
+        .signers([member1])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      // member1 is registered with the Member role, which lacks the correction permission
+      assert.include(err.toString(), "Unauthorized");
+    }
+  });
+
+  it("Presence mode rejects check-in without a signed QR code", async () => {
+    await program.methods
+      .setSessionPresenceMode(60)
+      .accounts({
+        authority: admin.publicKey,
+        session: studySessionPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    try {
+      await program.methods
+        .checkIn()
+        .accounts({
+          memberWallet: member1.publicKey,
+          session: studySessionPDA,
+          pointMint,
+        })
+        .signers([member1])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "PresenceProofRequired");
+    }
+  });
+
+  it("Presence mode accepts a QR code signed by the session admin", async () => {
+    // QR payload: "BAY_CHECKIN" || session || (now / presence window) as i64 LE
+    const windowIndex = new anchor.BN(Math.floor(Date.now() / 1000 / 60));
+    const message = Buffer.concat([
+      Buffer.from("BAY_CHECKIN"),
+      studySessionPDA.toBuffer(),
+      windowIndex.toArrayLike(Buffer, "le", 8),
+    ]);
+
+    await program.methods
+      .checkIn()
+      .accounts({
+        memberWallet: member1.publicKey,
+        session: studySessionPDA,
+        pointMint,
+      })
+      .preInstructions([
+        anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: admin.secretKey,
+          message,
+        }),
+      ])
+      .signers([member1])
+      .rpc();
+
+    const sessionAccount = await program.account.session.fetch(studySessionPDA);
+    assert.equal(sessionAccount.totalAttendees, 1);
+  });
+
+  it("Member submits an excuse before an event and an admin approves it", async () => {
+    const [eventSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), new anchor.BN(2).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [excuseRequestPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("excuse"), eventSessionPDA.toBuffer(), member1.publicKey.toBuffer()],
+      program.programId
+    );
+    const [eventRecordPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("attendance"), eventSessionPDA.toBuffer(), member1.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeSession("Club event", { event: {} }, sessionDate, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const memberBefore = await program.account.member.fetch(member1PDA);
+
+    await program.methods
+      .submitExcuse(Array.from(Buffer.alloc(32, 7)))
+      .accounts({
+        memberWallet: member1.publicKey,
+        session: eventSessionPDA,
+      })
+      .signers([member1])
+      .rpc();
+
+    let excuseRequest = await program.account.excuseRequest.fetch(excuseRequestPDA);
+    assert.deepEqual(excuseRequest.status, { pending: {} });
+    assert.equal(excuseRequest.isPreAnnounced, true);
+
+    await program.methods
+      .approveExcuse()
+      .accounts({
+        authority: admin.publicKey,
+        session: eventSessionPDA,
+        excuseRequest: excuseRequestPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    excuseRequest = await program.account.excuseRequest.fetch(excuseRequestPDA);
+    assert.deepEqual(excuseRequest.status, { approved: {} });
+    assert.equal(excuseRequest.reviewedBy.toString(), admin.publicKey.toString());
+
+    const record = await program.account.attendanceRecord.fetch(eventRecordPDA);
+    assert.deepEqual(record.status, { excused: {} });
+
+    const sessionAccount = await program.account.session.fetch(eventSessionPDA);
+    assert.equal(sessionAccount.totalExcused, 1);
+    assert.equal(sessionAccount.totalAbsent, 0);
+
+    const memberAccount = await program.account.member.fetch(member1PDA);
+    assert.equal(memberAccount.totalExcused, memberBefore.totalExcused + 1);
+    assert.equal(memberAccount.totalAbsence, memberBefore.totalAbsence);
+
+    try {
+      await program.methods
+        .rejectExcuse()
+        .accounts({
+          authority: admin.publicKey,
+          excuseRequest: excuseRequestPDA,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "ExcuseAlreadyReviewed");
+    }
+  });
+
+  it("Admin can promote, deactivate and close a member", async () => {
+    const officer = anchor.web3.Keypair.generate();
+    const [officerPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("member"), officer.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeMember({ member: {} })
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        memberWallet: officer.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .changeMemberRole({ admin: {} })
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        member: officerPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    let memberAccount = await program.account.member.fetch(officerPDA);
+    assert.deepEqual(memberAccount.role, { admin: {} });
+
+    try {
+      await program.methods
+        .closeMember()
+        .accountsPartial({
+          authority: admin.publicKey,
+          admin: adminMemberPDA,
+          member: officerPDA,
+          payer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "MemberStillActive");
+    }
+
+    await program.methods
+      .setMemberActive(false)
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        member: officerPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    memberAccount = await program.account.member.fetch(officerPDA);
+    assert.equal(memberAccount.isActive, false);
+
+    // The rent can only be refunded to the wallet that paid it at registration
+    try {
+      await program.methods
+        .closeMember()
+        .accountsPartial({
+          authority: admin.publicKey,
+          admin: adminMemberPDA,
+          member: officerPDA,
+          payer: officer.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "Unauthorized");
+    }
+
+    assert.equal(memberAccount.payer.toString(), admin.publicKey.toString());
+    const memberRent = await provider.connection.getBalance(officerPDA);
+    const adminBefore = await provider.connection.getBalance(admin.publicKey);
+    await program.methods
+      .closeMember()
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        member: officerPDA,
+        payer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    assert.isNull(await program.account.member.fetchNullable(officerPDA));
+    assert.equal(
+      await provider.connection.getBalance(admin.publicKey),
+      adminBefore + memberRent
+    );
+  });
+
+  it("Roles are checked against the configurable permission bitmask", async () => {
+    const configAccount = await program.account.config.fetch(configPDA);
+    assert.equal(configAccount.rolePermissions[4], 0b00001); // TeamLead: create session
+
+    const teamLead = anchor.web3.Keypair.generate();
+    await program.methods
+      .initializeMember({ teamLead: {} })
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        memberWallet: teamLead.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const [teamLeadPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("member"), teamLead.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // Team leads cannot manage members by default
+    try {
+      await program.methods
+        .initializeMember({ member: {} })
+        .accountsPartial({
+          authority: teamLead.publicKey,
+          admin: teamLeadPDA,
+          memberWallet: anchor.web3.Keypair.generate().publicKey,
+        })
+        .signers([teamLead])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "Unauthorized");
+    }
+
+    await program.methods
+      .setRolePermissions({ teamLead: {} }, 0b10001)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeMember({ probationary: {} })
+      .accountsPartial({
+        authority: teamLead.publicKey,
+        admin: teamLeadPDA,
+        memberWallet: anchor.web3.Keypair.generate().publicKey,
+      })
+      .signers([teamLead])
+      .rpc();
+
+    // Roles with more permissions than the granter cannot be assigned
+    try {
+      await program.methods
+        .initializeMember({ president: {} })
+        .accountsPartial({
+          authority: teamLead.publicKey,
+          admin: teamLeadPDA,
+          memberWallet: anchor.web3.Keypair.generate().publicKey,
+        })
+        .signers([teamLead])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "Unauthorized");
+    }
+
+    try {
+      await program.methods
+        .setRolePermissions({ member: {} }, 1 << 8)
+        .accounts({
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "InvalidPermissions");
+    }
+  });
+
+  it("Member and admin can update the member profile", async () => {
+    await program.methods
+      .updateMemberProfile({
+        displayName: "bay01",
+        cohort: 15,
+        profileUri: "https://example.com/bay01.json",
+      })
+      .accountsPartial({
+        authority: member1.publicKey,
+        admin: null,
+        member: member1PDA,
+      })
+      .signers([member1])
+      .rpc();
+
+    await program.methods
+      .updateMemberProfile({
+        displayName: "bay01",
+        cohort: 16,
+        profileUri: "https://example.com/bay01.json",
+      })
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        member: member1PDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const memberAccount = await program.account.member.fetch(member1PDA);
+    assert.equal(memberAccount.displayName, "bay01");
+    assert.equal(memberAccount.cohort, 16);
+
+    try {
+      await program.methods
+        .updateMemberProfile({
+          displayName: "not me",
+          cohort: null,
+          profileUri: "",
+        })
+        .accountsPartial({
+          authority: member2.publicKey,
+          admin: null,
+          member: member1PDA,
+        })
+        .signers([member2])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "Unauthorized");
+    }
+  });
+
+  it("Migrating an up-to-date account is a no-op", async () => {
+    const before = await provider.connection.getAccountInfo(member1PDA);
+
+    await program.methods
+      .migrateMember()
+      .accounts({
+        account: member1PDA,
+      })
+      .rpc();
+
+    const after = await provider.connection.getAccountInfo(member1PDA);
+    assert.equal(after.data.length, before.data.length);
+
+    const memberAccount = await program.account.member.fetch(member1PDA);
+    assert.equal(memberAccount.version, 4);
+
+    const sessionAccount = await program.account.session.fetch(sessionPDA);
+    assert.equal(sessionAccount.version, 2);
+  });
+
+  it("Migration rejects accounts of a different type", async () => {
+    try {
+      await program.methods
+        .migrateMember()
+        .accounts({
+          account: sessionPDA,
+        })
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "InvalidMigrationAccount");
+    }
+  });
+
+  it("Migration converts baseline-layout accounts field by field", async () => {
+    // Loaded into the validator from tests/fixtures (see Anchor.toml): a member with
+    // 3 attended, 1 late, 1 absent and 35 points, a session keyed by its date, and a
+    // present record worth 10 points, all written with the baseline layouts
+    const legacyWallet = new anchor.web3.PublicKey("6Nb9poon4tjKvWGJ9F8wg6FyFKWMApZut119HfSEaHZY");
+    const legacyAdmin = new anchor.web3.PublicKey("9KKe41geUxXxk8ZPNtbfNtjkvY2cdUuryqVMC94H39s1");
+    const legacySessionDate = new anchor.BN(1719792000);
+    const [legacyMemberPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("member"), legacyWallet.toBuffer()],
+      program.programId
+    );
+    const [legacySessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), legacySessionDate.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [legacyRecordPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("attendance"), legacySessionPDA.toBuffer(), legacyWallet.toBuffer()],
+      program.programId
+    );
+
+    const legacyInfo = await provider.connection.getAccountInfo(legacyMemberPDA);
+    assert.equal(legacyInfo.data.length, 63);
+
+    await program.methods.migrateMember().accounts({ account: legacyMemberPDA }).rpc();
+    await program.methods.migrateSession().accounts({ account: legacySessionPDA }).rpc();
+    await program.methods.migrateAttendanceRecord().accounts({ account: legacyRecordPDA }).rpc();
+
+    const member = await program.account.member.fetch(legacyMemberPDA);
+    assert.equal(member.version, 4);
+    assert.equal(member.wallet.toString(), legacyWallet.toString());
+    assert.equal(member.payer.toString(), anchor.web3.PublicKey.default.toString());
+    assert.deepEqual(member.role, { member: {} });
+    assert.equal(member.totalAttendance, 3);
+    assert.equal(member.totalLate, 1);
+    assert.equal(member.totalAbsence, 1);
+    assert.equal(member.totalExcused, 0);
+    assert.equal(member.totalPoints.toNumber(), 35);
+    assert.equal(member.isActive, true);
+    assert.equal(member.displayName, "");
+    assert.isNull(member.teamId);
+
+    // The session id takes the date so that [session, id] matches the legacy address
+    const session = await program.account.session.fetch(legacySessionPDA);
+    assert.equal(session.version, 2);
+    assert.equal(session.id.toNumber(), legacySessionDate.toNumber());
+    assert.equal(session.admin.toString(), legacyAdmin.toString());
+    assert.equal(session.sessionDate.toNumber(), legacySessionDate.toNumber());
+    assert.equal(session.startTime.toNumber(), legacySessionDate.toNumber() + 10 * 3600);
+    assert.equal(session.lateTime.toNumber(), session.startTime.toNumber() + 1800);
+    assert.equal(session.totalAttendees, 1);
+    assert.deepEqual(session.stateOverride, { closed: {} });
+    assert.equal(session.pointMultiplier, 100);
+    const stats = await program.methods
+      .getSessionStats()
+      .accounts({ session: legacySessionPDA })
+      .view();
+    assert.equal(stats.totalAttendees, 1);
+
+    const record = await program.account.attendanceRecord.fetch(legacyRecordPDA);
+    assert.equal(record.version, 3);
+    assert.equal(record.member.toString(), legacyWallet.toString());
+    assert.equal(record.session.toString(), legacySessionPDA.toString());
+    assert.deepEqual(record.status, { present: {} });
+    assert.equal(record.pointsEarned.toNumber(), 10);
+    assert.equal(record.payer.toString(), legacyWallet.toString());
+    // Baseline check-ins never minted BAY tokens
+    assert.equal(record.pointsMinted.toNumber(), 0);
+    assert.equal(record.checkInTime.toNumber(), session.startTime.toNumber() - 120);
+  });
+
+  it("Sessions in a term update the member's term statistics", async () => {
+    const [termPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("term"), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [termStatsPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("term_stats"), termPDA.toBuffer(), member1.publicKey.toBuffer()],
+      program.programId
+    );
+    const configBefore = await program.account.config.fetch(configPDA);
+    const [termSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), configBefore.sessionCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .initializeTerm("2025-2", new anchor.BN(now - 30 * 86400), new anchor.BN(now + 120 * 86400))
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeTermStats()
+      .accounts({
+        term: termPDA,
+        member: member1PDA,
+      })
+      .rpc();
+
+    await program.methods
+      .initializeSession("Term session", { regular: {} }, sessionDate, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+        term: termPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const sessionAccount = await program.account.session.fetch(termSessionPDA);
+    assert.equal(sessionAccount.term.toString(), termPDA.toString());
+
+    // Check-in without the term statistics account is rejected
+    try {
+      await program.methods
+        .checkIn()
+        .accounts({
+          memberWallet: member1.publicKey,
+          session: termSessionPDA,
+          pointMint,
+        })
+        .signers([member1])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "TermStatsRequired");
+    }
+
+    await program.methods
+      .checkIn()
+      .accounts({
+        memberWallet: member1.publicKey,
+        session: termSessionPDA,
+        pointMint,
+        termStats: termStatsPDA,
+      })
+      .signers([member1])
+      .rpc();
+
+    const stats = await program.methods
+      .getTermStats()
+      .accounts({
+        termStats: termStatsPDA,
+      })
+      .view();
+
+    assert.equal(stats.totalAttendance, 1);
+    assert.equal(stats.totalPoints.toNumber(), 10);
+    assert.equal(stats.attendanceRateBps, 10000);
+
+    const termAccount = await program.account.term.fetch(termPDA);
+    assert.equal(termAccount.totalSessions, 1);
+  });
+
+  it("Reaching a streak milestone awards bonus points", async () => {
+    const memberBefore = await program.account.member.fetch(member1PDA);
+    const nextStreak = memberBefore.currentStreak + 1;
+    const emptyMilestone = { streak: 0, bonusPoints: 0 };
+
+    // Milestones must use distinct streak counts
+    try {
+      await program.methods
+        .setStreakRules({
+          keepStreakOnLate: false,
+          milestones: [
+            { streak: 3, bonusPoints: 5 },
+            { streak: 3, bonusPoints: 7 },
+            emptyMilestone,
+            emptyMilestone,
+          ],
+        })
+        .accounts({
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "InvalidStreakRules");
+    }
+
+    // Bonuses are capped and only the super admin can change the rules
+    for (const [bonusPoints, signer, error] of [
+      [1001, admin, "InvalidStreakRules"],
+      [5, member1, "Unauthorized"],
+    ] as [number, anchor.web3.Keypair, string][]) {
+      try {
+        await program.methods
+          .setStreakRules({
+            keepStreakOnLate: false,
+            milestones: [{ streak: 3, bonusPoints }, emptyMilestone, emptyMilestone, emptyMilestone],
+          })
+          .accounts({
+            authority: signer.publicKey,
+          })
+          .signers([signer])
+          .rpc();
+
+        assert.fail("Should have failed");
+      } catch (err) {
+        assert.include(err.toString(), error);
+      }
+    }
+
+    await program.methods
+      .setStreakRules({
+        keepStreakOnLate: false,
+        milestones: [
+          { streak: nextStreak, bonusPoints: 5 },
+          emptyMilestone,
+          emptyMilestone,
+          emptyMilestone,
+        ],
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const configBefore = await program.account.config.fetch(configPDA);
+    const [streakSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), configBefore.sessionCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .initializeSession("Streak session", { regular: {} }, sessionDate, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const tx = await program.methods
+      .checkIn()
+      .accounts({
+        memberWallet: member1.publicKey,
+        session: streakSessionPDA,
+        pointMint,
+      })
+      .signers([member1])
+      .rpc({ commitment: "confirmed" });
+
+    const txInfo = await provider.connection.getTransaction(tx, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const eventParser = new anchor.EventParser(program.programId, program.coder);
+    const events = [...eventParser.parseLogs(txInfo.meta.logMessages)];
+    const milestone = events.find((event) => event.name === "streakMilestoneReached");
+    assert.isDefined(milestone);
+    assert.equal(milestone.data.streak, nextStreak);
+    assert.equal(milestone.data.bonusPoints.toNumber(), 5);
+
+    // 10 present points plus the 5 point milestone bonus
+    const [recordPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("attendance"), streakSessionPDA.toBuffer(), member1.publicKey.toBuffer()],
+      program.programId
+    );
+    const record = await program.account.attendanceRecord.fetch(recordPDA);
+    assert.equal(record.pointsEarned.toNumber(), 15);
+
+    const memberAfter = await program.account.member.fetch(member1PDA);
+    assert.equal(memberAfter.currentStreak, nextStreak);
+    assert.isAtLeast(memberAfter.longestStreak, nextStreak);
+    assert.equal(
+      memberAfter.totalPoints.toNumber(),
+      memberBefore.totalPoints.toNumber() + 15
+    );
+  });
+
+  it("Check-in before the session opens is rejected", async () => {
+    // Check-in must open no later than the session start
+    try {
+      await program.methods
+        .initializeSession("Invalid session", { regular: {} }, sessionDate, lateTime, startTime, lateTime)
+        .accounts({
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "InvalidTimeParameters");
+    }
+
+    const configBefore = await program.account.config.fetch(configPDA);
+    const [laterSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), configBefore.sessionCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    // Check-in opens at the session start, one hour from now
+    await program.methods
+      .initializeSession("Later session", { regular: {} }, sessionDate, startTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    try {
+      await program.methods
+        .checkIn()
+        .accounts({
+          memberWallet: member1.publicKey,
+          session: laterSessionPDA,
+          pointMint,
+        })
+        .signers([member1])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "CheckInNotOpen");
+    }
+  });
+
+  it("Check-ins update the aggregates of the member's team", async () => {
+    const configBefore = await program.account.config.fetch(configPDA);
+    const [teamPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("team"), new anchor.BN(configBefore.teamCount).toArrayLike(Buffer, "le", 2)],
+      program.programId
+    );
+
+    await program.methods
+      .initializeTeam("Team Solana", admin.publicKey)
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const teamMember = anchor.web3.Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(
+      teamMember.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdrop);
+    const [teamMemberPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("member"), teamMember.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeMember({ member: {} })
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        memberWallet: teamMember.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .setMemberTeam(configBefore.teamCount)
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        member: teamMemberPDA,
+        previousTeam: null,
+        team: teamPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const [teamSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), configBefore.sessionCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .initializeSession("Team session", { regular: {} }, sessionDate, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    // Check-in without the member's team is rejected
+    try {
+      await program.methods
+        .checkIn()
+        .accounts({
+          memberWallet: teamMember.publicKey,
+          session: teamSessionPDA,
+          pointMint,
+        })
+        .signers([teamMember])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "TeamRequired");
+    }
+
+    await program.methods
+      .checkIn()
+      .accounts({
+        memberWallet: teamMember.publicKey,
+        session: teamSessionPDA,
+        pointMint,
+        team: teamPDA,
+      })
+      .signers([teamMember])
+      .rpc();
+
+    const stats = await program.methods
+      .getTeamStats()
+      .accounts({
+        team: teamPDA,
+      })
+      .view();
+
+    assert.equal(stats.name, "Team Solana");
+    assert.equal(stats.memberCount, 1);
+    assert.equal(stats.totalAttendance, 1);
+    assert.equal(stats.totalLate, 0);
+    assert.equal(stats.totalPoints.toNumber(), 10);
+    assert.equal(stats.pointsPerMember.toNumber(), 10);
+
+    // Members in a team cannot be closed
+    await program.methods
+      .setMemberActive(false)
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        member: teamMemberPDA,
+      })
+      .signers([admin])
+      .rpc();
+    try {
+      await program.methods
+        .closeMember()
+        .accountsPartial({
+          authority: admin.publicKey,
+          admin: adminMemberPDA,
+          member: teamMemberPDA,
+          payer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "MemberStillInTeam");
+    }
+  });
+
+  it("Leaderboards rank members by points and are updated on check-in", async () => {
+    const [leaderboardPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), anchor.web3.PublicKey.default.toBuffer()],
+      program.programId
+    );
+    const [termPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("term"), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [termLeaderboardPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), termPDA.toBuffer()],
+      program.programId
+    );
+    const [termStatsPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("term_stats"), termPDA.toBuffer(), member1.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeLeaderboard(anchor.web3.PublicKey.default)
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        termAccount: null,
+      })
+      .signers([admin])
+      .rpc();
+    await program.methods
+      .initializeLeaderboard(termPDA)
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        termAccount: termPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    // Existing members are added by refreshing with their accounts
+    await program.methods
+      .refreshLeaderboard()
+      .accounts({
+        leaderboard: leaderboardPDA,
+      })
+      .remainingAccounts([
+        { pubkey: adminMemberPDA, isSigner: false, isWritable: false },
+        { pubkey: member1PDA, isSigner: false, isWritable: false },
+      ])
+      .rpc();
+    await program.methods
+      .refreshLeaderboard()
+      .accounts({
+        leaderboard: termLeaderboardPDA,
+      })
+      .remainingAccounts([
+        { pubkey: termStatsPDA, isSigner: false, isWritable: false },
+      ])
+      .rpc();
+
+    const member1Before = await program.account.member.fetch(member1PDA);
+    let leaderboard = await program.methods
+      .getLeaderboard()
+      .accounts({
+        leaderboard: leaderboardPDA,
+      })
+      .view();
+    assert.isNull(leaderboard.term);
+    assert.equal(leaderboard.entries.length, 2);
+    assert.equal(leaderboard.entries[0].wallet.toString(), member1.publicKey.toString());
+    assert.equal(leaderboard.entries[0].points.toNumber(), member1Before.totalPoints.toNumber());
+
+    const termLeaderboard = await program.methods
+      .getLeaderboard()
+      .accounts({
+        leaderboard: termLeaderboardPDA,
+      })
+      .view();
+    assert.equal(termLeaderboard.term.toString(), termPDA.toString());
+    assert.equal(termLeaderboard.entries.length, 1);
+
+    const configBefore = await program.account.config.fetch(configPDA);
+    const [leaderboardSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), configBefore.sessionCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .initializeSession("Leaderboard session", { regular: {} }, sessionDate, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    // A term leaderboard cannot be updated by a session outside the term
+    try {
+      await program.methods
+        .checkIn()
+        .accounts({
+          memberWallet: member1.publicKey,
+          session: leaderboardSessionPDA,
+          pointMint,
+          termLeaderboard: termLeaderboardPDA,
+        })
+        .signers([member1])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "InvalidLeaderboard");
+    }
+
+    await program.methods
+      .checkIn()
+      .accounts({
+        memberWallet: member1.publicKey,
+        session: leaderboardSessionPDA,
+        pointMint,
+        leaderboard: leaderboardPDA,
+      })
+      .signers([member1])
+      .rpc();
+
+    const member1After = await program.account.member.fetch(member1PDA);
+    leaderboard = await program.methods
+      .getLeaderboard()
+      .accounts({
+        leaderboard: leaderboardPDA,
+      })
+      .view();
+    assert.equal(leaderboard.entries[0].wallet.toString(), member1.publicKey.toString());
+    assert.equal(leaderboard.entries[0].points.toNumber(), member1After.totalPoints.toNumber());
+    assert.isAbove(member1After.totalPoints.toNumber(), member1Before.totalPoints.toNumber());
+  });
+
+  it("A session series creates weekly sessions across several transactions", async () => {
+    const configBefore = await program.account.config.fetch(configPDA);
+    const utcOffset = configBefore.sessionTimeRules.utcOffset;
+    const now = Math.floor(Date.now() / 1000);
+    const localDay = Math.floor((now + utcOffset) / 86400);
+    const today = localDay * 86400 - utcOffset;
+    // Weekly on today's weekday at 18:00 local time with check-in from 17:00,
+    // the first occurrence is later today
+    const schedule = {
+      startDate: new anchor.BN(now),
+      weekday: (localDay + 3) % 7,
+      startTimeOfDay: 18 * 3600,
+      openWindow: 3600,
+      lateWindow: 1800,
+      occurrenceCount: 3,
+    };
+
+    const [seriesPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("series"), configBefore.seriesCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const sessionPDAs = [0, 1, 2].map((offset) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("session"), configBefore.sessionCount.addn(offset).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0]
+    );
+    const asRemaining = (pubkeys: anchor.web3.PublicKey[]) =>
+      pubkeys.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }));
+
+    await program.methods
+      .createSessionSeries("Weekly session", { regular: {} }, schedule)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    let series = await program.account.sessionSeries.fetch(seriesPDA);
+    assert.equal(series.firstDate.toNumber(), today);
+    assert.equal(series.nextOccurrence, 0);
+
+    // Sessions are created in batches, resuming from the series cursor
+    await program.methods
+      .createSeriesSessions()
+      .accounts({
+        authority: admin.publicKey,
+        series: seriesPDA,
+      })
+      .remainingAccounts(asRemaining(sessionPDAs.slice(0, 2)))
+      .signers([admin])
+      .rpc();
+
+    // Other session creators cannot create sessions hosted by the series admin
+    const teamLead = anchor.web3.Keypair.generate();
+    await program.methods
+      .initializeMember({ teamLead: {} })
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        memberWallet: teamLead.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+    try {
+      await program.methods
+        .createSeriesSessions()
+        .accounts({
+          authority: teamLead.publicKey,
+          series: seriesPDA,
+        })
+        .remainingAccounts(asRemaining(sessionPDAs.slice(2)))
+        .signers([teamLead])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "NotSessionHost");
+    }
+    await program.methods
+      .createSeriesSessions()
+      .accounts({
+        authority: admin.publicKey,
+        series: seriesPDA,
+      })
+      .remainingAccounts(asRemaining(sessionPDAs.slice(2)))
+      .signers([admin])
+      .rpc();
+
+    series = await program.account.sessionSeries.fetch(seriesPDA);
+    assert.equal(series.nextOccurrence, 3);
+    assert.deepEqual(
+      series.sessionIds.map((id) => id.toNumber()),
+      [0, 1, 2].map((offset) => configBefore.sessionCount.toNumber() + offset)
+    );
+
+    const lastSession = await program.account.session.fetch(sessionPDAs[2]);
+    assert.equal(lastSession.sessionDate.toNumber(), today + 14 * 86400);
+    assert.equal(lastSession.startTime.toNumber(), today + 14 * 86400 + 18 * 3600);
+    assert.equal(lastSession.openTime.toNumber(), lastSession.startTime.toNumber() - 3600);
+    assert.equal(lastSession.lateTime.toNumber(), lastSession.startTime.toNumber() + 1800);
+    assert.equal(lastSession.series.toString(), seriesPDA.toString());
+
+    try {
+      const [extraSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("session"), configBefore.sessionCount.addn(3).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createSeriesSessions()
+        .accounts({
+          authority: admin.publicKey,
+          series: seriesPDA,
+        })
+        .remainingAccounts(asRemaining([extraSessionPDA]))
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "SeriesComplete");
+    }
+
+    // Only the series admin or the super admin can reschedule, as for session creation
+    try {
+      await program.methods
+        .rescheduleSeries(19 * 3600, 3600)
+        .accounts({
+          authority: teamLead.publicKey,
+          series: seriesPDA,
+        })
+        .remainingAccounts(asRemaining(sessionPDAs))
+        .signers([teamLead])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "NotSessionHost");
+    }
+
+    // Check-in has started on the first session, so its times are kept
+    await program.methods
+      .setSessionOverride({ open: {} })
+      .accounts({
+        authority: admin.publicKey,
+        session: sessionPDAs[0],
+      })
+      .signers([admin])
+      .rpc();
+
+    // Rescheduling moves every session whose check-in has not started
+    await program.methods
+      .rescheduleSeries(19 * 3600, 3600)
+      .accounts({
+        authority: admin.publicKey,
+        series: seriesPDA,
+      })
+      .remainingAccounts(asRemaining(sessionPDAs))
+      .signers([admin])
+      .rpc();
+
+    const startedSession = await program.account.session.fetch(sessionPDAs[0]);
+    assert.equal(startedSession.startTime.toNumber(), today + 18 * 3600);
+    assert.equal(startedSession.openTime.toNumber(), today + 17 * 3600);
+    for (const [index, sessionPDA] of sessionPDAs.entries()) {
+      if (index === 0) {
+        continue;
+      }
+      const session = await program.account.session.fetch(sessionPDA);
+      assert.equal(session.startTime.toNumber(), today + index * 7 * 86400 + 19 * 3600);
+      assert.equal(session.lateTime.toNumber(), session.startTime.toNumber() + 3600);
+      assert.equal(session.openTime.toNumber(), session.startTime.toNumber() - 3600);
+    }
+  });
+
+  it("Session state follows the clock until overridden or cancelled", async () => {
+    const configBefore = await program.account.config.fetch(configPDA);
+    const [scheduledSessionPDA, cancelledSessionPDA] = [0, 1].map((offset) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("session"), configBefore.sessionCount.addn(offset).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0]
+    );
+    // Check-in opens at the session start, one hour from now
+    for (const title of ["Scheduled session", "Cancelled session"]) {
+      await program.methods
+        .initializeSession(title, { regular: {} }, sessionDate, startTime, startTime, lateTime)
+        .accounts({
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    }
+
+    const sessionState = async (session: anchor.web3.PublicKey) =>
+      (await program.methods.getSessionStats().accounts({ session }).view()).state;
+    const setOverride = (session: anchor.web3.PublicKey, stateOverride: any) =>
+      program.methods
+        .setSessionOverride(stateOverride)
+        .accounts({
+          authority: admin.publicKey,
+          session,
+        })
+        .signers([admin])
+        .rpc();
+    const checkIn = (session: anchor.web3.PublicKey) =>
+      program.methods
+        .checkIn()
+        .accounts({
+          memberWallet: member1.publicKey,
+          session,
+          pointMint,
+        })
+        .signers([member1])
+        .rpc();
+
+    assert.deepEqual(await sessionState(scheduledSessionPDA), { scheduled: {} });
+
+    // A manually closed session rejects check-ins regardless of the clock
+    await setOverride(scheduledSessionPDA, { closed: {} });
+    assert.deepEqual(await sessionState(scheduledSessionPDA), { closed: {} });
+    try {
+      await checkIn(scheduledSessionPDA);
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "SessionNotActive");
+    }
+
+    // A manually opened session accepts check-ins before its open time
+    await setOverride(scheduledSessionPDA, { open: {} });
+    assert.deepEqual(await sessionState(scheduledSessionPDA), { open: {} });
+    await checkIn(scheduledSessionPDA);
+
+    // The legacy toggle maps to Closed (inactive) and Auto (active)
+    for (const [isActive, stateOverride] of [
+      [false, { closed: {} }],
+      [true, { auto: {} }],
+    ] as [boolean, object][]) {
+      await program.methods
+        .updateSessionStatus(isActive)
+        .accounts({
+          authority: admin.publicKey,
+          session: scheduledSessionPDA,
+        })
+        .signers([admin])
+        .rpc();
+      const session = await program.account.session.fetch(scheduledSessionPDA);
+      assert.deepEqual(session.stateOverride, stateOverride);
+    }
+
+    await program.methods
+      .cancelSession()
+      .accounts({
+        authority: admin.publicKey,
+        session: cancelledSessionPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const cancelledSession = await program.account.session.fetch(cancelledSessionPDA);
+    assert.equal(cancelledSession.isCancelled, true);
+    assert.deepEqual(await sessionState(cancelledSessionPDA), { cancelled: {} });
+
+    for (const attempt of [
+      () => checkIn(cancelledSessionPDA),
+      () => setOverride(cancelledSessionPDA, { open: {} }),
+    ]) {
+      try {
+        await attempt();
+        assert.fail("Should have failed");
+      } catch (err) {
+        assert.include(err.toString(), "SessionCancelled");
+      }
+    }
+  });
+
+  it("Cancelling a session reverses attendance and refunds record rent", async () => {
+    const [leaderboardPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), anchor.web3.PublicKey.default.toBuffer()],
+      program.programId
+    );
+    const member1TokenAccount = getAssociatedTokenAddressSync(pointMint, member1.publicKey);
+    const configBefore = await program.account.config.fetch(configPDA);
+    const [cancelledSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), configBefore.sessionCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [recordPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("attendance"), cancelledSessionPDA.toBuffer(), member1.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeSession("Mistaken session", { regular: {} }, sessionDate, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const memberBefore = await program.account.member.fetch(member1PDA);
+    await program.methods
+      .checkIn()
+      .accounts({
+        memberWallet: member1.publicKey,
+        session: cancelledSessionPDA,
+        pointMint,
+        leaderboard: leaderboardPDA,
+      })
+      .signers([member1])
+      .rpc();
+
+    const record = await program.account.attendanceRecord.fetch(recordPDA);
+    assert.isNull(record.teamId);
+    const tokensBefore = await getAccount(provider.connection, member1TokenAccount);
+    const recordRent = await provider.connection.getBalance(recordPDA);
+    const walletBefore = await provider.connection.getBalance(member1.publicKey);
+
+    const closeSession = () =>
+      program.methods
+        .closeSession()
+        .accounts({
+          authority: admin.publicKey,
+          session: cancelledSessionPDA,
+          sessionAdmin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    // Only cancelled sessions can be closed
+    try {
+      await closeSession();
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "SessionNotCancelled");
+    }
+
+    // The first call cancels the session and closes the records passed with it
+    await program.methods
+      .cancelSession()
+      .accounts({
+        authority: admin.publicKey,
+        session: cancelledSessionPDA,
+        leaderboard: leaderboardPDA,
+      })
+      .remainingAccounts([
+        { pubkey: recordPDA, isSigner: false, isWritable: true },
+        { pubkey: member1PDA, isSigner: false, isWritable: true },
+        { pubkey: member1.publicKey, isSigner: false, isWritable: true },
+      ])
+      .signers([admin])
+      .rpc();
+
+    const session = await program.account.session.fetch(cancelledSessionPDA);
+    assert.equal(session.isCancelled, true);
+    assert.equal(session.totalAttendees, 0);
+
+    const memberAfter = await program.account.member.fetch(member1PDA);
+    assert.equal(memberAfter.totalAttendance, memberBefore.totalAttendance);
+    assert.equal(memberAfter.totalPoints.toNumber(), memberBefore.totalPoints.toNumber());
+
+    // Minted BAY stay in the wallet and are credited against future mints
+    const tokensAfter = await getAccount(provider.connection, member1TokenAccount);
+    assert.equal(tokensAfter.amount.toString(), tokensBefore.amount.toString());
+    assert.equal(
+      memberAfter.mintedCredit.toNumber(),
+      memberBefore.mintedCredit.toNumber() + record.pointsMinted.toNumber()
+    );
+
+    // The leaderboard passed with the records reflects the reverted points
+    const leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+    const entry = leaderboard.entries.find((e) => e.wallet.equals(member1.publicKey));
+    assert.equal(entry.points.toNumber(), memberBefore.totalPoints.toNumber());
+
+    assert.isNull(await program.account.attendanceRecord.fetchNullable(recordPDA));
+    assert.equal(
+      await provider.connection.getBalance(member1.publicKey),
+      walletBefore + recordRent
+    );
+
+    for (const attempt of [
+      () =>
+        program.methods
+          .checkIn()
+          .accounts({
+            memberWallet: member1.publicKey,
+            session: cancelledSessionPDA,
+            pointMint,
+          })
+          .signers([member1])
+          .rpc(),
+      () =>
+        program.methods
+          .finalizeSession(true)
+          .accounts({
+            authority: admin.publicKey,
+            session: cancelledSessionPDA,
+          })
+          .signers([admin])
+          .rpc(),
+    ]) {
+      try {
+        await attempt();
+        assert.fail("Should have failed");
+      } catch (err) {
+        assert.include(err.toString(), "SessionCancelled");
+      }
+    }
+
+    // With every record closed, the session account is closed and its rent returns to the session admin
+    const sessionRent = await provider.connection.getBalance(cancelledSessionPDA);
+    const adminBefore = await provider.connection.getBalance(admin.publicKey);
+    await closeSession();
+
+    assert.isNull(await program.account.session.fetchNullable(cancelledSessionPDA));
+    assert.equal(
+      await provider.connection.getBalance(admin.publicKey),
+      adminBefore + sessionRent
+    );
+  });
+
+  it("Records of a closed member are still closed when a session is cancelled", async () => {
+    const leaver = anchor.web3.Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(
+      leaver.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdrop);
+    const [leaverPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("member"), leaver.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeMember({ member: {} })
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        memberWallet: leaver.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const configBefore = await program.account.config.fetch(configPDA);
+    const [leaverSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), configBefore.sessionCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [recordPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("attendance"), leaverSessionPDA.toBuffer(), leaver.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeSession("Farewell session", { regular: {} }, sessionDate, openTime, startTime, lateTime)
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const [leaderboardPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), anchor.web3.PublicKey.default.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .checkIn()
+      .accounts({
+        memberWallet: leaver.publicKey,
+        session: leaverSessionPDA,
+        pointMint,
+        leaderboard: leaderboardPDA,
+      })
+      .signers([leaver])
+      .rpc();
+    let leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+    assert.isTrue(leaderboard.entries.some((e) => e.wallet.equals(leaver.publicKey)));
+
+    // The member leaves while the check-in record is still open
+    await program.methods
+      .setMemberActive(false)
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        member: leaverPDA,
+      })
+      .signers([admin])
+      .rpc();
+    await program.methods
+      .closeMember()
+      .accountsPartial({
+        authority: admin.publicKey,
+        admin: adminMemberPDA,
+        member: leaverPDA,
+        payer: admin.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: leaderboardPDA, isSigner: false, isWritable: true },
+      ])
+      .signers([admin])
+      .rpc();
+    assert.isNull(await program.account.member.fetchNullable(leaverPDA));
+
+    // The closed member is removed from the leaderboards passed with the instruction
+    leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+    assert.isFalse(leaderboard.entries.some((e) => e.wallet.equals(leaver.publicKey)));
+
+    // The empty member PDA is passed in the member slot and its stats are skipped
+    await program.methods
+      .cancelSession()
+      .accounts({
+        authority: admin.publicKey,
+        session: leaverSessionPDA,
+      })
+      .remainingAccounts([
+        { pubkey: recordPDA, isSigner: false, isWritable: true },
+        { pubkey: leaverPDA, isSigner: false, isWritable: true },
+        { pubkey: leaver.publicKey, isSigner: false, isWritable: true },
+      ])
+      .signers([admin])
+      .rpc();
+
+    assert.isNull(await program.account.attendanceRecord.fetchNullable(recordPDA));
+    const session = await program.account.session.fetch(leaverSessionPDA);
+    assert.equal(session.totalAttendees, 0);
+  });
+
+  it("Finalizing a session marks absentees and locks check-in", async () => {
+    const configBefore = await program.account.config.fetch(configPDA);
+    const [endedSessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), configBefore.sessionCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const recordPDAFor = (wallet: anchor.web3.PublicKey) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("attendance"), endedSessionPDA.toBuffer(), wallet.toBuffer()],
+        program.programId
+      )[0];
+    const finalize = (lock: boolean, members: [anchor.web3.PublicKey, anchor.web3.PublicKey][]) =>
+      program.methods
+        .finalizeSession(lock)
+        .accounts({
+          authority: admin.publicKey,
+          session: endedSessionPDA,
+        })
+        .remainingAccounts(
+          members.flatMap(([memberPDA, wallet]) => [
+            { pubkey: memberPDA, isSigner: false, isWritable: true },
+            { pubkey: recordPDAFor(wallet), isSigner: false, isWritable: true },
+          ])
+        )
+        .signers([admin])
+        .rpc();
+
+    // A short session that ends a few seconds from now
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .initializeSession(
+        "Short session",
+        { regular: {} },
+        new anchor.BN(now),
+        new anchor.BN(now - 60),
+        new anchor.BN(now + 2),
+        new anchor.BN(now + 4)
+      )
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .checkIn()
+      .accounts({
+        memberWallet: member1.publicKey,
+        session: endedSessionPDA,
+        pointMint,
+      })
+      .signers([member1])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 6000));
+
+    const member2Before = await program.account.member.fetch(member2PDA);
+    await finalize(false, [[member2PDA, member2.publicKey]]);
+
+    const absentRecord = await program.account.attendanceRecord.fetch(recordPDAFor(member2.publicKey));
+    assert.deepEqual(absentRecord.status, { absent: {} });
+    assert.equal(absentRecord.pointsEarned.toNumber(), 0);
+    assert.equal(absentRecord.payer.toString(), admin.publicKey.toString());
+
+    const member2After = await program.account.member.fetch(member2PDA);
+    assert.equal(member2After.totalAbsence, member2Before.totalAbsence + 1);
+
+    let session = await program.account.session.fetch(endedSessionPDA);
+    assert.equal(session.totalAttendees, 1);
+    assert.equal(session.totalAbsent, 1);
+    assert.equal(session.isFinalized, false);
+
+    // Members that already have a record cannot be marked absent again
+    for (const member of [
+      [member2PDA, member2.publicKey],
+      [member1PDA, member1.publicKey],
+    ] as [anchor.web3.PublicKey, anchor.web3.PublicKey][]) {
+      try {
+        await finalize(false, [member]);
+        assert.fail("Should have failed");
+      } catch (err) {
+        assert.include(err.toString(), "AlreadyCheckedIn");
+      }
+    }
+
+    // Approving an excuse for the absentee turns the record into an excused one
+    // and re-ranks the leaderboard passed with it
+    const [leaderboardPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), anchor.web3.PublicKey.default.toBuffer()],
+      program.programId
+    );
+    const [excuseRequestPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("excuse"), endedSessionPDA.toBuffer(), member2.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .submitExcuse(Array.from(Buffer.alloc(32, 9)))
+      .accounts({
+        memberWallet: member2.publicKey,
+        session: endedSessionPDA,
+      })
+      .signers([member2])
+      .rpc();
+    await program.methods
+      .approveExcuse()
+      .accounts({
+        authority: admin.publicKey,
+        session: endedSessionPDA,
+        excuseRequest: excuseRequestPDA,
+        leaderboard: leaderboardPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const excusedRecord = await program.account.attendanceRecord.fetch(recordPDAFor(member2.publicKey));
+    assert.deepEqual(excusedRecord.status, { excused: {} });
+    const member2Excused = await program.account.member.fetch(member2PDA);
+    const leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+    const entry = leaderboard.entries.find((e) => e.wallet.equals(member2.publicKey));
+    assert.isDefined(entry);
+    assert.equal(entry.points.toNumber(), member2Excused.totalPoints.toNumber());
+    assert.equal(entry.attendanceRateBps, 0);
+
+    await finalize(true, []);
+    session = await program.account.session.fetch(endedSessionPDA);
+    assert.equal(session.isFinalized, true);
+    assert.deepEqual(
+      (await program.methods.getSessionStats().accounts({ session: endedSessionPDA }).view()).state,
+      { finalized: {} }
+    );
+
+    // A locked session rejects check-ins and further finalization
+    try {
+      await program.methods
+        .checkIn()
+        .accounts({
+          memberWallet: admin.publicKey,
+          session: endedSessionPDA,
+          pointMint,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "SessionFinalized");
+    }
+
+    try {
+      await finalize(false, []);
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "SessionFinalized");
+    }
+
+    // Finalized results are locked, so the session can no longer be cancelled
+    try {
+      await program.methods
+        .cancelSession()
+        .accounts({
+          authority: admin.publicKey,
+          session: endedSessionPDA,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.fail("Should have failed");
+    } catch (err) {
+      assert.include(err.toString(), "SessionFinalized");
+    }
+  });
+
+  it("Get member stats", async () => {
+    const stats = await program.methods
+      .getMemberStats()
+      .accounts({
+        member: member1PDA,
+      })
+      .view();
+
+    const memberAccount = await program.account.member.fetch(member1PDA);
+    assert.equal(stats.wallet.toString(), member1.publicKey.toString());
+    assert.equal(stats.displayName, "bay01");
+    assert.equal(stats.totalAttendance, memberAccount.totalAttendance);
+    assert.equal(stats.totalPoints.toNumber(), memberAccount.totalPoints.toNumber());
+    assert.equal(stats.isActive, true);
+  });
+
+  it("Get session stats", async () => {
+    const stats = await program.methods
+      .getSessionStats()
+      .accounts({
+        session: sessionPDA,
+      })
+      .view();
+
+    const sessionAccount = await program.account.session.fetch(sessionPDA);
+    assert.equal(stats.sessionId.toNumber(), 0);
+    assert.equal(stats.totalAttendees, sessionAccount.totalAttendees);
+    assert.equal(stats.totalLate, sessionAccount.totalLate);
+    assert.equal(stats.onTimeRateBps, 0);
   });
 });
\ No newline at end of file
@@ -11,7 +11,7 @@ import {
   
   import { createUmi } from "@metaplex-foundation/umi-bundle-defaults";
   
-  import { Connection, LAMPORTS_PER_SOL, clusterApiUrl, Keypair } from "@solana/web3.js";
+  import { Connection, LAMPORTS_PER_SOL, clusterApiUrl, Keypair, PublicKey } from "@solana/web3.js";
   import {
     generateSigner,
     keypairIdentity,
@@ -26,6 +26,44 @@ import {
   const secretKeyArray = JSON.parse(fs.readFileSync("./baySMce3jxfnxTH1UivnFaDVXQRpTxziGU2YgnKFRNy.json", "utf8"));
   const user = Keypair.fromSecretKey(Uint8Array.from(secretKeyArray));
   
+  // BAY 출석 프로그램의 역할별 권한 중 NFT 발행 권한 (PERMISSION_MINT_NFTS)
+  const ATTENDANCE_PROGRAM_ID = new PublicKey("HW4UmSnJfLd8yn8afM3WGz2w52ea7i1oTGqCSAXJmwv5");
+  const PERMISSION_MINT_NFTS = 1 << 3;
+  
+  // 최고 관리자이거나 NFT 발행 권한이 있는 역할의 활성 학회원인지 확인
+  // (계정 레이아웃은 필드를 reserved 앞에만 추가하므로 앞쪽 필드 위치는 고정)
+  async function canMintNfts(wallet: PublicKey): Promise<boolean> {
+    const [configPDA] = PublicKey.findProgramAddressSync([Buffer.from("config")], ATTENDANCE_PROGRAM_ID);
+    const [memberPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("member"), wallet.toBuffer()],
+      ATTENDANCE_PROGRAM_ID
+    );
+    
+    // Config: discriminator(8) | super_admin(32) | session_count(8) | point_schedule(20) | role_permissions(u16 x 7)
+    const config = await connection.getAccountInfo(configPDA);
+    if (!config || !config.owner.equals(ATTENDANCE_PROGRAM_ID)) {
+      return false;
+    }
+    if (new PublicKey(config.data.subarray(8, 40)).equals(wallet)) {
+      return true;
+    }
+    
+    // Member: discriminator(8) | wallet(32) | role(1) | 출석 통계(u32 x 4) | total_points(8) | is_active(1)
+    const member = await connection.getAccountInfo(memberPDA);
+    if (!member || !member.owner.equals(ATTENDANCE_PROGRAM_ID)) {
+      return false;
+    }
+    const role = member.data[40];
+    const isActive = member.data[65] === 1;
+    const permissions = config.data.readUInt16LE(68 + role * 2);
+    return isActive && (permissions & PERMISSION_MINT_NFTS) !== 0;
+  }
+  
+  if (!(await canMintNfts(user.publicKey))) {
+    console.error("This wallet does not have the NFT minting permission in the BAY attendance program");
+    process.exit(1);
+  }
+  
   await airdropIfRequired(
     connection,
     user.publicKey,
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MarketplaceInitialized {
+    pub admin: Pubkey,
+    pub token_mint: Pubkey,
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct ProductAdded {
+    pub product: Pubkey,
+    pub product_id: u64,
+    pub name: String,
+    pub price: u64,
+    pub stock: u64,
+}
+
+#[event]
+pub struct ProductUpdated {
+    pub product: Pubkey,
+    pub product_id: u64,
+    pub price: u64,
+    pub stock: u64,
+}
+
+#[event]
+pub struct ProductDeactivated {
+    pub product: Pubkey,
+    pub product_id: u64,
+}
+
+#[event]
+pub struct Purchased {
+    pub purchase: Pubkey,
+    pub product_id: u64,
+    pub buyer: Pubkey,
+    pub quantity: u64,
+    pub total_price: u64,
+    pub remaining_stock: u64,
+    pub timestamp: i64,
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::state::*;
 use crate::errors::MarketplaceError;
+use crate::events::*;
 
 pub fn initialize_marketplace(
     ctx: Context<InitializeMarketplace>,
@@ -21,6 +22,12 @@ pub fn initialize_marketplace(
     marketplace.is_initialized = true;
     marketplace.bump = ctx.bumps.marketplace;
     
+    emit!(MarketplaceInitialized {
+        admin: marketplace.admin,
+        token_mint: marketplace.token_mint,
+        treasury: marketplace.treasury,
+    });
+    
     msg!("Marketplace initialized successfully");
     msg!("Admin: {}", marketplace.admin);
     msg!("Token Mint: {}", marketplace.token_mint);
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::MarketplaceError;
+use crate::events::*;
+use bay_attendance_check::state::{Config as AttendanceConfig, Member as AttendanceMember};
 
 pub fn add_product(
     ctx: Context<AddProduct>,
@@ -13,7 +15,11 @@ pub fn add_product(
     let product = &mut ctx.accounts.product;
     
     require!(
-        ctx.accounts.admin.key() == marketplace.admin,
+        marketplace.can_manage_products(
+            &ctx.accounts.admin.key(),
+            ctx.accounts.attendance_config.as_deref().map(|config| &**config),
+            ctx.accounts.attendance_member.as_deref().map(|member| &**member),
+        ),
         MarketplaceError::Unauthorized
     );
     
@@ -47,6 +53,14 @@ pub fn add_product(
     product.updated_at = Clock::get()?.unix_timestamp;
     product.bump = ctx.bumps.product;
     
+    emit!(ProductAdded {
+        product: product.key(),
+        product_id,
+        name: product.name.clone(),
+        price: product.price,
+        stock: product.stock,
+    });
+    
     msg!("Product added successfully");
     msg!("Product ID: {}", product_id);
     msg!("Name: {}", product.name);
@@ -65,7 +79,11 @@ pub fn update_product(
     let product = &mut ctx.accounts.product;
     
     require!(
-        ctx.accounts.admin.key() == marketplace.admin,
+        marketplace.can_manage_products(
+            &ctx.accounts.admin.key(),
+            ctx.accounts.attendance_config.as_deref().map(|config| &**config),
+            ctx.accounts.attendance_member.as_deref().map(|member| &**member),
+        ),
         MarketplaceError::Unauthorized
     );
     
@@ -84,6 +102,13 @@ pub fn update_product(
     
     product.updated_at = Clock::get()?.unix_timestamp;
     
+    emit!(ProductUpdated {
+        product: product.key(),
+        product_id: product.id,
+        price: product.price,
+        stock: product.stock,
+    });
+    
     msg!("Product updated successfully");
     
     Ok(())
@@ -94,13 +119,22 @@ pub fn deactivate_product(ctx: Context<DeactivateProduct>) -> Result<()> {
     let product = &mut ctx.accounts.product;
     
     require!(
-        ctx.accounts.admin.key() == marketplace.admin,
+        marketplace.can_manage_products(
+            &ctx.accounts.admin.key(),
+            ctx.accounts.attendance_config.as_deref().map(|config| &**config),
+            ctx.accounts.attendance_member.as_deref().map(|member| &**member),
+        ),
         MarketplaceError::Unauthorized
     );
     
     product.is_active = false;
     product.updated_at = Clock::get()?.unix_timestamp;
     
+    emit!(ProductDeactivated {
+        product: product.key(),
+        product_id: product.id,
+    });
+    
     msg!("Product deactivated successfully");
     msg!("Product ID: {}", product.id);
     
@@ -132,6 +166,22 @@ pub struct AddProduct<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     
+    // 출석 프로그램 설정 (선택, 전달 시 상품 관리 권한이 있는 학회원도 허용)
+    #[account(
+        seeds = [b"config"],
+        bump = attendance_config.bump,
+        seeds::program = bay_attendance_check::ID
+    )]
+    pub attendance_config: Option<Box<Account<'info, AttendanceConfig>>>,
+    
+    // 서명자의 출석 프로그램 학회원 계정 (선택)
+    #[account(
+        seeds = [b"member", admin.key().as_ref()],
+        bump = attendance_member.bump,
+        seeds::program = bay_attendance_check::ID
+    )]
+    pub attendance_member: Option<Box<Account<'info, AttendanceMember>>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -155,6 +205,22 @@ pub struct UpdateProduct<'info> {
     pub product: Account<'info, Product>,
     
     pub admin: Signer<'info>,
+    
+    // 출석 프로그램 설정 (선택, 전달 시 상품 관리 권한이 있는 학회원도 허용)
+    #[account(
+        seeds = [b"config"],
+        bump = attendance_config.bump,
+        seeds::program = bay_attendance_check::ID
+    )]
+    pub attendance_config: Option<Box<Account<'info, AttendanceConfig>>>,
+    
+    // 서명자의 출석 프로그램 학회원 계정 (선택)
+    #[account(
+        seeds = [b"member", admin.key().as_ref()],
+        bump = attendance_member.bump,
+        seeds::program = bay_attendance_check::ID
+    )]
+    pub attendance_member: Option<Box<Account<'info, AttendanceMember>>>,
 }
 
 #[derive(Accounts)]
@@ -177,4 +243,20 @@ pub struct DeactivateProduct<'info> {
     pub product: Account<'info, Product>,
     
     pub admin: Signer<'info>,
+    
+    // 출석 프로그램 설정 (선택, 전달 시 상품 관리 권한이 있는 학회원도 허용)
+    #[account(
+        seeds = [b"config"],
+        bump = attendance_config.bump,
+        seeds::program = bay_attendance_check::ID
+    )]
+    pub attendance_config: Option<Box<Account<'info, AttendanceConfig>>>,
+    
+    // 서명자의 출석 프로그램 학회원 계정 (선택)
+    #[account(
+        seeds = [b"member", admin.key().as_ref()],
+        bump = attendance_member.bump,
+        seeds::program = bay_attendance_check::ID
+    )]
+    pub attendance_member: Option<Box<Account<'info, AttendanceMember>>>,
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer};
 use crate::state::*;
 use crate::errors::MarketplaceError;
+use crate::events::*;
 
 pub fn purchase_product(
     ctx: Context<PurchaseProduct>,
@@ -61,6 +62,16 @@ pub fn purchase_product(
     purchase.timestamp = Clock::get()?.unix_timestamp;
     purchase.bump = ctx.bumps.purchase;
     
+    emit!(Purchased {
+        purchase: purchase.key(),
+        product_id,
+        buyer: purchase.buyer,
+        quantity,
+        total_price,
+        remaining_stock: product.stock,
+        timestamp: purchase.timestamp,
+    });
+    
     msg!("Purchase successful!");
     msg!("Product: {}", product.name);
     msg!("Quantity: {}", quantity);
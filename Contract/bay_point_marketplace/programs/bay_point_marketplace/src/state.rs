@@ -1,4 +1,9 @@
 use anchor_lang::prelude::*;
+use bay_attendance_check::state::{
+    Config as AttendanceConfig,
+    Member as AttendanceMember,
+    PERMISSION_MANAGE_PRODUCTS,
+};
 
 #[account]
 pub struct MarketplaceState {
@@ -47,6 +52,22 @@ impl MarketplaceState {
         8 +                      
         1 +                      
         1;                       
+    
+    // 마켓플레이스 관리자 또는 출석 프로그램에서 상품 관리 권한을 가진 학회원
+    // (출석 프로그램 Config/Member 계정 주소는 seeds로 검증)
+    pub fn can_manage_products(
+        &self,
+        authority: &Pubkey,
+        attendance_config: Option<&AttendanceConfig>,
+        attendance_member: Option<&AttendanceMember>,
+    ) -> bool {
+        if *authority == self.admin {
+            return true;
+        }
+        attendance_config.is_some_and(|config| {
+            config.has_permission(authority, attendance_member, PERMISSION_MANAGE_PRODUCTS)
+        })
+    }
 }
 
 impl Product {
@@ -13,6 +13,15 @@ function question(query: string): Promise<string> {
   return new Promise(resolve => rl.question(query, resolve));
 }
 
+// Attendance program whose role permissions (manage products) are accepted by the marketplace
+const ATTENDANCE_PROGRAM_ID = new PublicKey("HW4UmSnJfLd8yn8afM3WGz2w52ea7i1oTGqCSAXJmwv5");
+
+// Attendance Config/Member accounts passed when the wallet is not the marketplace admin
+let attendanceAccounts: { attendanceConfig: PublicKey | null; attendanceMember: PublicKey | null } = {
+  attendanceConfig: null,
+  attendanceMember: null,
+};
+
 async function main() {
   console.log("🛠️  BAY Point Marketplace Admin Panel");
   console.log("=====================================\n");
@@ -31,13 +40,29 @@ async function main() {
     const marketplaceAccount = await program.account.marketplaceState.fetch(marketplace);
     
     if (marketplaceAccount.admin.toString() !== provider.wallet.publicKey.toString()) {
-      console.error("❌ You are not the admin of this marketplace!");
-      console.log("Admin:", marketplaceAccount.admin.toString());
-      console.log("Your wallet:", provider.wallet.publicKey.toString());
-      process.exit(1);
+      const [attendanceConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        ATTENDANCE_PROGRAM_ID
+      );
+      const [attendanceMember] = PublicKey.findProgramAddressSync(
+        [Buffer.from("member"), provider.wallet.publicKey.toBuffer()],
+        ATTENDANCE_PROGRAM_ID
+      );
+      
+      if (!(await provider.connection.getAccountInfo(attendanceMember))) {
+        console.error("❌ You are not the admin of this marketplace!");
+        console.log("Admin:", marketplaceAccount.admin.toString());
+        console.log("Your wallet:", provider.wallet.publicKey.toString());
+        process.exit(1);
+      }
+      
+      // The program checks the manage-products permission of the member's role
+      attendanceAccounts = { attendanceConfig, attendanceMember };
+      console.log("✅ Using attendance member role permissions");
+    } else {
+      console.log("✅ Admin verified");
     }
     
-    console.log("✅ Admin verified");
     console.log("Current product count:", marketplaceAccount.productCount.toString());
     console.log("Total sales:", marketplaceAccount.totalSales.toString(), "points\n");
     
@@ -113,6 +138,7 @@ async function addProduct(
         marketplace,
         product,
         admin: program.provider.publicKey,
+        ...attendanceAccounts,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
@@ -166,6 +192,7 @@ async function updateProduct(
         marketplace,
         product,
         admin: program.provider.publicKey,
+        ...attendanceAccounts,
       })
       .rpc();
     
@@ -211,6 +238,7 @@ async function deactivateProduct(
           marketplace,
           product,
           admin: program.provider.publicKey,
+          ...attendanceAccounts,
         })
         .rpc();
       
@@ -1,7 +1,10 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { BayPointMarketplace } from "../target/types/bay_point_marketplace";
+import { BayAttendanceCheck } from "../../bay_attendance_check/target/types/bay_attendance_check";
+import attendanceIdl from "../../bay_attendance_check/target/idl/bay_attendance_check.json";
 import { 
+  createMint,
   getOrCreateAssociatedTokenAccount,
   getAccount,
   transfer,
@@ -126,6 +129,8 @@ describe("bay_point_marketplace", () => {
         marketplace,
         product,
         admin: admin.publicKey,
+        attendanceConfig: null,
+        attendanceMember: null,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([admin])
@@ -162,6 +167,8 @@ describe("bay_point_marketplace", () => {
         marketplace,
         product,
         admin: admin.publicKey,
+        attendanceConfig: null,
+        attendanceMember: null,
       })
       .signers([admin])
       .rpc();
@@ -260,6 +267,8 @@ describe("bay_point_marketplace", () => {
         marketplace,
         product,
         admin: admin.publicKey,
+        attendanceConfig: null,
+        attendanceMember: null,
       })
       .signers([admin])
       .rpc();
@@ -332,6 +341,8 @@ describe("bay_point_marketplace", () => {
           marketplace,
           product,
           admin: buyer.publicKey,
+          attendanceConfig: null,
+          attendanceMember: null,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([buyer])
@@ -342,4 +353,129 @@ describe("bay_point_marketplace", () => {
       assert.include(error.toString(), "Unauthorized");
     }
   });
+
+  describe("product managers from the attendance program", () => {
+    const attendanceProgram = new Program<BayAttendanceCheck>(attendanceIdl as BayAttendanceCheck, provider);
+    const [attendanceConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      attendanceProgram.programId
+    );
+    const attendanceMemberFor = (wallet: anchor.web3.PublicKey) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("member"), wallet.toBuffer()],
+        attendanceProgram.programId
+      )[0];
+    const nextProductPDA = async () => {
+      const marketplaceAccount = await program.account.marketplaceState.fetch(marketplace);
+      return anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("product"),
+          marketplace.toBuffer(),
+          marketplaceAccount.productCount.toArrayLike(Buffer, "le", 8)
+        ],
+        program.programId
+      )[0];
+    };
+    
+    // The president role has every permission by default, a plain member has none
+    const president = anchor.web3.Keypair.generate();
+    const plainMember = anchor.web3.Keypair.generate();
+    
+    before(async function () {
+      let config = await attendanceProgram.account.config.fetchNullable(attendanceConfig);
+      if (!config) {
+        // The provider wallet is the upgrade authority of the attendance program loaded at genesis
+        const [mintAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+          [Buffer.from("mint_authority")],
+          attendanceProgram.programId
+        );
+        const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+          [attendanceProgram.programId.toBuffer()],
+          new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+        );
+        const pointMint = await createMint(
+          provider.connection,
+          admin,
+          mintAuthority,
+          null,
+          6
+        );
+        await attendanceProgram.methods
+          .initializeConfig(provider.publicKey)
+          .accounts({
+            authority: provider.publicKey,
+            programData,
+            pointMint,
+          })
+          .rpc();
+        config = await attendanceProgram.account.config.fetch(attendanceConfig);
+      }
+      
+      // Members can only be registered by the attendance super admin
+      if (!config.superAdmin.equals(provider.publicKey)) {
+        this.skip();
+      }
+      
+      for (const [wallet, role] of [
+        [president, { president: {} }],
+        [plainMember, { member: {} }],
+      ] as [anchor.web3.Keypair, any][]) {
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(wallet.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+        );
+        await attendanceProgram.methods
+          .initializeMember(role)
+          .accountsPartial({
+            authority: provider.publicKey,
+            admin: null,
+            memberWallet: wallet.publicKey,
+          })
+          .rpc();
+      }
+    });
+    
+    it("Lets a member whose role can manage products add a product", async () => {
+      const product = await nextProductPDA();
+      
+      await program.methods
+        .addProduct("BAY Sticker", "Added by the club president", new anchor.BN(1000), new anchor.BN(50))
+        .accountsStrict({
+          marketplace,
+          product,
+          admin: president.publicKey,
+          attendanceConfig,
+          attendanceMember: attendanceMemberFor(president.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([president])
+        .rpc();
+      
+      const productAccount = await program.account.product.fetch(product);
+      assert.equal(productAccount.seller.toString(), president.publicKey.toString());
+      assert.equal(productAccount.isActive, true);
+    });
+    
+    it("Rejects a member whose role cannot manage products", async () => {
+      const product = await nextProductPDA();
+      
+      try {
+        await program.methods
+          .addProduct("Test Product", "Test Description", new anchor.BN(1000), new anchor.BN(50))
+          .accountsStrict({
+            marketplace,
+            product,
+            admin: plainMember.publicKey,
+            attendanceConfig,
+            attendanceMember: attendanceMemberFor(plainMember.publicKey),
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([plainMember])
+          .rpc();
+        
+        assert.fail("Should have failed when a plain member tries to add product");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+    });
+  });
 });
\ No newline at end of file
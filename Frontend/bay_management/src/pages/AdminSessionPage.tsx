@@ -3,18 +3,16 @@ import { Button } from '@/components/ui/button';
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
 import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
-import { Calendar, Clock, QrCode, Users, ExternalLink } from 'lucide-react';
+import { Calendar, Clock, QrCode, Users, ExternalLink, UserPlus } from 'lucide-react';
 import QRCode from 'qrcode';
 import { toast } from 'sonner';
 import { format } from 'date-fns';
 import { WalletGuard } from '@/components/WalletGuard';
 import { useWallet, useConnection } from '@solana/wallet-adapter-react';
-import { 
-  PublicKey, 
-  SystemProgram
-} from '@solana/web3.js';
-import { BN, AnchorProvider, Program } from '@project-serum/anchor';
-import { IDL, BayAttendanceCheck } from '@/types/program-types';
+import { PublicKey } from '@solana/web3.js';
+import { BN, AnchorProvider, Program } from '@coral-xyz/anchor';
+import type { BayAttendanceCheck } from '@/types/program-types';
+import IDL from '@/utils/attendance_dapp.json';
 
 export function AdminSessionPage() {
   return (
@@ -29,12 +27,17 @@ const PROGRAM_ID = new PublicKey(
   import.meta.env.VITE_PROGRAM_ID || 'HW4UmSnJfLd8yn8afM3WGz2w52ea7i1oTGqCSAXJmwv5'
 );
 
+// 환경 변수의 프로그램 ID로 배포된 프로그램을 사용
+const programFor = (provider: AnchorProvider) =>
+  new Program<BayAttendanceCheck>({ ...IDL, address: PROGRAM_ID.toBase58() } as BayAttendanceCheck, provider);
+
 
 function AdminSessionContent() {
   const { publicKey, wallet, sendTransaction } = useWallet();
   const { connection } = useConnection();
   const [sessionData, setSessionData] = useState({
     date: format(new Date(), 'yyyy-MM-dd'),
+    openTime: '18:30',
     startTime: '19:00',
     lateTime: '19:30',
     location: 'BAY 세미나실',
@@ -78,6 +81,7 @@ function AdminSessionContent() {
       
       // 세션 시간 검증 및 준비
       const sessionDate = new Date(sessionData.date);
+      const openDateTime = new Date(`${sessionData.date}T${sessionData.openTime}`);
       const startDateTime = new Date(`${sessionData.date}T${sessionData.startTime}`);
       const lateDateTime = new Date(`${sessionData.date}T${sessionData.lateTime}`);
       
@@ -90,6 +94,13 @@ function AdminSessionContent() {
       });
 
       // 시간 검증
+      if (openDateTime > startDateTime) {
+        console.error('❌ 시간 검증 실패: 체크인 시작 시간 > 시작 시간');
+        toast.error('체크인 시작 시간은 세션 시작 시간보다 늦을 수 없습니다.');
+        setIsGenerating(false);
+        return;
+      }
+      
       if (startDateTime >= lateDateTime) {
         console.error('❌ 시간 검증 실패: 시작 시간 >= 지각 시간');
         toast.error('시작 시간은 지각 기준 시간보다 이전이어야 합니다.');
@@ -110,6 +121,7 @@ function AdminSessionContent() {
       
       // Unix 타임스탬프 변환
       const sessionDateUnix = Math.floor(sessionDate.setHours(0, 0, 0, 0) / 1000);
+      const openTimeUnix = Math.floor(openDateTime.getTime() / 1000);
       const startTimeUnix = Math.floor(startDateTime.getTime() / 1000);
       const lateTimeUnix = Math.floor(lateDateTime.getTime() / 1000);
       
@@ -118,6 +130,7 @@ function AdminSessionContent() {
         startTime: sessionData.startTime,
         lateTime: sessionData.lateTime,
         sessionDateUnix,
+        openTimeUnix,
         startTimeUnix,
         lateTimeUnix,
         validation: {
@@ -126,24 +139,11 @@ function AdminSessionContent() {
         }
       });
 
-      // PDA 계산 (테스트 파일과 동일한 방식)
-      const sessionDateBN = new BN(sessionDateUnix);
-      const sessionDateBytes = sessionDateBN.toArrayLike(Buffer, 'le', 8);
-
-      const [sessionPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from('session'), sessionDateBytes],
-        PROGRAM_ID
-      );
-
+      // 운영진 Member PDA (세션 생성 권한 확인용)
       const [adminMemberPDA] = PublicKey.findProgramAddressSync(
         [Buffer.from('member'), publicKey.toBuffer()],
         PROGRAM_ID
       );
-      
-      console.log('PDA 생성:', {
-        sessionPDA: sessionPDA.toBase58(),
-        adminMemberPDA: adminMemberPDA.toBase58()
-      });
 
       // 세션 정보를 위한 고유 메모 생성
       const randomSuffix = Math.random().toString(36).substring(2, 15);
@@ -155,75 +155,52 @@ function AdminSessionContent() {
       const provider = new AnchorProvider(connection, wallet.adapter as any, {
         commitment: 'confirmed',
       });
-      const program = new Program<BayAttendanceCheck>(IDL, PROGRAM_ID, provider);
+      const program = programFor(provider);
       
       console.log('Anchor 프로그램 설정 완료');
       
-      // Admin member 등록 확인 및 초기화
+      // 운영진 학회원 등록 확인 (학회원 등록은 아래 학회원 등록 카드에서 진행)
       console.log('🔍 Admin member 상태 확인...');
-      let adminMemberExists = false;
-      
-      try {
-        const adminMemberAccount = await program.account.member.fetchNullable(adminMemberPDA);
-        
-        if (!adminMemberAccount) {
-          console.log('❌ Admin member 미등록 - 초기화 진행');
-          toast.info('Admin 계정을 초기화합니다...', { duration: 2000 });
-          
-          // Admin member 초기화 트랜잭션 생성
-          const initMemberTx = await program.methods
-            .initializeMember({ admin: {} }) // MemberRole::Admin 설정
-            .accounts({
-              authority: publicKey,      // 트랜잭션 서명자 (fee payer)
-              admin: publicKey,          // Admin 권한 확인용 (자기 자신)
-              memberWallet: publicKey,   // 등록할 member의 지갑 주소
-              member: adminMemberPDA,    // 생성될 Member PDA
-              systemProgram: SystemProgram.programId,
-            })
-            .transaction();
-          
-          // 블록해시 설정
-          const { blockhash: initBlockhash } = await connection.getLatestBlockhash('confirmed');
-          initMemberTx.recentBlockhash = initBlockhash;
-          initMemberTx.feePayer = publicKey;
-          
-          // Admin member 초기화 실행
-          const initSignature = await sendTransaction(
-            initMemberTx,
-            connection,
-            { skipPreflight: false }
-          );
-          
-          console.log('✅ Admin member 초기화 완료:', initSignature);
-          toast.success('Admin 계정이 초기화되었습니다!');
-          
-          // 트랜잭션 확인 대기
-          await new Promise(resolve => setTimeout(resolve, 3000));
-          adminMemberExists = true;
-          
-        } else {
-          console.log('✅ Admin member 이미 등록됨:', {
-            wallet: adminMemberAccount.wallet.toString(),
-            role: adminMemberAccount.role,
-            isActive: adminMemberAccount.isActive
-          });
-          adminMemberExists = true;
-        }
-      } catch (error) {
-        console.error('❌ Admin member 확인/초기화 실패:', error);
-        toast.error('Admin 계정 확인에 실패했습니다.');
-        throw error;
+      const adminMemberAccount = await program.account.member.fetchNullable(adminMemberPDA);
+      if (!adminMemberAccount) {
+        console.log('❌ Admin member 미등록');
+        toast.error('학회원으로 등록되지 않은 지갑입니다.', {
+          description: '최고 관리자나 학회원 관리 권한이 있는 운영진에게 등록을 요청해주세요.',
+          duration: 8000
+        });
+        setIsGenerating(false);
+        return;
       }
       
-      if (!adminMemberExists) {
-        throw new Error('Admin member 초기화에 실패했습니다.');
-      }
+      console.log('✅ Admin member 확인:', {
+        wallet: adminMemberAccount.wallet.toString(),
+        role: adminMemberAccount.role,
+        isActive: adminMemberAccount.isActive
+      });
+      
+      // Session PDA는 Config의 세션 카운터로 계산 (테스트 파일과 동일한 방식)
+      const [configPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from('config')],
+        PROGRAM_ID
+      );
+      const config = await program.account.config.fetch(configPDA);
+      const [sessionPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from('session'), config.sessionCount.toArrayLike(Buffer, 'le', 8)],
+        PROGRAM_ID
+      );
+      
+      console.log('PDA 생성:', {
+        sessionPDA: sessionPDA.toBase58(),
+        adminMemberPDA: adminMemberPDA.toBase58()
+      });
       
       // 세션 생성 준비
       console.log('📅 세션 생성 시작...');
       toast.info('세션을 블록체인에 생성하고 있습니다...', { duration: 3000 });
       
       // BN 변환 (가이드에 따른 정확한 타임스탬프)
+      const sessionDateBN = new BN(sessionDateUnix);
+      const openTimeBN = new BN(openTimeUnix);
       const startTimeBN = new BN(startTimeUnix);
       const lateTimeBN = new BN(lateTimeUnix);
       
@@ -235,6 +212,7 @@ function AdminSessionContent() {
         },
         timestamps: {
           sessionDateBN: sessionDateBN.toString(),
+          openTimeBN: openTimeBN.toString(),
           startTimeBN: startTimeBN.toString(),
           lateTimeBN: lateTimeBN.toString()
         },
@@ -244,17 +222,22 @@ function AdminSessionContent() {
         }
       });
       
-      // 세션 중복 생성 확인
+      // 세션 중복 생성 확인 (같은 날짜, 같은 제목의 취소되지 않은 세션)
       try {
-        const existingSession = await program.account.session.fetchNullable(sessionPDA);
-        if (existingSession) {
-          console.log('⚠️ 동일한 날짜의 세션이 이미 존재함 - 기존 세션으로 QR 생성:', existingSession);
+        const existing = (await program.account.session.all()).find(({ account }) =>
+          account.sessionDate.toNumber() === sessionDateUnix &&
+          account.title === sessionData.title &&
+          !account.isCancelled
+        );
+        if (existing) {
+          const existingSessionPDA = existing.publicKey;
+          console.log('⚠️ 동일한 날짜와 제목의 세션이 이미 존재함 - 기존 세션으로 QR 생성:', existing.account);
           toast.info(`${sessionData.date} 날짜의 세션이 이미 존재합니다. 기존 세션의 QR 코드를 생성합니다.`);
           
           // 기존 세션의 QR 코드 생성 (모바일 딥링크 포함)
           const baseUrl = window.location.origin;
           const checkInParams = new URLSearchParams({
-            sessionPDA: sessionPDA.toBase58(),
+            sessionPDA: existingSessionPDA.toBase58(),
             sessionDate: sessionDateUnix.toString(),
             title: sessionData.title,
             location: sessionData.location
@@ -268,7 +251,7 @@ function AdminSessionContent() {
           
           console.log('🎯 생성된 체크인 URL (Phantom용):', checkInUrl);
           console.log('📋 URL 파라미터:', {
-            sessionPDA: sessionPDA.toBase58(),
+            sessionPDA: existingSessionPDA.toBase58(),
             sessionDate: sessionDateUnix.toString(),
             title: sessionData.title,
             location: sessionData.location
@@ -298,12 +281,20 @@ function AdminSessionContent() {
       // 세션 초기화 트랜잭션 생성 (가이드 참조)
       console.log('🚀 initializeSession 트랜잭션 생성...');
       const transaction = await program.methods
-        .initializeSession(sessionDateBN, startTimeBN, lateTimeBN)
-        .accounts({
+        .initializeSession(
+          sessionData.title,
+          { regular: {} },
+          sessionDateBN,
+          openTimeBN,
+          startTimeBN,
+          lateTimeBN
+        )
+        .accountsPartial({
           authority: publicKey,        // Admin 지갑 (서명자)
           admin: adminMemberPDA,      // Admin Member PDA (권한 확인용)
+          config: configPDA,          // 세션 카운터 증가
+          term: null,                 // 학기에 속하지 않는 세션
           session: sessionPDA,        // 생성될 Session PDA
-          systemProgram: SystemProgram.programId,
         })
         .transaction();
 
@@ -530,7 +521,16 @@ function AdminSessionContent() {
               />
             </div>
 
-            <div className="grid grid-cols-2 gap-4">
+            <div className="grid grid-cols-3 gap-4">
+              <div className="space-y-2">
+                <Label htmlFor="openTime">체크인 시작</Label>
+                <Input
+                  id="openTime"
+                  type="time"
+                  value={sessionData.openTime}
+                  onChange={(e) => setSessionData({ ...sessionData, openTime: e.target.value })}
+                />
+              </div>
               <div className="space-y-2">
                 <Label htmlFor="startTime">시작 시간</Label>
                 <Input
@@ -622,6 +622,8 @@ function AdminSessionContent() {
         </Card>
       </div>
 
+      <RegisterMember />
+
       <CreatedSessions />
 
       <Card className="mt-6">
@@ -633,10 +635,11 @@ function AdminSessionContent() {
         </CardHeader>
         <CardContent>
           <ol className="list-decimal list-inside space-y-2 text-sm">
-            <li><strong>시간 설정:</strong> 시작 시간은 지각 기준 시간보다 이전이어야 하며, 현재 시간보다 미래여야 합니다</li>
-            <li><strong>Admin 권한:</strong> 처음 사용 시 Admin 계정이 자동으로 초기화됩니다</li>
+            <li><strong>시간 설정:</strong> 체크인 시작, 시작, 지각 기준 시간이 모두 세션 날짜 안에 있어야 하며, 지각 기준 시간이 현재 시간보다 미래여야 합니다</li>
+            <li><strong>운영진 권한:</strong> 세션 생성 권한이 있는 학회원으로 등록된 지갑만 세션을 만들 수 있습니다</li>
+            <li><strong>학회원 등록:</strong> 학회원은 직접 등록할 수 없으며, 학회원 관리 권한이 있는 운영진이 지갑 주소로 등록합니다</li>
             <li><strong>세션 생성:</strong> QR 코드 생성 버튼을 클릭하면 솔라나 블록체인 트랜잭션이 실행됩니다</li>
-            <li><strong>중복 방지:</strong> 동일한 날짜에는 하나의 세션만 생성 가능합니다</li>
+            <li><strong>중복 방지:</strong> 같은 날짜와 제목의 세션이 있으면 기존 세션의 QR 코드를 다시 생성합니다</li>
             <li><strong>블록체인 기록:</strong> 세션 정보가 영구적으로 솔라나 devnet에 저장됩니다</li>
             <li><strong>QR 코드:</strong> 생성된 QR 코드에는 세션 PDA 주소가 포함되어 실제 출석체크가 가능합니다</li>
             <li><strong>출석체크:</strong> 학회원들이 QR을 스캔하면 블록체인에서 직접 출석 기록이 생성됩니다</li>
@@ -653,6 +656,167 @@ function AdminSessionContent() {
   );
 }
 
+// 운영진이 학회원 지갑을 등록 (학회원 관리 권한 필요, 자신보다 강한 역할은 부여 불가)
+const MEMBER_ROLES = [
+  { value: 'member', label: '일반 학회원' },
+  { value: 'probationary', label: '수습 학회원' },
+  { value: 'teamLead', label: '팀장' },
+  { value: 'trackLead', label: '트랙장' },
+  { value: 'president', label: '회장' },
+  { value: 'admin', label: '운영진' },
+  { value: 'alumni', label: '수료생' },
+] as const;
+
+type MemberRoleValue = typeof MEMBER_ROLES[number]['value'];
+
+function RegisterMember() {
+  const { publicKey, wallet, sendTransaction } = useWallet();
+  const { connection } = useConnection();
+  const [memberWallet, setMemberWallet] = useState('');
+  const [role, setRole] = useState<MemberRoleValue>('member');
+  const [isRegistering, setIsRegistering] = useState(false);
+
+  const registerMember = async () => {
+    if (!publicKey || !wallet) {
+      toast.error('지갑이 연결되지 않았습니다.');
+      return;
+    }
+
+    let memberWalletKey: PublicKey;
+    try {
+      memberWalletKey = new PublicKey(memberWallet.trim());
+    } catch {
+      toast.error('올바른 지갑 주소를 입력해주세요.');
+      return;
+    }
+
+    setIsRegistering(true);
+    try {
+      const provider = new AnchorProvider(connection, wallet.adapter as any, {
+        commitment: 'confirmed',
+      });
+      const program = programFor(provider);
+
+      const [memberPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from('member'), memberWalletKey.toBuffer()],
+        PROGRAM_ID
+      );
+      if (await program.account.member.fetchNullable(memberPDA)) {
+        toast.info('이미 등록된 학회원입니다.');
+        return;
+      }
+
+      // 최고 관리자가 아니면 등록하는 운영진의 Member PDA로 권한 확인
+      const [configPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from('config')],
+        PROGRAM_ID
+      );
+      const config = await program.account.config.fetch(configPDA);
+      const [adminMemberPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from('member'), publicKey.toBuffer()],
+        PROGRAM_ID
+      );
+
+      const transaction = await program.methods
+        .initializeMember({ [role]: {} } as any)
+        .accountsPartial({
+          authority: publicKey,
+          admin: config.superAdmin.equals(publicKey) ? null : adminMemberPDA,
+          memberWallet: memberWalletKey,
+        })
+        .transaction();
+
+      const { blockhash } = await connection.getLatestBlockhash('confirmed');
+      transaction.recentBlockhash = blockhash;
+      transaction.feePayer = publicKey;
+
+      const signature = await sendTransaction(transaction, connection, {
+        skipPreflight: false,
+        preflightCommitment: 'confirmed'
+      });
+      await connection.confirmTransaction(signature, 'confirmed');
+
+      console.log('✅ 학회원 등록 완료:', signature);
+      toast.success('학회원이 등록되었습니다!', {
+        description: memberWalletKey.toBase58(),
+        action: {
+          label: "Solscan에서 보기",
+          onClick: () => window.open(`https://solscan.io/tx/${signature}?cluster=devnet`, '_blank')
+        }
+      });
+      setMemberWallet('');
+    } catch (error: any) {
+      console.error('학회원 등록 에러:', error);
+      if (error?.message?.includes('User rejected')) {
+        toast.error('트랜잭션이 거부되었습니다.');
+      } else if (error?.message?.includes('Unauthorized') || error?.message?.includes('AccountNotInitialized')) {
+        toast.error('학회원 등록 권한이 없습니다.', {
+          description: '학회원 관리 권한이 있는 운영진만 등록할 수 있으며, 자신보다 강한 역할은 부여할 수 없습니다.',
+          duration: 8000
+        });
+      } else {
+        toast.error(`학회원 등록 중 오류가 발생했습니다: ${error?.message ?? error}`);
+      }
+    } finally {
+      setIsRegistering(false);
+    }
+  };
+
+  return (
+    <Card className="mt-6">
+      <CardHeader>
+        <CardTitle className="flex items-center gap-2">
+          <UserPlus className="h-5 w-5" />
+          학회원 등록
+        </CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="space-y-2">
+          <Label htmlFor="memberWallet">학회원 지갑 주소</Label>
+          <Input
+            id="memberWallet"
+            value={memberWallet}
+            onChange={(e) => setMemberWallet(e.target.value)}
+            placeholder="예: bay1..."
+          />
+        </div>
+
+        <div className="space-y-2">
+          <Label htmlFor="memberRole">역할</Label>
+          <select
+            id="memberRole"
+            value={role}
+            onChange={(e) => setRole(e.target.value as MemberRoleValue)}
+            className="flex h-9 w-full rounded-md border border-input bg-transparent px-3 py-1 text-sm shadow-xs"
+          >
+            {MEMBER_ROLES.map(({ value, label }) => (
+              <option key={value} value={value}>{label}</option>
+            ))}
+          </select>
+        </div>
+
+        <Button
+          onClick={registerMember}
+          className="w-full"
+          disabled={isRegistering || !memberWallet.trim()}
+        >
+          {isRegistering ? (
+            <>
+              <div className="animate-spin rounded-full h-4 w-4 border-b-2 border-white mr-2"></div>
+              등록 중...
+            </>
+          ) : (
+            <>
+              <UserPlus className="h-4 w-4 mr-2" />
+              학회원 등록
+            </>
+          )}
+        </Button>
+      </CardContent>
+    </Card>
+  );
+}
+
 interface SessionRecord {
   pubkey: string;
   date: string;
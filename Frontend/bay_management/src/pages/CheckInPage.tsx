@@ -5,9 +5,10 @@ import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
 import { Badge } from '@/components/ui/badge';
 import { CheckCircle, Clock, XCircle, MapPin, Calendar } from 'lucide-react';
 import { useWallet, useConnection } from '@solana/wallet-adapter-react';
-import { PublicKey, SystemProgram } from '@solana/web3.js';
-import { BN, AnchorProvider, Program } from '@project-serum/anchor';
-import { IDL, BayAttendanceCheck } from '@/types/program-types';
+import { PublicKey } from '@solana/web3.js';
+import { BN, AnchorProvider, Program } from '@coral-xyz/anchor';
+import type { BayAttendanceCheck } from '@/types/program-types';
+import IDL from '@/utils/attendance_dapp.json';
 import { toast } from 'sonner';
 import { format } from 'date-fns';
 import { ko } from 'date-fns/locale';
@@ -30,50 +31,9 @@ const PROGRAM_ID = new PublicKey(
   import.meta.env.VITE_PROGRAM_ID || 'HW4UmSnJfLd8yn8afM3WGz2w52ea7i1oTGqCSAXJmwv5'
 );
 
-// 사전 등록된 회원 지갑 주소 리스트 (테스트용)
-// TODO: 프로덕션에서는 블록체인에서 직접 확인하도록 변경 필요
-const ALLOWED_WALLETS = [
-  // Admin
-  'bay1aCfaEwELREDGtadKov2S9CbkSHwLiBmtTo7Mp4u',
-  // BAY 회원들
-  'bay12mZK31mjZmRAC83Yk7DoRgVKJKXpMiqQxxTkppT',
-  'bay14PmhGaanQ3EMXbssPYFdcgngNYR9gHwYCQH7F9d',
-  'bay1871yDJ8SVg7RUTPZTEWRN6HtHQ6uQ9GjtFrZPGa',
-  'bay18x2WNXQvfu4FbWvCAg7cxwqqAy6uy5k2RZjxjgD',
-  'bay1CYCndJWMBz77mkoitLnVEnjdLuitkmKT53XDH6p',
-  'bay1E58uRJuJ8BW7U2ZEZhytzRixZBnJhdf4dhqLi7M',
-  'bay1EE3XY5A4exGRA2Z1HqaK5fKudkxxasTrLtQ5iEw',
-  'bay1EKFc3aGgvrg5Rjt6R3Ntonk3evEz8JF3eauV9pY',
-  'bay1FCpSNEmAZRRRM6ccGV8x7HttgWfMLbgb8ptV1Ve',
-  'bay1Fm2zkeN5dBifSsLJGBqGZXMeepGYmALsEiijx1a',
-  'bay1HJbcXvWLjnezNkTBMXBmF7jTeYT1oUDLHy9MQiB',
-  'bay1Hfxx4cAyQJZe8kiLpzeBsbZNczefVm8TCBXab98',
-  'bay1MMHv4Uq6dErugnQNAFKEtbprKJwssXvuK4NLfyL',
-  'bay1N7U6CCRMhXbWuxh7E7rrGAdYRdop9o9iv2WbmEZ',
-  'bay1NukeEffF7kRWwciPZVA5DrXe4r2YRQ2mDKTGv75',
-  'bay1P8SyXP9BHaZb7wbJiNr2S5VpjkBkUKBFGANptXf',
-  'bay1QFzcWZ94CYnh28Y2Hygjf5BDZenuzH5orWKQWMB',
-  'bay1QwJazB126YZbGYd7e9jUf96wsNH9T5m3H7oHcb2',
-  'bay1V5PpogfQnKa9mJmDP6rFw5V7TnqedrTCgaAXBnh',
-  'bay1bU9co4YDEG9UWFsc56ksK83Jb8iFTjr2NkttnoT',
-  'bay1bY4ii1giYXA4mxL2wMCKg7S24ka9zMUehk1BbdU',
-  'bay1d3MACAgFdG2wYKpWAX1cTFYYgiJJrgyiebec5fJ',
-  'bay1dasSunC4vQZEFJdj4XVm8u1Bn2AYyw8dX116Uuk',
-  'bay1dkNpQis6K3iFBzHUohrsnrRo8vLyPJAphn13zrt',
-  'bay1enJFFzBi71PfwiyD46xA6mYxqGKjWckhVKBb6z2',
-  'bay1f89EAk6YCrzYw7f8NvtRghiuPj3FNnrJVLpQWA4',
-  'bay1gskxjFCeZczAMTmU4eaDqE1UTpR3sghTUwVCkWg',
-  'bay1hacbChobr1M95VPoiVFA8nL4xWjXY5UC23vPGYD',
-  'bay1kUiBmtN6hv2daT5wiPwoa6a3sjfQ2kzDCGj1Pep',
-  'bay1s6QF5rmc1t9aziBofxuDyh76B2E2kK3obNHYZaE',
-  'bay1sPwV6x4odMYVVHVq1b7nFu3TxhJuyG1mmwfq8sx',
-  'bay1tQbmyNWXaGJciowsMazuFewJtb59yYPv1qwTTtW',
-  'bay1vDJLriB5Ebxp5jLrm2tGzR7nH4g84fW1pncAngg',
-  'bay1vYY6XV9BVWHiu6NHrWhoQzpjch6wKT4LDu8qPQu',
-  'bay1xJgrti3YNe86cZ2UKkN6dvsuErF6u7wZVDM9rxJ',
-  'bay1zQcPCGronVj92A9YSCeBmbv4y6ryrLi7Q2qFGXJ',
-  'bay1zxJeiV2hJxZFBYph2YPD1mjb25gGDXuP8mfisms',
-];
+// 환경 변수의 프로그램 ID로 배포된 프로그램을 사용
+const programFor = (provider: AnchorProvider) =>
+  new Program<BayAttendanceCheck>({ ...IDL, address: PROGRAM_ID.toBase58() } as BayAttendanceCheck, provider);
 
 function CheckInContent() {
   const [searchParams] = useSearchParams();
@@ -121,7 +81,7 @@ function CheckInContent() {
         const provider = new AnchorProvider(connection, wallet.adapter as any, {
           commitment: 'confirmed',
         });
-        const program = new Program<BayAttendanceCheck>(IDL, PROGRAM_ID, provider);
+        const program = programFor(provider);
         
         const sessionPubkey = new PublicKey(sessionPDA);
         const session = await program.account.session.fetch(sessionPubkey);
@@ -171,20 +131,6 @@ function CheckInContent() {
       return;
     }
 
-    // 하드코딩된 화이트리스트 체크 (테스트용)
-    if (ALLOWED_WALLETS.length > 0 && !ALLOWED_WALLETS.includes(publicKey.toBase58())) {
-      console.log('❌ 허용되지 않은 지갑:', publicKey.toBase58());
-      toast.error('허용되지 않은 지갑 주소입니다.', {
-        description: 'BAY 학회에 등록된 회원만 이용 가능합니다. 운영진에게 문의해주세요.',
-        duration: 5000
-      });
-      setStatus('failed');
-      setIsProcessing(false);
-      return;
-    }
-
-    console.log('✅ 화이트리스트 통과:', publicKey.toBase58());
-
     setIsProcessing(true);
     setStatus('checking');
 
@@ -193,7 +139,7 @@ function CheckInContent() {
       const provider = new AnchorProvider(connection, wallet.adapter as any, {
         commitment: 'confirmed',
       });
-      const program = new Program<BayAttendanceCheck>(IDL, PROGRAM_ID, provider);
+      const program = programFor(provider);
       
       // PDA 계산
       const sessionPubkey = new PublicKey(sessionPDA);
@@ -233,61 +179,13 @@ function CheckInContent() {
       // 중복 체크인 확인 (출석 기록은 checkIn 메서드 내부에서 확인됨)
       // 프로그램이 자동으로 중복 체크를 수행하므로 여기서는 스킵
       
-      // Member 등록 확인 및 자동 등록 (화이트리스트 통과한 경우)
+      // Member 등록 확인 (학회원 등록은 운영진이 세션 관리 페이지에서 진행)
+      let memberAccount;
       try {
-        const memberAccount = await program.account.member.fetchNullable(memberPDA);
-        if (!memberAccount) {
-          console.log('❌ 블록체인에 미등록 회원 - 자동 등록 진행');
-          toast.info('회원 등록을 진행합니다...', { duration: 3000 });
-          
-          // 화이트리스트 통과한 회원을 자동으로 Member 등록
-          const initMemberTx = await program.methods
-            .initializeMember({ member: {} }) // MemberRole::Member
-            .accounts({
-              authority: publicKey,
-              admin: publicKey, // 임시로 자기 자신을 admin으로 (실제로는 권한 체크 안함)
-              memberWallet: publicKey,
-              member: memberPDA,
-              systemProgram: SystemProgram.programId,
-            })
-            .transaction();
-          
-          const { blockhash: initBlockhash } = await connection.getLatestBlockhash('confirmed');
-          initMemberTx.recentBlockhash = initBlockhash;
-          initMemberTx.feePayer = publicKey;
-          
-          const initSignature = await sendTransaction(
-            initMemberTx,
-            connection,
-            { skipPreflight: false }
-          );
-          
-          console.log('✅ Member 자동 등록 완료:', initSignature);
-          toast.success('회원 등록이 완료되었습니다!');
-          
-          // 트랜잭션 확인 대기
-          await new Promise(resolve => setTimeout(resolve, 3000));
-          
-        } else {
-          console.log('✅ 블록체인 등록 회원 확인:', {
-            wallet: memberAccount.wallet.toBase58(),
-            role: memberAccount.role,
-            isActive: memberAccount.isActive,
-            totalAttendance: memberAccount.totalAttendance,
-            totalPoints: memberAccount.totalPoints.toNumber()
-          });
-          
-          // 회원이 비활성화된 경우
-          if (!memberAccount.isActive) {
-            toast.error('비활성화된 회원입니다. 운영진에게 문의해주세요.');
-            setStatus('failed');
-            setIsProcessing(false);
-            return;
-          }
-        }
+        memberAccount = await program.account.member.fetchNullable(memberPDA);
       } catch (error) {
-        console.error('❌ Member 확인/등록 실패:', error);
-        toast.error('회원 정보 확인/등록에 실패했습니다.', {
+        console.error('❌ Member 확인 실패:', error);
+        toast.error('회원 정보 확인에 실패했습니다.', {
           description: '운영진에게 문의해주세요.',
           duration: 8000
         });
@@ -296,6 +194,44 @@ function CheckInContent() {
         return;
       }
       
+      if (!memberAccount) {
+        console.log('❌ 블록체인에 미등록 회원:', publicKey.toBase58());
+        toast.error('등록되지 않은 지갑 주소입니다.', {
+          description: 'BAY 학회에 등록된 회원만 이용 가능합니다. 운영진에게 회원 등록을 요청해주세요.',
+          duration: 8000
+        });
+        setStatus('failed');
+        setIsProcessing(false);
+        return;
+      }
+      
+      console.log('✅ 블록체인 등록 회원 확인:', {
+        wallet: memberAccount.wallet.toBase58(),
+        role: memberAccount.role,
+        isActive: memberAccount.isActive,
+        totalAttendance: memberAccount.totalAttendance,
+        totalPoints: memberAccount.totalPoints.toNumber()
+      });
+      
+      // 회원이 비활성화된 경우
+      if (!memberAccount.isActive) {
+        toast.error('비활성화된 회원입니다. 운영진에게 문의해주세요.');
+        setStatus('failed');
+        setIsProcessing(false);
+        return;
+      }
+      
+      // 현장 인증 모드 세션은 진행자가 띄운 QR 코드의 서명이 필요해 웹 체크인으로 처리할 수 없음
+      if (sessionInfo.presenceWindow > 0) {
+        toast.error('현장 인증이 필요한 세션입니다.', {
+          description: '세션 진행자가 띄운 현장 QR 코드로 체크인해주세요.',
+          duration: 8000
+        });
+        setStatus('failed');
+        setIsProcessing(false);
+        return;
+      }
+      
       // 시간 검증 및 출석 상태 결정
       const currentTime = Math.floor(Date.now() / 1000);
       const startTime = sessionInfo.startTime.toNumber();
@@ -334,14 +270,36 @@ function CheckInContent() {
       console.log('체크인 트랜잭션 실행...');
       toast.info('블록체인에 출석을 기록하고 있습니다...', { duration: 3000 });
       
+      // BAY 포인트 민트는 Config에서 조회 (config, member, attendanceRecord, mintAuthority,
+      // memberTokenAccount, instructionsSysvar 등 나머지 계정은 IDL의 PDA/주소로 자동 계산)
+      const [configPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from('config')],
+        PROGRAM_ID
+      );
+      const config = await program.account.config.fetch(configPDA);
+      
+      // 학기 세션이면 학기별 통계, 팀 소속 학회원이면 팀 계정이 필요
+      const termStatsPDA = sessionInfo.term
+        ? PublicKey.findProgramAddressSync(
+            [Buffer.from('term_stats'), sessionInfo.term.toBuffer(), publicKey.toBuffer()],
+            PROGRAM_ID
+          )[0]
+        : null;
+      const teamPDA = memberAccount.teamId !== null
+        ? PublicKey.findProgramAddressSync(
+            [Buffer.from('team'), new BN(memberAccount.teamId).toArrayLike(Buffer, 'le', 2)],
+            PROGRAM_ID
+          )[0]
+        : null;
+      
       const transaction = await program.methods
         .checkIn()
         .accounts({
           memberWallet: publicKey,
-          member: memberPDA,
           session: sessionPubkey,
-          attendanceRecord: attendancePDA,
-          systemProgram: SystemProgram.programId,
+          pointMint: config.pointMint,
+          termStats: termStatsPDA,
+          team: teamPDA,
         })
         .transaction();
       
@@ -458,6 +416,14 @@ function CheckInContent() {
         await new Promise(resolve => setTimeout(resolve, 1000));
       }
 
+      // 실제 지급된 포인트는 출석 기록에서 확인 (세션 포인트 배율, 연속 출석 보너스 반영)
+      try {
+        const record = await program.account.attendanceRecord.fetch(attendancePDA);
+        points = record.pointsEarned.toNumber();
+      } catch (error) {
+        console.warn('출석 기록 조회 실패, 예상 포인트로 표시:', error);
+      }
+
       // 출석 기록 저장
       const newRecord = {
         id: `${sessionDateParam}-${publicKey.toBase58()}`,
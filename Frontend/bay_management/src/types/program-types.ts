@@ -1,142 +1,7376 @@
+/**
+ * Program IDL in camelCase format in order to be used in JS/TS.
+ *
+ * Note that this is only a type helper and is not the actual IDL. The original
+ * IDL can be found at `target/idl/bay_attendance_check.json`.
+ */
 export type BayAttendanceCheck = {
-  "version": "0.1.0",
-  "name": "bay_attendance_check",
+  "address": "HW4UmSnJfLd8yn8afM3WGz2w52ea7i1oTGqCSAXJmwv5",
+  "metadata": {
+    "name": "bayAttendanceCheck",
+    "version": "0.1.0",
+    "spec": "0.1.0",
+    "description": "Created with Anchor"
+  },
   "instructions": [
     {
-      "name": "checkIn",
+      "name": "approveExcuse",
+      "discriminator": [
+        72,
+        206,
+        41,
+        141,
+        126,
+        210,
+        253,
+        19
+      ],
       "accounts": [
         {
-          "name": "memberWallet",
-          "isMut": true,
-          "isSigner": true
+          "name": "authority",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "member",
-          "isMut": true,
-          "isSigner": false
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
         },
         {
           "name": "session",
-          "isMut": true,
-          "isSigner": false
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        },
+        {
+          "name": "member",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "excuse_request.member",
+                "account": "excuseRequest"
+              }
+            ]
+          }
+        },
+        {
+          "name": "excuseRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  99,
+                  117,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session"
+              },
+              {
+                "kind": "account",
+                "path": "excuse_request.member",
+                "account": "excuseRequest"
+              }
+            ]
+          }
         },
         {
           "name": "attendanceRecord",
-          "isMut": true,
-          "isSigner": false
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  116,
+                  116,
+                  101,
+                  110,
+                  100,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session"
+              },
+              {
+                "kind": "account",
+                "path": "excuse_request.member",
+                "account": "excuseRequest"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_stats.term",
+                "account": "termStats"
+              },
+              {
+                "kind": "account",
+                "path": "excuse_request.member",
+                "account": "excuseRequest"
+              }
+            ]
+          }
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termLeaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
         },
         {
           "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": []
     },
     {
-      "name": "initializeMember",
+      "name": "cancelSession",
+      "discriminator": [
+        57,
+        207,
+        155,
+        166,
+        136,
+        32,
+        99,
+        116
+      ],
       "accounts": [
         {
           "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "admin",
-          "isMut": false,
-          "isSigner": false
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
         },
         {
-          "name": "memberWallet",
-          "isMut": false,
-          "isSigner": false
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
         },
         {
-          "name": "member",
-          "isMut": true,
-          "isSigner": false
+          "name": "term",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term.id",
+                "account": "term"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "leaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termLeaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeMemberRole",
+      "discriminator": [
+        24,
+        49,
+        246,
+        17,
+        29,
+        38,
+        54,
+        223
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "member",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
         }
       ],
       "args": [
         {
           "name": "role",
           "type": {
-            "defined": "MemberRole"
+            "defined": {
+              "name": "memberRole"
+            }
           }
         }
       ]
     },
     {
-      "name": "initializeSession",
+      "name": "checkIn",
+      "discriminator": [
+        209,
+        253,
+        4,
+        217,
+        250,
+        241,
+        207,
+        50
+      ],
+      "accounts": [
+        {
+          "name": "memberWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "member",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "memberWallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "session",
+          "writable": true
+        },
+        {
+          "name": "attendanceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  116,
+                  116,
+                  101,
+                  110,
+                  100,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session"
+              },
+              {
+                "kind": "account",
+                "path": "memberWallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_stats.term",
+                "account": "termStats"
+              },
+              {
+                "kind": "account",
+                "path": "memberWallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "team",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "team.id",
+                "account": "team"
+              }
+            ]
+          }
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termLeaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pointMint",
+          "writable": true
+        },
+        {
+          "name": "memberTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "memberWallet"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pointMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMember",
+      "discriminator": [
+        221,
+        98,
+        181,
+        59,
+        120,
+        117,
+        20,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "member",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeSession",
+      "discriminator": [
+        68,
+        114,
+        178,
+        140,
+        222,
+        38,
+        248,
+        211
+      ],
       "accounts": [
         {
           "name": "authority",
-          "isMut": true,
-          "isSigner": true
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "admin",
-          "isMut": false,
-          "isSigner": false
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
         },
         {
           "name": "session",
-          "isMut": true,
-          "isSigner": false
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sessionAdmin",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSeriesSessions",
+      "discriminator": [
+        208,
+        38,
+        27,
+        206,
+        190,
+        4,
+        167,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "series",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  114,
+                  105,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "series.id",
+                "account": "sessionSeries"
+              }
+            ]
+          }
+        },
+        {
+          "name": "term",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term.id",
+                "account": "term"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSessionSeries",
+      "discriminator": [
+        53,
+        59,
+        213,
+        189,
+        125,
+        22,
+        17,
+        214
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "term",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term.id",
+                "account": "term"
+              }
+            ]
+          }
+        },
+        {
+          "name": "series",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  114,
+                  105,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.series_count",
+                "account": "config"
+              }
+            ]
+          }
         },
         {
           "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "sessionDate",
-          "type": "i64"
+          "name": "title",
+          "type": "string"
         },
         {
-          "name": "startTime",
-          "type": "i64"
+          "name": "kind",
+          "type": {
+            "defined": {
+              "name": "sessionKind"
+            }
+          }
         },
         {
-          "name": "lateTime",
-          "type": "i64"
+          "name": "schedule",
+          "type": {
+            "defined": {
+              "name": "seriesSchedule"
+            }
+          }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
+    {
+      "name": "finalizeSession",
+      "discriminator": [
+        34,
+        148,
+        144,
+        47,
+        37,
+        130,
+        206,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termLeaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "lock",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "getLeaderboard",
+      "discriminator": [
+        120,
+        151,
+        17,
+        201,
+        227,
+        143,
+        94,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "leaderboard",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "leaderboardStats"
+        }
+      }
+    },
+    {
+      "name": "getMemberStats",
+      "discriminator": [
+        69,
+        179,
+        204,
+        79,
+        91,
+        148,
+        175,
+        103
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "memberStats"
+        }
+      }
+    },
+    {
+      "name": "getSessionStats",
+      "discriminator": [
+        246,
+        201,
+        15,
+        99,
+        62,
+        199,
+        4,
+        20
+      ],
+      "accounts": [
+        {
+          "name": "session",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "sessionStats"
+        }
+      }
+    },
+    {
+      "name": "getTeamStats",
+      "discriminator": [
+        215,
+        51,
+        223,
+        34,
+        99,
+        91,
+        223,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "team",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "team.id",
+                "account": "team"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "teamStats"
+        }
+      }
+    },
+    {
+      "name": "getTermStats",
+      "discriminator": [
+        165,
+        134,
+        130,
+        168,
+        167,
+        65,
+        19,
+        117
+      ],
+      "accounts": [
+        {
+          "name": "termStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_stats.term",
+                "account": "termStats"
+              },
+              {
+                "kind": "account",
+                "path": "term_stats.member",
+                "account": "termStats"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "termMemberStats"
+        }
+      }
+    },
+    {
+      "name": "initializeConfig",
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "HW4UmSnJfLd8yn8afM3WGz2w52ea7i1oTGqCSAXJmwv5"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pointMint"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "superAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "initializeLeaderboard",
+      "discriminator": [
+        47,
+        23,
+        34,
+        39,
+        46,
+        108,
+        91,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_account.id",
+                "account": "term"
+              }
+            ]
+          }
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "term"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "term",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "initializeMember",
+      "discriminator": [
+        175,
+        223,
+        6,
+        110,
+        126,
+        61,
+        144,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "memberWallet"
+        },
+        {
+          "name": "member",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "memberWallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "memberRole"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeSession",
+      "discriminator": [
+        69,
+        130,
+        92,
+        236,
+        107,
+        231,
+        159,
+        129
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "term",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term.id",
+                "account": "term"
+              }
+            ]
+          }
+        },
+        {
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.session_count",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "kind",
+          "type": {
+            "defined": {
+              "name": "sessionKind"
+            }
+          }
+        },
+        {
+          "name": "sessionDate",
+          "type": "i64"
+        },
+        {
+          "name": "openTime",
+          "type": "i64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
+        },
+        {
+          "name": "lateTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "initializeTeam",
+      "discriminator": [
+        52,
+        213,
+        213,
+        247,
+        136,
+        22,
+        163,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "team",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.team_count",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "lead",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "initializeTerm",
+      "discriminator": [
+        151,
+        181,
+        113,
+        51,
+        228,
+        42,
+        167,
+        0
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "term",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.term_count",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "startDate",
+          "type": "i64"
+        },
+        {
+          "name": "endDate",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "initializeTermStats",
+      "discriminator": [
+        158,
+        180,
+        36,
+        220,
+        137,
+        48,
+        74,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "term",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term.id",
+                "account": "term"
+              }
+            ]
+          }
+        },
+        {
+          "name": "member",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term"
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateAttendanceRecord",
+      "discriminator": [
+        47,
+        8,
+        238,
+        198,
+        85,
+        35,
+        254,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateConfig",
+      "discriminator": [
+        92,
+        131,
+        58,
+        105,
+        210,
+        154,
+        224,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateMember",
+      "discriminator": [
+        131,
+        166,
+        40,
+        58,
+        44,
+        3,
+        147,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateSession",
+      "discriminator": [
+        176,
+        128,
+        4,
+        35,
+        63,
+        1,
+        118,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reactivateSession",
+      "discriminator": [
+        187,
+        30,
+        212,
+        232,
+        191,
+        241,
+        37,
+        211
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termLeaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": {
+              "name": "reactivateMode"
+            }
+          }
+        },
+        {
+          "name": "newOpenTime",
+          "type": "i64"
+        },
+        {
+          "name": "newStartTime",
+          "type": "i64"
+        },
+        {
+          "name": "newLateTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "recordAttendance",
+      "discriminator": [
+        79,
+        87,
+        96,
+        24,
+        25,
+        169,
+        16,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "member",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        },
+        {
+          "name": "memberWallet"
+        },
+        {
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        },
+        {
+          "name": "attendanceRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  116,
+                  116,
+                  101,
+                  110,
+                  100,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session"
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_stats.term",
+                "account": "termStats"
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        },
+        {
+          "name": "team",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "team.id",
+                "account": "team"
+              }
+            ]
+          }
+        },
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        },
+        {
+          "name": "termLeaderboard",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term_leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pointMint",
+          "writable": true
+        },
+        {
+          "name": "memberTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "memberWallet"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pointMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "status",
+          "type": {
+            "defined": {
+              "name": "attendanceStatus"
+            }
+          }
+        },
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "correctionReason"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "refreshLeaderboard",
+      "discriminator": [
+        71,
+        226,
+        181,
+        26,
+        26,
+        183,
+        153,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "leaderboard",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  100,
+                  101,
+                  114,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "leaderboard.term",
+                "account": "leaderboard"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectExcuse",
+      "discriminator": [
+        207,
+        231,
+        120,
+        80,
+        114,
+        0,
+        54,
+        244
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "excuseRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  99,
+                  117,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "excuse_request.session",
+                "account": "excuseRequest"
+              },
+              {
+                "kind": "account",
+                "path": "excuse_request.member",
+                "account": "excuseRequest"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rescheduleSeries",
+      "discriminator": [
+        215,
+        34,
+        253,
+        182,
+        10,
+        17,
+        128,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "series",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  114,
+                  105,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "series.id",
+                "account": "sessionSeries"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "startTimeOfDay",
+          "type": "u32"
+        },
+        {
+          "name": "lateWindow",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setMemberActive",
+      "discriminator": [
+        54,
+        245,
+        234,
+        225,
+        86,
+        173,
+        116,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "member",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "isActive",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setMemberTeam",
+      "discriminator": [
+        197,
+        48,
+        215,
+        5,
+        16,
+        74,
+        154,
+        38
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "member",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        },
+        {
+          "name": "previousTeam",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "previous_team.id",
+                "account": "team"
+              }
+            ]
+          }
+        },
+        {
+          "name": "team",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "team.id",
+                "account": "team"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "teamId",
+          "type": {
+            "option": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPointMint",
+      "discriminator": [
+        254,
+        17,
+        224,
+        102,
+        58,
+        197,
+        102,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pointMint"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPointSchedule",
+      "discriminator": [
+        204,
+        11,
+        127,
+        91,
+        175,
+        213,
+        81,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "pointSchedule",
+          "type": {
+            "defined": {
+              "name": "pointSchedule"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRolePermissions",
+      "discriminator": [
+        195,
+        19,
+        230,
+        152,
+        53,
+        36,
+        95,
+        11
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "memberRole"
+            }
+          }
+        },
+        {
+          "name": "permissions",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setSessionCoHosts",
+      "discriminator": [
+        93,
+        115,
+        32,
+        7,
+        168,
+        32,
+        45,
+        8
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "coHosts",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setSessionOverride",
+      "discriminator": [
+        0,
+        164,
+        204,
+        95,
+        85,
+        208,
+        170,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "stateOverride",
+          "type": {
+            "defined": {
+              "name": "sessionOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setSessionPresenceMode",
+      "discriminator": [
+        74,
+        29,
+        206,
+        77,
+        89,
+        164,
+        222,
+        211
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "presenceWindow",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setSessionRewards",
+      "discriminator": [
+        117,
+        120,
+        4,
+        93,
+        187,
+        222,
+        206,
+        9
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "pointSchedule",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "pointSchedule"
+              }
+            }
+          }
+        },
+        {
+          "name": "pointMultiplier",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setSessionTimeRules",
+      "discriminator": [
+        246,
+        170,
+        178,
+        177,
+        87,
+        205,
+        106,
+        241
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionTimeRules",
+          "type": {
+            "defined": {
+              "name": "sessionTimeRules"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setStreakRules",
+      "discriminator": [
+        137,
+        14,
+        247,
+        171,
+        3,
+        114,
+        34,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "streakRules",
+          "type": {
+            "defined": {
+              "name": "streakRules"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTermActive",
+      "discriminator": [
+        27,
+        6,
+        206,
+        114,
+        116,
+        7,
+        191,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "term",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  114,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "term.id",
+                "account": "term"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "isActive",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "submitExcuse",
+      "discriminator": [
+        247,
+        251,
+        16,
+        144,
+        156,
+        184,
+        118,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "memberWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "member",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "memberWallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "session",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        },
+        {
+          "name": "excuseRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  99,
+                  117,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session"
+              },
+              {
+                "kind": "account",
+                "path": "memberWallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "reasonHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMemberProfile",
+      "discriminator": [
+        238,
+        156,
+        74,
+        158,
+        199,
+        133,
+        159,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "member",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "member.wallet",
+                "account": "member"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "profile",
+          "type": {
+            "defined": {
+              "name": "memberProfile"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateSessionStatus",
+      "discriminator": [
+        138,
+        126,
+        174,
+        2,
+        46,
+        166,
+        179,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "session",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  115,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "session.id",
+                "account": "session"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "isActive",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updateTeam",
+      "discriminator": [
+        44,
+        16,
+        12,
+        127,
+        225,
+        143,
+        144,
+        145
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "team",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "team.id",
+                "account": "team"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "lead",
+          "type": "pubkey"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "attendanceRecord",
+      "discriminator": [
+        207,
+        57,
+        71,
+        145,
+        143,
+        128,
+        238,
+        179
+      ]
+    },
+    {
+      "name": "config",
+      "discriminator": [
+        155,
+        12,
+        170,
+        224,
+        30,
+        250,
+        204,
+        130
+      ]
+    },
+    {
+      "name": "excuseRequest",
+      "discriminator": [
+        210,
+        79,
+        215,
+        84,
+        65,
+        32,
+        169,
+        58
+      ]
+    },
+    {
+      "name": "leaderboard",
+      "discriminator": [
+        247,
+        186,
+        238,
+        243,
+        194,
+        30,
+        9,
+        36
+      ]
+    },
+    {
+      "name": "member",
+      "discriminator": [
+        54,
+        19,
+        162,
+        21,
+        29,
+        166,
+        17,
+        198
+      ]
+    },
+    {
+      "name": "session",
+      "discriminator": [
+        243,
+        81,
+        72,
+        115,
+        214,
+        188,
+        72,
+        144
+      ]
+    },
+    {
+      "name": "sessionSeries",
+      "discriminator": [
+        14,
+        37,
+        110,
+        89,
+        46,
+        83,
+        24,
+        112
+      ]
+    },
+    {
+      "name": "team",
+      "discriminator": [
+        140,
+        218,
+        177,
+        140,
+        193,
+        241,
+        199,
+        106
+      ]
+    },
+    {
+      "name": "term",
+      "discriminator": [
+        223,
+        23,
+        94,
+        27,
+        187,
+        5,
+        151,
+        61
+      ]
+    },
+    {
+      "name": "termStats",
+      "discriminator": [
+        181,
+        184,
+        30,
+        14,
+        58,
+        148,
+        157,
+        172
+      ]
+    }
+  ],
+  "events": [
+    {
+      "discriminator": [
+        153,
+        121,
+        252,
+        128,
+        30,
+        241,
+        166,
+        101
+      ],
+      "name": "accountMigrated"
+    },
+    {
+      "discriminator": [
+        77,
+        74,
+        185,
+        118,
+        177,
+        222,
+        23,
+        206
+      ],
+      "name": "attendanceCorrected"
+    },
+    {
+      "discriminator": [
+        201,
+        49,
+        54,
+        177,
+        107,
+        159,
+        97,
+        242
+      ],
+      "name": "cancelledRecordsClosed"
+    },
+    {
+      "discriminator": [
+        211,
+        80,
+        198,
+        244,
+        196,
+        84,
+        212,
+        150
+      ],
+      "name": "checkedIn"
+    },
+    {
+      "discriminator": [
+        181,
+        49,
+        200,
+        156,
+        19,
+        167,
+        178,
+        91
+      ],
+      "name": "configInitialized"
+    },
+    {
+      "discriminator": [
+        53,
+        239,
+        156,
+        190,
+        231,
+        27,
+        6,
+        10
+      ],
+      "name": "excuseReviewed"
+    },
+    {
+      "discriminator": [
+        153,
+        150,
+        119,
+        218,
+        46,
+        10,
+        37,
+        206
+      ],
+      "name": "excuseSubmitted"
+    },
+    {
+      "discriminator": [
+        178,
+        95,
+        91,
+        55,
+        83,
+        46,
+        236,
+        26
+      ],
+      "name": "leaderboardCreated"
+    },
+    {
+      "discriminator": [
+        144,
+        66,
+        227,
+        96,
+        123,
+        189,
+        130,
+        204
+      ],
+      "name": "memberClosed"
+    },
+    {
+      "discriminator": [
+        76,
+        38,
+        137,
+        192,
+        220,
+        5,
+        6,
+        164
+      ],
+      "name": "memberMarkedAbsent"
+    },
+    {
+      "discriminator": [
+        143,
+        254,
+        68,
+        182,
+        160,
+        240,
+        47,
+        183
+      ],
+      "name": "memberProfileUpdated"
+    },
+    {
+      "discriminator": [
+        57,
+        155,
+        128,
+        94,
+        1,
+        149,
+        25,
+        250
+      ],
+      "name": "memberRegistered"
+    },
+    {
+      "discriminator": [
+        137,
+        32,
+        118,
+        57,
+        141,
+        162,
+        192,
+        72
+      ],
+      "name": "memberRoleChanged"
+    },
+    {
+      "discriminator": [
+        97,
+        23,
+        4,
+        181,
+        116,
+        104,
+        175,
+        73
+      ],
+      "name": "memberStatusChanged"
+    },
+    {
+      "discriminator": [
+        103,
+        213,
+        197,
+        13,
+        195,
+        35,
+        243,
+        121
+      ],
+      "name": "memberTeamChanged"
+    },
+    {
+      "discriminator": [
+        229,
+        121,
+        105,
+        236,
+        232,
+        114,
+        113,
+        163
+      ],
+      "name": "pointMintUpdated"
+    },
+    {
+      "discriminator": [
+        35,
+        90,
+        156,
+        95,
+        216,
+        28,
+        215,
+        27
+      ],
+      "name": "pointScheduleUpdated"
+    },
+    {
+      "discriminator": [
+        163,
+        54,
+        38,
+        219,
+        173,
+        163,
+        181,
+        233
+      ],
+      "name": "rolePermissionsUpdated"
+    },
+    {
+      "discriminator": [
+        250,
+        75,
+        46,
+        8,
+        151,
+        26,
+        254,
+        54
+      ],
+      "name": "sessionCancelled"
+    },
+    {
+      "discriminator": [
+        57,
+        237,
+        11,
+        243,
+        194,
+        34,
+        120,
+        27
+      ],
+      "name": "sessionClosed"
+    },
+    {
+      "discriminator": [
+        217,
+        202,
+        235,
+        204,
+        21,
+        94,
+        211,
+        22
+      ],
+      "name": "sessionCoHostsUpdated"
+    },
+    {
+      "discriminator": [
+        107,
+        111,
+        254,
+        25,
+        21,
+        122,
+        220,
+        225
+      ],
+      "name": "sessionCreated"
+    },
+    {
+      "discriminator": [
+        33,
+        12,
+        242,
+        91,
+        206,
+        42,
+        163,
+        235
+      ],
+      "name": "sessionFinalized"
+    },
+    {
+      "discriminator": [
+        234,
+        184,
+        229,
+        126,
+        74,
+        62,
+        140,
+        99
+      ],
+      "name": "sessionPresenceModeChanged"
+    },
+    {
+      "discriminator": [
+        142,
+        5,
+        135,
+        193,
+        247,
+        246,
+        106,
+        228
+      ],
+      "name": "sessionReactivated"
+    },
+    {
+      "discriminator": [
+        132,
+        183,
+        249,
+        54,
+        133,
+        94,
+        97,
+        150
+      ],
+      "name": "sessionSeriesCreated"
+    },
+    {
+      "discriminator": [
+        153,
+        127,
+        215,
+        163,
+        65,
+        163,
+        95,
+        51
+      ],
+      "name": "sessionSeriesRescheduled"
+    },
+    {
+      "discriminator": [
+        196,
+        72,
+        137,
+        196,
+        47,
+        238,
+        57,
+        214
+      ],
+      "name": "sessionStatusChanged"
+    },
+    {
+      "discriminator": [
+        103,
+        237,
+        131,
+        208,
+        255,
+        120,
+        12,
+        48
+      ],
+      "name": "sessionTimeRulesUpdated"
+    },
+    {
+      "discriminator": [
+        80,
+        31,
+        166,
+        67,
+        190,
+        37,
+        221,
+        27
+      ],
+      "name": "streakMilestoneReached"
+    },
+    {
+      "discriminator": [
+        111,
+        46,
+        253,
+        101,
+        222,
+        120,
+        242,
+        255
+      ],
+      "name": "streakRulesUpdated"
+    },
+    {
+      "discriminator": [
+        172,
+        52,
+        201,
+        62,
+        192,
+        159,
+        66,
+        49
+      ],
+      "name": "teamCreated"
+    },
+    {
+      "discriminator": [
+        128,
+        179,
+        131,
+        23,
+        147,
+        211,
+        232,
+        55
+      ],
+      "name": "teamUpdated"
+    },
+    {
+      "discriminator": [
+        98,
+        6,
+        214,
+        157,
+        96,
+        28,
+        164,
+        160
+      ],
+      "name": "termCreated"
+    },
+    {
+      "discriminator": [
+        133,
+        209,
+        100,
+        48,
+        26,
+        223,
+        58,
+        196
+      ],
+      "name": "termStatusChanged"
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "unauthorized",
+      "msg": "You are not authorized to perform this action"
+    },
+    {
+      "code": 6001,
+      "name": "sessionNotActive",
+      "msg": "The session is not active"
+    },
+    {
+      "code": 6002,
+      "name": "alreadyCheckedIn",
+      "msg": "You have already checked in for this session"
+    },
+    {
+      "code": 6003,
+      "name": "checkInTimePassed",
+      "msg": "The check-in time has passed"
+    },
+    {
+      "code": 6004,
+      "name": "invalidTimeParameters",
+      "msg": "Invalid time parameters"
+    },
+    {
+      "code": 6005,
+      "name": "memberNotActive",
+      "msg": "Member is not active"
+    },
+    {
+      "code": 6006,
+      "name": "sessionNotFound",
+      "msg": "Session not found"
+    },
+    {
+      "code": 6007,
+      "name": "overflow",
+      "msg": "Integer overflow"
+    },
+    {
+      "code": 6008,
+      "name": "sessionFinalized",
+      "msg": "The session has been finalized"
+    },
+    {
+      "code": 6009,
+      "name": "sessionNotEnded",
+      "msg": "The session has not ended yet"
+    },
+    {
+      "code": 6010,
+      "name": "invalidRemainingAccounts",
+      "msg": "Invalid remaining accounts"
+    },
+    {
+      "code": 6011,
+      "name": "notSessionHost",
+      "msg": "Only the session admin or its co-hosts can manage this session"
+    },
+    {
+      "code": 6012,
+      "name": "tooManyCoHosts",
+      "msg": "Too many co-hosts"
+    },
+    {
+      "code": 6013,
+      "name": "titleTooLong",
+      "msg": "Session title is too long"
+    },
+    {
+      "code": 6014,
+      "name": "invalidPointMultiplier",
+      "msg": "Invalid point multiplier"
+    },
+    {
+      "code": 6015,
+      "name": "presenceProofRequired",
+      "msg": "This session requires a signed QR presence code"
+    },
+    {
+      "code": 6016,
+      "name": "invalidPresenceProof",
+      "msg": "Invalid presence proof"
+    },
+    {
+      "code": 6017,
+      "name": "presenceProofExpired",
+      "msg": "The presence code has expired"
+    },
+    {
+      "code": 6018,
+      "name": "excuseAlreadyReviewed",
+      "msg": "The excuse request has already been reviewed"
+    },
+    {
+      "code": 6019,
+      "name": "attendanceAlreadyRecorded",
+      "msg": "Attendance has already been recorded for this member"
+    },
+    {
+      "code": 6020,
+      "name": "memberStillActive",
+      "msg": "Deactivate the member before closing the account"
+    },
+    {
+      "code": 6021,
+      "name": "invalidPermissions",
+      "msg": "Invalid permission bits"
+    },
+    {
+      "code": 6022,
+      "name": "displayNameTooLong",
+      "msg": "Display name is too long"
+    },
+    {
+      "code": 6023,
+      "name": "profileUriTooLong",
+      "msg": "Profile URI is too long"
+    },
+    {
+      "code": 6024,
+      "name": "invalidMigrationAccount",
+      "msg": "Account cannot be migrated"
+    },
+    {
+      "code": 6025,
+      "name": "termNameTooLong",
+      "msg": "Term name is too long"
+    },
+    {
+      "code": 6026,
+      "name": "termNotActive",
+      "msg": "The term is not active"
+    },
+    {
+      "code": 6027,
+      "name": "sessionOutsideTerm",
+      "msg": "The session date is outside the term"
+    },
+    {
+      "code": 6028,
+      "name": "termStatsRequired",
+      "msg": "Term statistics account for this session's term is required"
+    },
+    {
+      "code": 6029,
+      "name": "invalidStreakRules",
+      "msg": "Invalid streak rules"
+    },
+    {
+      "code": 6030,
+      "name": "checkInNotOpen",
+      "msg": "Check-in is not open yet"
+    },
+    {
+      "code": 6031,
+      "name": "sessionTimeOutsideDate",
+      "msg": "Session times must fall within the session date"
+    },
+    {
+      "code": 6032,
+      "name": "sessionAlreadyEnded",
+      "msg": "Session late time has already passed"
+    },
+    {
+      "code": 6033,
+      "name": "lateWindowTooLong",
+      "msg": "Late window exceeds the configured maximum"
+    },
+    {
+      "code": 6034,
+      "name": "invalidUtcOffset",
+      "msg": "Invalid UTC offset"
+    },
+    {
+      "code": 6035,
+      "name": "teamNameTooLong",
+      "msg": "Team name is too long"
+    },
+    {
+      "code": 6036,
+      "name": "teamRequired",
+      "msg": "Team account is required for a member in a team"
+    },
+    {
+      "code": 6037,
+      "name": "memberStillInTeam",
+      "msg": "Member must be removed from their team first"
+    },
+    {
+      "code": 6038,
+      "name": "invalidLeaderboard",
+      "msg": "Leaderboard does not match the session or stats account"
+    },
+    {
+      "code": 6039,
+      "name": "invalidSeriesSchedule",
+      "msg": "Invalid session series schedule"
+    },
+    {
+      "code": 6040,
+      "name": "seriesComplete",
+      "msg": "All sessions of the series have been created"
+    },
+    {
+      "code": 6041,
+      "name": "sessionCancelled",
+      "msg": "The session has been cancelled"
+    },
+    {
+      "code": 6042,
+      "name": "invalidPointMint",
+      "msg": "The point mint does not match the configured BAY mint"
+    },
+    {
+      "code": 6043,
+      "name": "invalidPointSchedule",
+      "msg": "Invalid point schedule"
+    },
+    {
+      "code": 6044,
+      "name": "rewardsLocked",
+      "msg": "Session rewards cannot change after check-in has opened"
+    },
+    {
+      "code": 6045,
+      "name": "sessionNotCancelled",
+      "msg": "Only cancelled sessions can be closed"
+    },
+    {
+      "code": 6046,
+      "name": "sessionHasOpenRecords",
+      "msg": "All attendance records of the session must be closed first"
+    }
+  ],
+  "types": [
+    {
+      "name": "accountMigrated",
+      "type": {
+        "fields": [
+          {
+            "name": "account",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousVersion",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "previousLen",
+            "type": "u32"
+          },
+          {
+            "name": "newLen",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "attendanceCorrected",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousStatus",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "attendanceStatus"
+                }
+              }
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "attendanceStatus"
+              }
+            }
+          },
+          {
+            "name": "points",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "correctionReason"
+              }
+            }
+          },
+          {
+            "name": "correctedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "attendanceRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "checkInTime",
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "attendanceStatus"
+              }
+            }
+          },
+          {
+            "name": "pointsEarned",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "correctedBy",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "correctionReason",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "correctionReason"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "teamId",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "pointsMinted",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                21
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "attendanceStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "present"
+          },
+          {
+            "name": "late"
+          },
+          {
+            "name": "absent"
+          },
+          {
+            "name": "excused"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cancelledRecordsClosed",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "recordsClosed",
+            "type": "u32"
+          },
+          {
+            "name": "recordsRemaining",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "checkedIn",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "attendanceStatus"
+              }
+            }
+          },
+          {
+            "name": "points",
+            "type": "u64"
+          },
+          {
+            "name": "checkInTime",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "superAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionCount",
+            "type": "u64"
+          },
+          {
+            "name": "pointSchedule",
+            "type": {
+              "defined": {
+                "name": "pointSchedule"
+              }
+            }
+          },
+          {
+            "name": "rolePermissions",
+            "type": {
+              "array": [
+                "u16",
+                7
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "termCount",
+            "type": "u64"
+          },
+          {
+            "name": "streakRules",
+            "type": {
+              "defined": {
+                "name": "streakRules"
+              }
+            }
+          },
+          {
+            "name": "sessionTimeRules",
+            "type": {
+              "defined": {
+                "name": "sessionTimeRules"
+              }
+            }
+          },
+          {
+            "name": "teamCount",
+            "type": "u16"
+          },
+          {
+            "name": "seriesCount",
+            "type": "u64"
+          },
+          {
+            "name": "pointMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                45
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configInitialized",
+      "type": {
+        "fields": [
+          {
+            "name": "superAdmin",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "correctionReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "deviceFailure"
+          },
+          {
+            "name": "walletFailure"
+          },
+          {
+            "name": "recordingError"
+          },
+          {
+            "name": "other"
+          }
+        ]
+      }
+    },
+    {
+      "name": "excuseRequest",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "reasonHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "submittedAt",
+            "type": "i64"
+          },
+          {
+            "name": "isPreAnnounced",
+            "type": "bool"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "excuseStatus"
+              }
+            }
+          },
+          {
+            "name": "reviewedBy",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "excuseReviewed",
+      "type": {
+        "fields": [
+          {
+            "name": "excuseRequest",
+            "type": "pubkey"
+          },
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "excuseStatus"
+              }
+            }
+          },
+          {
+            "name": "reviewedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "excuseStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pending"
+          },
+          {
+            "name": "approved"
+          },
+          {
+            "name": "rejected"
+          }
+        ]
+      }
+    },
+    {
+      "name": "excuseSubmitted",
+      "type": {
+        "fields": [
+          {
+            "name": "excuseRequest",
+            "type": "pubkey"
+          },
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "reasonHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "isPreAnnounced",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "leaderboard",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "term",
+            "type": "pubkey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "leaderboardEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "leaderboardCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "leaderboard",
+            "type": "pubkey"
+          },
+          {
+            "name": "term",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "leaderboardEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "points",
+            "type": "u64"
+          },
+          {
+            "name": "attendanceRateBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "leaderboardStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "term",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "leaderboardEntry"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "member",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "memberRole"
+              }
+            }
+          },
+          {
+            "name": "totalAttendance",
+            "type": "u32"
+          },
+          {
+            "name": "totalLate",
+            "type": "u32"
+          },
+          {
+            "name": "totalAbsence",
+            "type": "u32"
+          },
+          {
+            "name": "totalExcused",
+            "type": "u32"
+          },
+          {
+            "name": "totalPoints",
+            "type": "u64"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "displayName",
+            "type": "string"
+          },
+          {
+            "name": "cohort",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "teamId",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "profileUri",
+            "type": "string"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "currentStreak",
+            "type": "u16"
+          },
+          {
+            "name": "longestStreak",
+            "type": "u16"
+          },
+          {
+            "name": "mintedCredit",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "memberClosed",
+      "type": {
+        "fields": [
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "closedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "refundedTo",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "memberMarkedAbsent",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "memberProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "displayName",
+            "type": "string"
+          },
+          {
+            "name": "cohort",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "profileUri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "memberProfileUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "displayName",
+            "type": "string"
+          },
+          {
+            "name": "cohort",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "updatedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "memberRegistered",
+      "type": {
+        "fields": [
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "memberRole"
+              }
+            }
+          },
+          {
+            "name": "registeredBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "memberRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "admin"
+          },
+          {
+            "name": "member"
+          },
+          {
+            "name": "president"
+          },
+          {
+            "name": "trackLead"
+          },
+          {
+            "name": "teamLead"
+          },
+          {
+            "name": "alumni"
+          },
+          {
+            "name": "probationary"
+          }
+        ]
+      }
+    },
+    {
+      "name": "memberRoleChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousRole",
+            "type": {
+              "defined": {
+                "name": "memberRole"
+              }
+            }
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "memberRole"
+              }
+            }
+          },
+          {
+            "name": "changedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "memberStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "displayName",
+            "type": "string"
+          },
+          {
+            "name": "cohort",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "teamId",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "totalAttendance",
+            "type": "u32"
+          },
+          {
+            "name": "totalLate",
+            "type": "u32"
+          },
+          {
+            "name": "totalAbsence",
+            "type": "u32"
+          },
+          {
+            "name": "totalExcused",
+            "type": "u32"
+          },
+          {
+            "name": "totalPoints",
+            "type": "u64"
+          },
+          {
+            "name": "attendanceRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "currentStreak",
+            "type": "u16"
+          },
+          {
+            "name": "longestStreak",
+            "type": "u16"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "memberStatusChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "changedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "memberTeamChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousTeamId",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "teamId",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "changedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "pointMintUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "pointMint",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "pointSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "presentPoints",
+            "type": "u32"
+          },
+          {
+            "name": "latePoints",
+            "type": "u32"
+          },
+          {
+            "name": "earlyBirdBonus",
+            "type": "u32"
+          },
+          {
+            "name": "earlyBirdWindow",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pointScheduleUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "pointSchedule",
+            "type": {
+              "defined": {
+                "name": "pointSchedule"
+              }
+            }
+          },
+          {
+            "name": "pointMultiplier",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "reactivateMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "extend"
+          },
+          {
+            "name": "reset"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rolePermissionsUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "memberRole"
+              }
+            }
+          },
+          {
+            "name": "permissions",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "seriesSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startDate",
+            "type": "i64"
+          },
+          {
+            "name": "weekday",
+            "type": "u8"
+          },
+          {
+            "name": "startTimeOfDay",
+            "type": "u32"
+          },
+          {
+            "name": "openWindow",
+            "type": "u32"
+          },
+          {
+            "name": "lateWindow",
+            "type": "u32"
+          },
+          {
+            "name": "occurrenceCount",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "session",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "sessionKind"
+              }
+            }
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "coHosts",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "sessionDate",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "lateTime",
+            "type": "i64"
+          },
+          {
+            "name": "totalAttendees",
+            "type": "u32"
+          },
+          {
+            "name": "totalLate",
+            "type": "u32"
+          },
+          {
+            "name": "totalAbsent",
+            "type": "u32"
+          },
+          {
+            "name": "totalExcused",
+            "type": "u32"
+          },
+          {
+            "name": "stateOverride",
+            "type": {
+              "defined": {
+                "name": "sessionOverride"
+              }
+            }
+          },
+          {
+            "name": "isFinalized",
+            "type": "bool"
+          },
+          {
+            "name": "pointSchedule",
+            "type": {
+              "defined": {
+                "name": "pointSchedule"
+              }
+            }
+          },
+          {
+            "name": "pointMultiplier",
+            "type": "u16"
+          },
+          {
+            "name": "presenceWindow",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "term",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "openTime",
+            "type": "i64"
+          },
+          {
+            "name": "series",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "isCancelled",
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                53
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionCancelled",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionId",
+            "type": "u64"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "sessionDate",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "affectedRecords",
+            "type": "u32"
+          },
+          {
+            "name": "cancelledBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionClosed",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionId",
+            "type": "u64"
+          },
+          {
+            "name": "refundedTo",
+            "type": "pubkey"
+          },
+          {
+            "name": "closedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionCoHostsUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "coHosts",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionId",
+            "type": "u64"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "sessionKind"
+              }
+            }
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "sessionDate",
+            "type": "i64"
+          },
+          {
+            "name": "openTime",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "lateTime",
+            "type": "i64"
+          },
+          {
+            "name": "term",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionFinalized",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalAttendees",
+            "type": "u32"
+          },
+          {
+            "name": "totalLate",
+            "type": "u32"
+          },
+          {
+            "name": "totalAbsent",
+            "type": "u32"
+          },
+          {
+            "name": "totalExcused",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "regular"
+          },
+          {
+            "name": "study"
+          },
+          {
+            "name": "event"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionOverride",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "closed"
+          },
+          {
+            "name": "auto"
+          },
+          {
+            "name": "open"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionPresenceModeChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "presenceWindow",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionReactivated",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": {
+                "name": "reactivateMode"
+              }
+            }
+          },
+          {
+            "name": "openTime",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "lateTime",
+            "type": "i64"
+          },
+          {
+            "name": "recordsReset",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionSeries",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "sessionKind"
+              }
+            }
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "term",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "schedule",
+            "type": {
+              "defined": {
+                "name": "seriesSchedule"
+              }
+            }
+          },
+          {
+            "name": "firstDate",
+            "type": "i64"
+          },
+          {
+            "name": "nextOccurrence",
+            "type": "u16"
+          },
+          {
+            "name": "sessionIds",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                28
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionSeriesCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "series",
+            "type": "pubkey"
+          },
+          {
+            "name": "seriesId",
+            "type": "u64"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "schedule",
+            "type": {
+              "defined": {
+                "name": "seriesSchedule"
+              }
+            }
+          },
+          {
+            "name": "firstDate",
+            "type": "i64"
+          },
+          {
+            "name": "term",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionSeriesRescheduled",
+      "type": {
+        "fields": [
+          {
+            "name": "series",
+            "type": "pubkey"
+          },
+          {
+            "name": "startTimeOfDay",
+            "type": "u32"
+          },
+          {
+            "name": "lateWindow",
+            "type": "u32"
+          },
+          {
+            "name": "sessionsUpdated",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "scheduled"
+          },
+          {
+            "name": "open"
+          },
+          {
+            "name": "closed"
+          },
+          {
+            "name": "finalized"
+          },
+          {
+            "name": "cancelled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sessionId",
+            "type": "u64"
+          },
+          {
+            "name": "sessionDate",
+            "type": "i64"
+          },
+          {
+            "name": "totalAttendees",
+            "type": "u32"
+          },
+          {
+            "name": "totalLate",
+            "type": "u32"
+          },
+          {
+            "name": "totalAbsent",
+            "type": "u32"
+          },
+          {
+            "name": "totalExcused",
+            "type": "u32"
+          },
+          {
+            "name": "onTimeRateBps",
+            "type": "u16"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": {
+                "name": "sessionState"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionStatusChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "stateOverride",
+            "type": {
+              "defined": {
+                "name": "sessionOverride"
+              }
+            }
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": {
+                "name": "sessionState"
+              }
+            }
+          },
+          {
+            "name": "changedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "sessionTimeRules",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "utcOffset",
+            "type": "i32"
+          },
+          {
+            "name": "maxLateWindow",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionTimeRulesUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "sessionTimeRules",
+            "type": {
+              "defined": {
+                "name": "sessionTimeRules"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "streakMilestone",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "streak",
+            "type": "u16"
+          },
+          {
+            "name": "bonusPoints",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "streakMilestoneReached",
+      "type": {
+        "fields": [
+          {
+            "name": "session",
+            "type": "pubkey"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "streak",
+            "type": "u16"
+          },
+          {
+            "name": "bonusPoints",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "streakRules",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keepStreakOnLate",
+            "type": "bool"
+          },
+          {
+            "name": "milestones",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "streakMilestone"
+                  }
+                },
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
     {
-      "name": "member",
+      "name": "streakRulesUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "streakRules",
+            "type": {
+              "defined": {
+                "name": "streakRules"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "team",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "wallet",
-            "type": "publicKey"
+            "name": "id",
+            "type": "u16"
           },
           {
-            "name": "role",
-            "type": {
-              "defined": "MemberRole"
-            }
+            "name": "name",
+            "type": "string"
           },
           {
-            "name": "totalAttendance",
+            "name": "lead",
+            "type": "pubkey"
+          },
+          {
+            "name": "memberCount",
             "type": "u32"
           },
           {
-            "name": "totalLate",
+            "name": "totalAttendance",
             "type": "u32"
           },
           {
-            "name": "totalAbsence",
+            "name": "totalLate",
             "type": "u32"
           },
           {
@@ -144,39 +7378,76 @@ export type BayAttendanceCheck = {
             "type": "u64"
           },
           {
-            "name": "isActive",
-            "type": "bool"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "bump",
+            "name": "version",
             "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "session",
+      "name": "teamCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "team",
+            "type": "pubkey"
+          },
+          {
+            "name": "teamId",
+            "type": "u16"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "lead",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "teamStats",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
-            "type": "publicKey"
+            "name": "team",
+            "type": "pubkey"
           },
           {
-            "name": "sessionDate",
-            "type": "i64"
+            "name": "teamId",
+            "type": "u16"
           },
           {
-            "name": "startTime",
-            "type": "i64"
+            "name": "name",
+            "type": "string"
           },
           {
-            "name": "lateTime",
-            "type": "i64"
+            "name": "lead",
+            "type": "pubkey"
           },
           {
-            "name": "totalAttendees",
+            "name": "memberCount",
+            "type": "u32"
+          },
+          {
+            "name": "totalAttendance",
             "type": "u32"
           },
           {
@@ -184,163 +7455,125 @@ export type BayAttendanceCheck = {
             "type": "u32"
           },
           {
-            "name": "isActive",
-            "type": "bool"
+            "name": "totalPoints",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "pointsPerMember",
+            "type": "u64"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "MemberRole",
+      "name": "teamUpdated",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "fields": [
+          {
+            "name": "team",
+            "type": "pubkey"
+          },
           {
-            "name": "Admin"
+            "name": "name",
+            "type": "string"
           },
           {
-            "name": "Member"
+            "name": "lead",
+            "type": "pubkey"
           }
-        ]
+        ],
+        "kind": "struct"
       }
-    }
-  ]
-};
-
-export const IDL: BayAttendanceCheck = {
-  "version": "0.1.0",
-  "name": "bay_attendance_check",
-  "instructions": [
-    {
-      "name": "checkIn",
-      "accounts": [
-        {
-          "name": "memberWallet",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "member",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "session",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "attendanceRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
     },
     {
-      "name": "initializeMember",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "memberWallet",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "member",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "role",
-          "type": {
-            "defined": "MemberRole"
+      "name": "term",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "startDate",
+            "type": "i64"
+          },
+          {
+            "name": "endDate",
+            "type": "i64"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "totalSessions",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "initializeSession",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "session",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "sessionDate",
-          "type": "i64"
-        },
-        {
-          "name": "startTime",
-          "type": "i64"
-        },
-        {
-          "name": "lateTime",
-          "type": "i64"
-        }
-      ]
-    }
-  ],
-  "accounts": [
+      "name": "termCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "term",
+            "type": "pubkey"
+          },
+          {
+            "name": "termId",
+            "type": "u64"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "startDate",
+            "type": "i64"
+          },
+          {
+            "name": "endDate",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
     {
-      "name": "member",
+      "name": "termMemberStats",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "wallet",
-            "type": "publicKey"
+            "name": "term",
+            "type": "pubkey"
           },
           {
-            "name": "role",
-            "type": {
-              "defined": "MemberRole"
-            }
+            "name": "wallet",
+            "type": "pubkey"
           },
           {
             "name": "totalAttendance",
@@ -355,74 +7588,91 @@ export const IDL: BayAttendanceCheck = {
             "type": "u32"
           },
           {
-            "name": "totalPoints",
-            "type": "u64"
+            "name": "totalExcused",
+            "type": "u32"
           },
           {
-            "name": "isActive",
-            "type": "bool"
+            "name": "totalPoints",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "attendanceRateBps",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "session",
+      "name": "termStats",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
-            "type": "publicKey"
+            "name": "term",
+            "type": "pubkey"
           },
           {
-            "name": "sessionDate",
-            "type": "i64"
+            "name": "member",
+            "type": "pubkey"
           },
           {
-            "name": "startTime",
-            "type": "i64"
+            "name": "totalAttendance",
+            "type": "u32"
           },
           {
-            "name": "lateTime",
-            "type": "i64"
+            "name": "totalLate",
+            "type": "u32"
           },
           {
-            "name": "totalAttendees",
+            "name": "totalAbsence",
             "type": "u32"
           },
           {
-            "name": "totalLate",
+            "name": "totalExcused",
             "type": "u32"
           },
           {
-            "name": "isActive",
-            "type": "bool"
+            "name": "totalPoints",
+            "type": "u64"
           },
           {
             "name": "bump",
             "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "MemberRole",
+      "name": "termStatusChanged",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "fields": [
+          {
+            "name": "term",
+            "type": "pubkey"
+          },
           {
-            "name": "Admin"
+            "name": "isActive",
+            "type": "bool"
           },
           {
-            "name": "Member"
+            "name": "changedBy",
+            "type": "pubkey"
           }
-        ]
+        ],
+        "kind": "struct"
       }
     }
   ]